@@ -0,0 +1,75 @@
+use aoc_parse::{each_char, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2015, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        line(input, parse_steps)
+    }
+
+    fn part_one(steps: &Vec<i64>) -> Answer {
+        find_floor(steps).into()
+    }
+
+    fn part_two(steps: &Vec<i64>) -> Answer {
+        into_basement(steps).into()
+    }
+}
+
+/// Reads the brackets as steps up and down a floor.
+fn parse_steps(s: &str) -> Result<Vec<i64>, ParseError> {
+    each_char(s, "'(' or ')'", |c| match c {
+        '(' => Some(1),
+        ')' => Some(-1),
+        _ => None,
+    })
+}
+
+fn find_floor(steps: &[i64]) -> i64 {
+    steps.iter().sum()
+}
+
+fn into_basement(steps: &[i64]) -> usize {
+    let mut floor = 0;
+    let final_instruction = steps.iter().position(|step| {
+        floor += step;
+        floor < 0
+    });
+
+    final_instruction.expect("Santa never goes into the basement") + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Vec<i64> {
+        Puzzle::parse(input).unwrap()
+    }
+
+    #[test]
+    fn find_floor_test() {
+        assert_eq!(find_floor(&parse("()()")), 0);
+        assert_eq!(find_floor(&parse("(()(()(")), 3);
+    }
+
+    #[test]
+    fn into_basement_test() {
+        assert_eq!(into_basement(&parse(")")), 1);
+        assert_eq!(into_basement(&parse("()())")), 5);
+    }
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("(()x)\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 4: expected '(' or ')', found \"x\""
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_parse::combinators::{complete, int, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2015, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<[u64; 3]>;
+
+    fn parse(input: &str) -> Result<Vec<[u64; 3]>, ParseError> {
+        lines(input, complete(parse_dimensions))
+    }
+
+    fn part_one(presents: &Vec<[u64; 3]>) -> Answer {
+        presents.iter().map(wrapping_required).sum::<u64>().into()
+    }
+
+    fn part_two(presents: &Vec<[u64; 3]>) -> Answer {
+        presents.iter().map(ribbon_required).sum::<u64>().into()
+    }
+}
+
+fn wrapping_required(dimensions: &[u64; 3]) -> u64 {
+    let a = dimensions[0] * dimensions[1];
+    let b = dimensions[0] * dimensions[2];
+    let c = dimensions[1] * dimensions[2];
+
+    2 * (a + b + c) + [a, b, c].iter().min().unwrap()
+}
+
+fn ribbon_required(dimensions: &[u64; 3]) -> u64 {
+    let mut dimensions = *dimensions;
+    dimensions.sort();
+
+    2 * (dimensions[0] + dimensions[1]) + dimensions[0] * dimensions[1] * dimensions[2]
+}
+
+/// Reads a present's dimensions, like `2x3x4`.
+fn parse_dimensions(s: &str) -> Parsed<'_, [u64; 3]> {
+    let (l, s) = int(s)?;
+    let (w, s) = ("x", int).read(s)?;
+    let (h, rest) = ("x", int).read(s)?;
+    Ok(([l, w, h], rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_required_test() {
+        assert_eq!(wrapping_required(&[2, 3, 4]), 58);
+        assert_eq!(wrapping_required(&[1, 1, 10]), 43);
+    }
+
+    #[test]
+    fn ribbon_required_test() {
+        assert_eq!(ribbon_required(&[2, 3, 4]), 34);
+        assert_eq!(ribbon_required(&[1, 1, 10]), 14);
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Puzzle::parse("2x3x4\n1x1x10\n"),
+            Ok(vec![[2, 3, 4], [1, 1, 10]])
+        );
+
+        let err = Puzzle::parse("2x3x4\n1xax10\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found \"ax10\""
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_parse::{each_char, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2015, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(i64, i64)>;
+
+    fn parse(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+        line(input, parse_moves)
+    }
+
+    fn part_one(moves: &Vec<(i64, i64)>) -> Answer {
+        houses_visited(moves.iter()).len().into()
+    }
+
+    fn part_two(moves: &Vec<(i64, i64)>) -> Answer {
+        with_robo_santa(moves).into()
+    }
+}
+
+/// Reads the arrows as steps in `x` and `y`.
+fn parse_moves(s: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    each_char(s, "'^', 'v', '<' or '>'", |c| match c {
+        '^' => Some((0, 1)),
+        'v' => Some((0, -1)),
+        '<' => Some((-1, 0)),
+        '>' => Some((1, 0)),
+        _ => None,
+    })
+}
+
+/// Santa and Robo-Santa take turns following the directions.
+fn with_robo_santa(moves: &[(i64, i64)]) -> usize {
+    let s = houses_visited(moves.iter().step_by(2));
+    let r = houses_visited(moves.iter().skip(1).step_by(2));
+    s.union(&r).count()
+}
+
+fn houses_visited<'a>(moves: impl Iterator<Item = &'a (i64, i64)>) -> HashSet<(i64, i64)> {
+    let mut visited = HashSet::new();
+    let mut location = (0, 0);
+    visited.insert(location);
+
+    for (dx, dy) in moves {
+        location = (location.0 + dx, location.1 + dy);
+        visited.insert(location);
+    }
+
+    visited
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn visited(input: &str) -> usize {
+        houses_visited(Puzzle::parse(input).unwrap().iter()).len()
+    }
+
+    #[test]
+    fn houses_visited_test() {
+        assert_eq!(visited(">"), 2);
+        assert_eq!(visited("^>v<"), 4);
+        assert_eq!(visited("v^v^v^v^v^"), 2);
+    }
+
+    #[test]
+    fn with_robo_santa_test() {
+        assert_eq!(with_robo_santa(&Puzzle::parse("^v").unwrap()), 3);
+        assert_eq!(with_robo_santa(&Puzzle::parse("^>v<").unwrap()), 3);
+        assert_eq!(with_robo_santa(&Puzzle::parse("^v^v^v^v^v").unwrap()), 11);
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_parse::{digits, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2017, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        line(input, digits)
+    }
+
+    fn part_one(digits: &Vec<u32>) -> Answer {
+        sum_matching(digits, 1).into()
+    }
+
+    fn part_two(digits: &Vec<u32>) -> Answer {
+        sum_matching(digits, digits.len() / 2).into()
+    }
+}
+
+fn sum_matching(digits: &[u32], offset: usize) -> u32 {
+    let len = digits.len();
+
+    digits.iter().enumerate().fold(0, |acc, x| {
+        if digits[(x.0 + offset) % len] == *x.1 {
+            acc + x.1
+        } else {
+            acc
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_conversion_works() {
+        assert_eq!(Puzzle::parse("1122\n"), Ok(vec![1, 1, 2, 2]));
+
+        let err = Puzzle::parse("11-22\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected a digit, found \"-\""
+        );
+    }
+
+    #[test]
+    fn part_one() {
+        assert_eq!(sum_matching(&[1, 1, 2, 2], 1), 3, "1122 returns 3");
+        assert_eq!(sum_matching(&[1, 1, 1, 1], 1), 4, "1111 returns 4");
+        assert_eq!(sum_matching(&[1, 2, 3, 4], 1), 0, "1234 returns 0");
+        assert_eq!(
+            sum_matching(&[9, 1, 2, 1, 2, 1, 2, 9], 1),
+            9,
+            "91212129 returns 9"
+        );
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(sum_matching(&[1, 2, 1, 2], 2), 6, "1212 returns 6");
+        assert_eq!(sum_matching(&[1, 2, 2, 1], 2), 0, "1221 returns 0");
+        assert_eq!(sum_matching(&[1, 2, 3, 4, 2, 5], 3), 4, "123425 returns 4");
+        assert_eq!(
+            sum_matching(&[1, 2, 3, 1, 2, 3], 3),
+            12,
+            "123123 returns 12"
+        );
+        assert_eq!(
+            sum_matching(&[1, 2, 1, 3, 1, 4, 1, 5], 4),
+            4,
+            "12131415 returns 4"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2017, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        lines(input, parse_line)
+    }
+
+    fn part_one(rows: &Vec<Vec<u32>>) -> Answer {
+        line_sum(rows, line_diff).into()
+    }
+
+    fn part_two(rows: &Vec<Vec<u32>>) -> Answer {
+        line_sum(rows, line_divisor).into()
+    }
+}
+
+fn line_sum(rows: &[Vec<u32>], f: fn(&[u32]) -> u32) -> u32 {
+    rows.iter().fold(0, |acc, line| acc + f(line))
+}
+
+fn line_diff(nums: &[u32]) -> u32 {
+    nums.iter().max().unwrap() - nums.iter().min().unwrap()
+}
+
+fn line_divisor(nums: &[u32]) -> u32 {
+    for (i, x) in nums.iter().enumerate() {
+        for (j, y) in nums.iter().enumerate() {
+            if i != j && x % y == 0 {
+                return x / y;
+            }
+        }
+    }
+    0
+}
+
+fn parse_line(input: &str) -> Result<Vec<u32>, ParseError> {
+    input.split_whitespace().map(number).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Vec<Vec<u32>> {
+        Puzzle::parse(input).unwrap()
+    }
+
+    #[test]
+    fn parse_line_test() {
+        assert_eq!(
+            parse_line("5806	6444	1281	38"),
+            Ok(vec![5806, 6444, 1281, 38])
+        );
+
+        let err = Puzzle::parse("5\t1\n7\tx5\t3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found \"x5\""
+        );
+    }
+
+    #[test]
+    fn line_diff_sum_test() {
+        assert_eq!(
+            line_sum(&parse("5\t1\t9\t5\n7\t5\t3\n2\t4\t6\t8"), line_diff),
+            18
+        );
+    }
+
+    #[test]
+    fn line_divisor_sum_test() {
+        assert_eq!(
+            line_sum(
+                &parse("5\t9\t2\t8\n9\t4\t7\t3\n3\t8\t6\t5"),
+                line_divisor
+            ),
+            9
+        );
+    }
+
+}
@@ -0,0 +1,70 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        lines(input, number)
+    }
+
+    fn part_one(changes: &Vec<i64>) -> Answer {
+        file_sum(changes).into()
+    }
+
+    fn part_two(changes: &Vec<i64>) -> Answer {
+        repeat_sum(changes).into()
+    }
+}
+
+fn file_sum(changes: &[i64]) -> i64 {
+    changes.iter().sum()
+}
+
+fn repeat_sum(changes: &[i64]) -> i64 {
+    let mut acc = 0;
+    let mut seen = HashSet::new();
+    seen.insert(0);
+
+    changes.iter().cycle().find(|&n| {
+        acc += n;
+        !seen.insert(acc)
+    });
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Puzzle::parse("+1\n-2\n+3\n-5"), Ok(vec![1, -2, 3, -5]));
+
+        let err = Puzzle::parse("+1\n-2\n3+\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a number, found \"3+\""
+        );
+    }
+
+    #[test]
+    fn file_sum_test() {
+        assert_eq!(file_sum(&[1, -2, 3, 1]), 3);
+        assert_eq!(file_sum(&[1, 1, 1]), 3);
+        assert_eq!(file_sum(&[1, 1, -2]), 0);
+        assert_eq!(file_sum(&[-1, -2, -3]), -6);
+    }
+
+    #[test]
+    fn repeat_sum_test() {
+        assert_eq!(repeat_sum(&[1, -2, 3, 1, 1, -2]), 2);
+        assert_eq!(repeat_sum(&[1, -1]), 0);
+        assert_eq!(repeat_sum(&[-6, 3, 8, 5, -6]), 5);
+    }
+}
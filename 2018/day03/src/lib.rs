@@ -0,0 +1,181 @@
+use aoc_parse::combinators::{complete, int, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Claim>;
+
+    fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+        parse_claims(input)
+    }
+
+    fn part_one(claims: &Vec<Claim>) -> Answer {
+        calc_overlap(claims).into()
+    }
+
+    fn part_two(claims: &Vec<Claim>) -> Answer {
+        find_non_overlapping(claims).into()
+    }
+}
+
+fn parse_claims(input: &str) -> Result<Vec<Claim>, ParseError> {
+    let mut claims: Vec<Claim> = lines(input, complete(Claim::parse))?;
+    claims.sort_unstable();
+    Ok(claims)
+}
+
+fn calc_overlap(claims: &[Claim]) -> usize {
+    let mut overlaps: HashSet<(u16, u16)> = HashSet::new();
+
+    for i in 0..claims.len() - 1 {
+        for j in i + 1..claims.len() {
+            // Claims are sorted, based on their left coordinate so we can exit early
+            if claims[j].left > claims[i].right {
+                break;
+            }
+
+            if let Some(overlap) = claims[i].intersection(&claims[j]) {
+                for &x in overlap.coords().iter() {
+                    overlaps.insert(x);
+                }
+            }
+        }
+    }
+
+    overlaps.len()
+}
+
+fn find_non_overlapping(claims: &[Claim]) -> u16 {
+    for i in 0..claims.len() - 1 {
+        let mut has_overlap = false;
+        for j in 1..claims.len() {
+            if i == j {
+                continue;
+            }
+
+            let overlap = claims[i].intersection(&claims[j]);
+            if overlap.is_some() {
+                has_overlap = true;
+                break;
+            }
+        }
+        if !has_overlap {
+            return claims[i].id;
+        }
+    }
+
+    0
+}
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Claim {
+    left: u16,
+    right: u16,
+    top: u16,
+    bottom: u16,
+    id: u16,
+}
+
+impl Claim {
+    /// Parses a claim like `#123 @ 3,2: 5x4`.
+    fn parse(input: &str) -> Parsed<'_, Claim> {
+        let (id, s) = ("#", int).read(input)?;
+        let (left, s) = (" @ ", int).read(s)?;
+        let (top, s) = (",", int).read(s)?;
+        let (width, s) = (": ", int::<u16>).read(s)?;
+        let (height, s) = ("x", int::<u16>).read(s)?;
+
+        let claim = Claim {
+            left,
+            right: left + width,
+            top,
+            bottom: top + height,
+            id,
+        };
+        Ok((claim, s))
+    }
+
+    fn intersection(&self, other: &Claim) -> Option<Claim> {
+        let left = max(self.left, other.left);
+        let right = min(self.right, other.right);
+        let top = max(self.top, other.top);
+        let bottom = min(self.bottom, other.bottom);
+
+        if left >= right || top >= bottom {
+            return None;
+        }
+
+        Some(Claim {
+            id: 0,
+            left,
+            right,
+            top,
+            bottom,
+        })
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
+        let mut coords = Vec::new();
+
+        for i in self.left..self.right {
+            for j in self.top..self.bottom {
+                coords.push((i, j));
+            }
+        }
+
+        coords
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn claim_parsing() {
+        assert_eq!(
+            complete(Claim::parse)("#123 @ 3,2: 5x4").unwrap(),
+            Claim {
+                id: 123,
+                left: 3,
+                top: 2,
+                right: 8,
+                bottom: 6
+            }
+        );
+    }
+
+    #[test]
+    fn claim_intersection() {
+        let a = Claim {
+            id: 0,
+            left: 2,
+            top: 2,
+            right: 10,
+            bottom: 10,
+        };
+        let b = Claim {
+            id: 0,
+            left: 5,
+            top: 7,
+            right: 8,
+            bottom: 12,
+        };
+        assert_eq!(
+            a.intersection(&b).unwrap(),
+            Claim {
+                id: 0,
+                left: 5,
+                top: 7,
+                right: 8,
+                bottom: 10
+            }
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_parse::combinators::{complete, either, int, map, take_while, Parsed, Parser};
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+        parse_records(input)
+    }
+
+    fn part_one(records: &Vec<Record>) -> Answer {
+        part_one(records).into()
+    }
+
+    fn part_two(records: &Vec<Record>) -> Answer {
+        part_two(records).into()
+    }
+}
+
+/// The records are in the input in any order, but their timestamps sort
+/// into the order they happened.
+fn parse_records(input: &str) -> Result<Vec<Record>, ParseError> {
+    let mut records = lines(input, complete(Record::parse))?;
+    records.sort_unstable_by_key(|&(timestamp, _)| timestamp);
+    Ok(records.into_iter().map(|(_, record)| record).collect())
+}
+
+fn part_one(records: &Vec<Record>) -> usize {
+    let guard = find_guard_with_most_sleep(records);
+    guard * most_common_sleeping_time(records, guard)
+}
+
+fn part_two(records: &Vec<Record>) -> usize {
+    let (guard, time) = most_asleep(records);
+    guard * time
+}
+
+pub enum Record {
+    NewGuard(usize),
+    WokeUp(usize),
+    FellAsleep(usize),
+}
+
+impl Record {
+    /// Parses a record like `[1518-11-01 00:05] falls asleep`, along with
+    /// its timestamp.
+    fn parse(input: &str) -> Parsed<'_, (&str, Record)> {
+        let (timestamp, s) = ("[", take_while("a timestamp", |c| c != ']')).read(input)?;
+        let (_, s) = "] ".read(s)?;
+        let time: usize = number(split_once(timestamp, ":")?.1)?;
+
+        let new_guard = |s| {
+            let (guard, s) = ("Guard #", int).read(s)?;
+            let (_, s) = " begins shift".read(s)?;
+            Ok((Record::NewGuard(guard), s))
+        };
+        let fell_asleep = map("falls asleep", |_| Record::FellAsleep(time));
+        let woke_up = map("wakes up", |_| Record::WokeUp(time));
+
+        let (record, s) = either(new_guard, either(fell_asleep, woke_up)).read(s)?;
+        Ok(((timestamp, record), s))
+    }
+}
+
+fn find_guard_with_most_sleep(records: &[Record]) -> usize {
+    let mut current_guard = 0;
+    let mut fell_asleep: usize = 0;
+
+    let mut sleep = HashMap::new();
+
+    for record in records.iter() {
+        match record {
+            Record::NewGuard(guard) => current_guard = *guard,
+            Record::FellAsleep(time) => fell_asleep = *time,
+            Record::WokeUp(time) => {
+                sleep.insert(
+                    current_guard,
+                    sleep.get(&current_guard).unwrap_or(&0) + time - fell_asleep,
+                );
+            }
+        }
+    }
+
+    *sleep
+        .iter()
+        .max_by_key(|(_, &time)| time)
+        .map(|x| x.0)
+        .unwrap()
+}
+
+fn most_common_sleeping_time(records: &Vec<Record>, guard: usize) -> usize {
+    let mut sleep = [0; 60];
+
+    let mut correct_guard = false;
+    let mut fell_asleep: usize = 0;
+
+    for record in records {
+        match record {
+            Record::NewGuard(g) => correct_guard = guard == *g,
+            Record::FellAsleep(time) => fell_asleep = *time,
+            Record::WokeUp(time) => {
+                if correct_guard {
+                    for i in fell_asleep..*time {
+                        sleep[i] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    sleep
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, &v)| v)
+        .map(|x| x.0)
+        .unwrap()
+}
+
+fn most_asleep(records: &Vec<Record>) -> (usize, usize) {
+    let mut sleep = HashMap::new();
+    let mut current_guard = 0;
+    let mut fell_asleep = 0;
+
+    for record in records {
+        match record {
+            Record::NewGuard(guard) => current_guard = *guard,
+            Record::FellAsleep(time) => fell_asleep = *time,
+            Record::WokeUp(time) => {
+                for t in fell_asleep..*time {
+                    let key = (current_guard, t);
+                    sleep.insert(key, sleep.get(&key).unwrap_or(&0) + 1);
+                }
+            }
+        }
+    }
+
+    *sleep
+        .iter()
+        .max_by_key(|(_, &v)| v)
+        .map(|(k, _)| k)
+        .unwrap()
+}
@@ -0,0 +1,102 @@
+use aoc_parse::{each_char, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 5);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+        line(input, |s| {
+            each_char(s, "a unit", |c| c.is_ascii_alphabetic().then_some(c))
+        })
+    }
+
+    fn part_one(polymer: &Vec<char>) -> Answer {
+        reduce_polymer(polymer).into()
+    }
+
+    fn part_two(polymer: &Vec<char>) -> Answer {
+        problem_unit(polymer).into()
+    }
+}
+
+fn reduce_polymer(chars: &[char]) -> usize {
+    let mut stack = Vec::with_capacity(chars.len());
+    let mut last: Option<&char> = chars.first();
+
+    for c in chars.iter().skip(1) {
+        if last.is_none() {
+            last = Some(c);
+            continue;
+        }
+
+        if reacts(c, last.unwrap()) {
+            last = stack.pop();
+            continue;
+        }
+
+        stack.push(last.unwrap());
+        last = Some(c);
+    }
+
+    if let Some(c) = last {
+        stack.push(c);
+    }
+
+    stack.len()
+}
+
+fn problem_unit(polymer: &[char]) -> usize {
+    let alphabet = "abcdefghijklmnopqrstuvwxyz";
+    alphabet
+        .chars()
+        .map(|c| {
+            let polymer = remove_unit(polymer, &c);
+            reduce_polymer(&polymer)
+        })
+        .min()
+        .unwrap()
+}
+
+fn reacts(a: &char, b: &char) -> bool {
+    a != b && a.eq_ignore_ascii_case(b)
+}
+
+fn remove_unit(polymer: &[char], unit: &char) -> Vec<char> {
+    polymer
+        .iter()
+        .filter(|&c| !c.eq_ignore_ascii_case(unit))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reacts_test() {
+        assert!(reacts(&'a', &'A'), "aA");
+        assert!(!reacts(&'a', &'a'), "aa");
+        assert!(!reacts(&'b', &'a'), "ba");
+    }
+
+    #[test]
+    fn reduce_test() {
+        let input = Puzzle::parse("dabAcCaCBAcCcaDA\n").unwrap();
+        assert_eq!(reduce_polymer(&input), 10);
+    }
+
+    #[test]
+    fn remove_unit_test() {
+        assert_eq!(
+            remove_unit(&Puzzle::parse("dabAcCaCBAcCcaDA").unwrap(), &'a')
+                .iter()
+                .collect::<String>(),
+            String::from("dbcCCBcCcD")
+        );
+    }
+}
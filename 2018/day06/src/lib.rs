@@ -0,0 +1,145 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+type Point = (usize, usize);
+type PointMap = HashMap<Point, usize>;
+type Grid = Vec<Vec<usize>>;
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = PointMap;
+
+    fn parse(input: &str) -> Result<PointMap, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(points: &PointMap) -> Answer {
+        part_one(points).into()
+    }
+
+    fn part_two(points: &PointMap) -> Answer {
+        safe_points(points.keys().copied().collect()).into()
+    }
+}
+
+fn part_one(points: &PointMap) -> usize {
+    let grid = populate_grid(points);
+    *get_counts(&grid).values().max().unwrap()
+}
+
+fn distance(p: Point, q: Point) -> usize {
+    let mut d = 0;
+    d += p.0.abs_diff(q.0);
+    d += p.1.abs_diff(q.1);
+    d
+}
+
+fn populate_grid(points: &PointMap) -> Grid {
+    let max_x = points.keys().map(|p| p.0).max().unwrap();
+    let max_y = points.keys().map(|p| p.1).max().unwrap();
+    let mut grid: Grid = vec![vec![0; max_y + 2]; max_x + 2];
+
+    for x in 0..max_x + 2 {
+        for y in 0..max_y + 2 {
+            let mut set = false;
+            let mut min_d = 0;
+            let mut closest = 0;
+            for (&p, &k) in points.iter() {
+                let d = distance(p, (x, y));
+                if !set || d < min_d {
+                    set = true;
+                    min_d = d;
+                    closest = k;
+                    continue;
+                }
+
+                if d == min_d {
+                    closest = 0;
+                }
+            }
+
+            grid[x][y] = closest
+        }
+    }
+
+    grid
+}
+
+fn safe_points(points: HashSet<Point>) -> usize {
+    let max_x = points.iter().map(|p| p.0).max().unwrap();
+    let max_y = points.iter().map(|p| p.1).max().unwrap();
+
+    let mut count = 0;
+
+    for x in 0..max_x {
+        for y in 0..max_y {
+            let mut sum = 0;
+            for p in points.iter() {
+                sum += p.0.abs_diff(x);
+                sum += p.1.abs_diff(y);
+            }
+            if sum < 10000 {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn get_counts(grid: &Grid) -> HashMap<usize, usize> {
+    let edges = get_edge_pieces(grid);
+
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for row in grid.iter() {
+        for &v in row.iter() {
+            if !edges.contains(&v) {
+                counts.insert(v, counts.get(&v).unwrap_or(&0) + 1);
+            }
+        }
+    }
+    counts
+}
+
+fn get_edge_pieces(grid: &Grid) -> HashSet<usize> {
+    let mut edges: HashSet<usize> = HashSet::new();
+
+    for &k in grid[0].iter() {
+        edges.insert(k);
+    }
+
+    for row in grid.iter() {
+        let mut row = row.iter();
+        edges.insert(*row.next().unwrap());
+        edges.insert(*row.last().unwrap());
+    }
+
+    for &k in grid[grid.len() - 1].iter() {
+        edges.insert(k);
+    }
+
+    edges
+}
+
+fn parse_input(input: &str) -> Result<PointMap, ParseError> {
+    let points: Vec<Point> = lines(input, |line| {
+        let (x, y) = split_once(line, ", ")?;
+        Ok((number(x)?, number(y)?))
+    })?;
+
+    let min_x = points.iter().map(|p| p.0).min();
+    let min_y = points.iter().map(|p| p.1).min();
+    let (Some(min_x), Some(min_y)) = (min_x, min_y) else {
+        return Err(ParseError::new("there are no coordinates"));
+    };
+
+    Ok(points
+        .into_iter()
+        .enumerate()
+        .map(|(i, p)| ((p.0 - min_x, p.1 - min_y), i + 1))
+        .collect())
+}
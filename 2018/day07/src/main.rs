@@ -2,7 +2,6 @@ extern crate regex;
 
 use regex::Regex;
 use std::collections::HashSet;
-use std::fs;
 
 #[macro_use]
 extern crate lazy_static;
@@ -12,7 +11,7 @@ lazy_static! {
 }
 
 fn main() {
-    let input = fs::read_to_string("input").expect("Couldn't read file");
+    let input = aoc_runner::input(2018, 7);
 
     println!("Part one: {}", running_order(&input));
 }
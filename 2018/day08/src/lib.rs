@@ -0,0 +1,170 @@
+use aoc_parse::{line, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Node;
+
+    fn parse(input: &str) -> Result<Node, ParseError> {
+        line(input, parse_tree)
+    }
+
+    fn part_one(tree: &Node) -> Answer {
+        tree.metadata_sum().into()
+    }
+
+    fn part_two(tree: &Node) -> Answer {
+        tree.value().into()
+    }
+}
+
+fn parse_tree(input: &str) -> Result<Node, ParseError> {
+    let numbers = input
+        .split_whitespace()
+        .map(number)
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    match Node::parse(&numbers)? {
+        (node, size) if size == numbers.len() => Ok(node),
+        (_, size) => Err(ParseError::new(format!(
+            "expected the tree to end after {} numbers, but there are {}",
+            size,
+            numbers.len()
+        ))),
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Node {
+    children: Vec<Node>,
+    metadata: Vec<usize>,
+}
+
+impl Node {
+    /// Reads the node at the start of `input`, and says how many numbers it
+    /// took up.
+    fn parse(input: &[usize]) -> Result<(Node, usize), ParseError> {
+        let too_short = || ParseError::new("expected more numbers, the tree ends early");
+        let (&n_children, &n_metadata) = match input {
+            [n_children, n_metadata, ..] => (n_children, n_metadata),
+            _ => return Err(too_short()),
+        };
+
+        let mut start = 2;
+        let mut children = Vec::new();
+        for _ in 0..n_children {
+            let (child, size) = Node::parse(&input[start..])?;
+            children.push(child);
+            start += size;
+        }
+
+        let metadata = input
+            .get(start..start + n_metadata)
+            .ok_or_else(too_short)?
+            .to_vec();
+        Ok((Node { children, metadata }, start + n_metadata))
+    }
+
+    fn metadata_sum(&self) -> usize {
+        let child_sum: usize = self.children.iter().map(|c| c.metadata_sum()).sum();
+        let meta_sum: usize = self.metadata.iter().sum();
+        child_sum + meta_sum
+    }
+
+    fn value(&self) -> usize {
+        if self.children.is_empty() {
+            return self.metadata.iter().sum();
+        }
+
+        self.metadata
+            .iter()
+            .map(|m| self.children.get(m - 1).map_or(0, |c| c.value()))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn node_parsing_test() {
+        assert_eq!(
+            Node::parse(&[0, 1, 99]),
+            Ok((
+                Node {
+                    children: Vec::new(),
+                    metadata: vec![99]
+                },
+                3
+            ))
+        );
+    }
+
+    #[test]
+    fn _node_with_children_test() {
+        let input = vec![2, 3, 0, 3, 10, 11, 12, 1, 1, 0, 1, 99, 2, 1, 1, 2];
+        let d = Node {
+            children: vec![],
+            metadata: vec![99],
+        };
+        let c = Node {
+            children: vec![d],
+            metadata: vec![2],
+        };
+        let b = Node {
+            children: vec![],
+            metadata: vec![10, 11, 12],
+        };
+        let a = Node {
+            children: vec![b, c],
+            metadata: vec![1, 1, 2],
+        };
+        let (node, _) = Node::parse(&input).unwrap();
+        assert_eq!(node, a);
+    }
+
+    #[test]
+    fn metadata_sum_test() {
+        let input = vec![2, 3, 0, 3, 10, 11, 12, 1, 1, 0, 1, 99, 2, 1, 1, 2];
+        let (node, _) = Node::parse(&input).unwrap();
+        assert_eq!(node.metadata_sum(), 138);
+    }
+
+    #[test]
+    fn childless_node_value_test() {
+        let b = Node {
+            children: vec![],
+            metadata: vec![10, 11, 12],
+        };
+        assert_eq!(b.value(), 33);
+    }
+
+    #[test]
+    fn node_value_with_children_test() {
+        let input = vec![2, 3, 0, 3, 10, 11, 12, 1, 1, 0, 1, 99, 2, 1, 1, 2];
+        let (node, _) = Node::parse(&input).unwrap();
+        assert_eq!(node.value(), 66);
+    }
+
+    #[test]
+    fn parse_test() {
+        let tree = Puzzle::parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\n").unwrap();
+        assert_eq!(tree.metadata_sum(), 138);
+
+        let err = Puzzle::parse("1 1 0 1 99\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: expected more numbers, the tree ends early"
+        );
+
+        let err = Puzzle::parse("0 1 99 7\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: expected the tree to end after 3 numbers, but there are 4"
+        );
+    }
+}
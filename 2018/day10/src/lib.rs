@@ -0,0 +1,140 @@
+use aoc_parse::combinators::{complete, field, int, list, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 10);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+        lines(input, complete(Point::parse))
+    }
+
+    fn part_one(points: &Vec<Point>) -> Answer {
+        message(points).0.into()
+    }
+
+    fn part_two(points: &Vec<Point>) -> Answer {
+        message(points).1.into()
+    }
+}
+
+/// The message the points spell out when they line up, and when that is.
+fn message(points: &[Point]) -> (String, i64) {
+    let (min_t, max_t) = candidate_time_range(points);
+
+    let mut scores = HashMap::new();
+    for t in min_t..max_t {
+        scores.insert(
+            t,
+            fitness(&points.iter().map(|p| p.advance(t)).collect::<Vec<_>>()),
+        );
+    }
+
+    let t = *scores
+        .iter()
+        .max_by_key(|&(_, s)| s)
+        .map(|(i, _)| i)
+        .unwrap();
+
+    let points: Vec<Point> = points.iter().map(|p| p.advance(t)).collect();
+    let lit = points.iter().map(|p| (p.x, p.y));
+    let text = aoc_ocr::read_points(lit).unwrap_or_else(|err| panic!("{}", err));
+    (text, t)
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Point {
+    x: i64,
+    y: i64,
+    v_x: i64,
+    v_y: i64,
+}
+
+impl Point {
+    /// Parses a point like `position=< 7,  0> velocity=<-1,  0>`.
+    fn parse(input: &str) -> Parsed<'_, Point> {
+        let ((x, y), s) = field("position", vector).read(input)?;
+        let ((v_x, v_y), s) = (" ", field("velocity", vector)).read(s)?;
+        Ok((Point { x, y, v_x, v_y }, s))
+    }
+
+    fn advance(&self, t: i64) -> Point {
+        Point {
+            x: self.x + t * self.v_x,
+            y: self.y + t * self.v_y,
+            ..*self
+        }
+    }
+
+    fn intersection_time(&self, other: &Point) -> Result<i64, &str> {
+        let d = (self.v_x - other.v_x) * (self.v_x - other.v_x)
+            + (self.v_y - other.v_y) * (self.v_y - other.v_y);
+        if d == 0 {
+            return Err("Moving parallel");
+        }
+
+        let n = (self.x - other.x) * (self.v_x - other.v_x)
+            + (self.y - other.y) * (self.v_y - other.v_y);
+        Ok(-n / d)
+    }
+}
+
+/// A pair of numbers in angle brackets, like `< 7,  0>`.
+fn vector(input: &str) -> Parsed<'_, (i64, i64)> {
+    let (coords, s) = ("<", list(int)).read(input)?;
+    let (_, s) = ">".read(s)?;
+    match coords[..] {
+        [x, y] => Ok(((x, y), s)),
+        _ => Err(ParseError::expected("two numbers", input)),
+    }
+}
+
+fn candidate_time_range(points: &[Point]) -> (i64, i64) {
+    let mut times = HashSet::new();
+    for (i, p) in points.iter().enumerate() {
+        for q in points.iter().skip(i) {
+            if let Ok(t) = p.intersection_time(q) {
+                times.insert(t);
+            }
+        }
+    }
+
+    (*times.iter().min().unwrap(), *times.iter().max().unwrap())
+}
+
+fn fitness(points: &[Point]) -> usize {
+    let mut f = 0;
+    for p in points.iter() {
+        for q in points.iter() {
+            if (p.x - q.x).abs() + (p.y - q.y).abs() == 1 {
+                f += 1;
+            }
+        }
+    }
+
+    f
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let input = "position=< 7,  0> velocity=<-1,  0>";
+        assert_eq!(
+            complete(Point::parse)(input).unwrap(),
+            Point {
+                x: 7,
+                y: 0,
+                v_x: -1,
+                v_y: 0
+            }
+        );
+    }
+}
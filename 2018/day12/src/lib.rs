@@ -0,0 +1,161 @@
+use aoc_parse::{each_char, line, lines, split_once, strip_prefix};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashSet, VecDeque};
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 12);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (HashSet<i64>, HashSet<Rule>);
+
+    fn parse(input: &str) -> Result<(HashSet<i64>, HashSet<Rule>), ParseError> {
+        let (initial_state, rules) = split_once(input, "\n\n")?;
+        let pots = line(initial_state, parse_initial_state)?;
+        let rules = lines(rules, parse_rule).map_err(|err| err.within(3, rules))?;
+
+        let rules = rules
+            .into_iter()
+            .filter(|&(_, spreads)| spreads)
+            .map(|(rule, _)| rule)
+            .collect();
+        Ok((pots, rules))
+    }
+
+    fn part_one((pots, rules): &(HashSet<i64>, HashSet<Rule>)) -> Answer {
+        part_one(pots.clone(), rules).into()
+    }
+
+    fn part_two((pots, rules): &(HashSet<i64>, HashSet<Rule>)) -> Answer {
+        part_two(pots.clone(), rules).into()
+    }
+}
+
+fn part_one(mut pots: HashSet<i64>, rules: &HashSet<Rule>) -> i64 {
+    for _ in 0..20 {
+        pots = iterate(pots, rules);
+    }
+
+    pots.iter().sum()
+}
+
+/// After a while the pots settle into a pattern that moves along by the same
+/// amount each generation, so run until the sum grows steadily and
+/// extrapolate from there.
+fn part_two(mut pots: HashSet<i64>, rules: &HashSet<Rule>) -> i64 {
+    let mut sum: i64 = pots.iter().sum();
+    let mut diffs: VecDeque<i64> = VecDeque::new();
+    let mut iteration = 0;
+    while diffs.len() < 10 || diffs.iter().min().unwrap() != diffs.iter().max().unwrap() {
+        pots = iterate(pots, rules);
+        let next_sum: i64 = pots.iter().sum();
+
+        let diff = next_sum - sum;
+        diffs.push_back(diff);
+        if diffs.len() > 10 {
+            diffs.pop_front();
+        }
+
+        sum = next_sum;
+        iteration += 1;
+    }
+
+    sum + (50000000000 - iteration) * diffs[0]
+}
+
+fn iterate(pots: HashSet<i64>, rules: &HashSet<Rule>) -> HashSet<i64> {
+    let min = pots.iter().min().unwrap();
+    let max = pots.iter().max().unwrap();
+
+    let mut next = HashSet::new();
+    for p in min - 4..max + 5 {
+        if will_spread(&pots, rules, &p) {
+            next.insert(p);
+        }
+    }
+
+    next
+}
+
+fn will_spread(pots: &HashSet<i64>, rules: &HashSet<Rule>, p: &i64) -> bool {
+    let spread = (
+        pots.contains(&(p - 2)),
+        pots.contains(&(p - 1)),
+        pots.contains(p),
+        pots.contains(&(p + 1)),
+        pots.contains(&(p + 2)),
+    );
+
+    rules.contains(&spread)
+}
+
+fn parse_initial_state(line: &str) -> Result<HashSet<i64>, ParseError> {
+    let pots = pots(strip_prefix(line, "initial state: ")?)?;
+    Ok((0..)
+        .zip(pots)
+        .filter(|&(_, plant)| plant)
+        .map(|(i, _)| i)
+        .collect())
+}
+
+type Rule = (bool, bool, bool, bool, bool);
+
+/// A rule like `.#.#. => #`, and whether it leaves a plant in the pot.
+fn parse_rule(line: &str) -> Result<(Rule, bool), ParseError> {
+    let (pattern, result) = split_once(line, " => ")?;
+    let rule = match pots(pattern)?[..] {
+        [a, b, c, d, e] => (a, b, c, d, e),
+        _ => return Err(ParseError::expected("five pots", pattern)),
+    };
+
+    match pots(result)?[..] {
+        [spreads] => Ok((rule, spreads)),
+        _ => Err(ParseError::expected("one pot", result)),
+    }
+}
+
+/// Which of the pots have plants in them.
+fn pots(s: &str) -> Result<Vec<bool>, ParseError> {
+    each_char(s, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_initial_state_test() {
+        let line = "initial state: #..##....";
+        let mut expected: HashSet<i64> = HashSet::new();
+        expected.insert(0);
+        expected.insert(3);
+        expected.insert(4);
+        assert_eq!(Ok(expected), parse_initial_state(line));
+    }
+
+    #[test]
+    fn parse_test() {
+        let input = "initial state: #\n\n.#### => .\n##.## => #\n#.#.# => .\n.#.#. => #\n";
+
+        let mut expected: HashSet<Rule> = HashSet::new();
+        expected.insert((true, true, false, true, true));
+        expected.insert((false, true, false, true, false));
+        assert_eq!(expected, Puzzle::parse(input).unwrap().1);
+
+        let err = Puzzle::parse("initial state: #\n\n.#### => .\n##.# => #\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected five pots, found \"##.#\""
+        );
+
+        let err = Puzzle::parse("initial state: #\n\n.#### => .\n##.#o => #\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 5: expected '#' or '.', found \"o\""
+        );
+    }
+}
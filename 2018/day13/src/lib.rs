@@ -0,0 +1,210 @@
+use aoc_parse::{each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 13);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Map, Carts);
+
+    fn parse(input: &str) -> Result<(Map, Carts), ParseError> {
+        lines(input, |line| {
+            each_char(line, "a track or a cart", |c| {
+                " -|/\\+^>v<".contains(c).then_some(c)
+            })
+        })?;
+
+        // Carts crash in pairs, so there has to be an odd number of them for
+        // one to be left at the end
+        let carts = parse_carts(input);
+        if carts.len() < 3 || carts.len().is_multiple_of(2) {
+            return Err(ParseError::new(format!(
+                "expected an odd number of carts, more than one, but there are {}",
+                carts.len()
+            )));
+        }
+
+        Ok((parse_map(input), carts))
+    }
+
+    fn part_one((map, carts): &(Map, Carts)) -> Answer {
+        let (x, y) = run_carts(map, carts.clone()).0;
+        format!("{},{}", x, y).into()
+    }
+
+    fn part_two((map, carts): &(Map, Carts)) -> Answer {
+        let (x, y) = run_carts(map, carts.clone()).1;
+        format!("{},{}", x, y).into()
+    }
+}
+
+/// Runs the carts until only one is left, returning where the first crash
+/// happened and where the last cart is.
+fn run_carts(map: &Map, mut carts: Carts) -> ((i32, i32), (i32, i32)) {
+    let mut first_crash = None;
+
+    while carts.len() > 1 {
+        carts.sort();
+        let mut to_remove = Vec::new();
+
+        for i in 0..carts.len() {
+            {
+                let cart = carts.get_mut(i).unwrap();
+                cart.turn(map);
+                cart.advance();
+            }
+
+            if let Some(collision) = check_for_collision(&carts) {
+                first_crash.get_or_insert((collision.2, collision.3));
+                to_remove.push(collision.0);
+                to_remove.push(collision.1);
+            }
+        }
+
+        if !to_remove.is_empty() {
+            to_remove.sort_by(|a, b| b.cmp(a));
+            to_remove.dedup();
+            for &i in to_remove.iter() {
+                carts.remove(i);
+            }
+        }
+    }
+
+    let last = &carts[0];
+    (first_crash.unwrap(), (last.x, last.y))
+}
+
+#[derive(Debug)]
+pub enum Corner {
+    NWSE,
+    SWNE,
+    INT,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Cart {
+    x: i32,
+    y: i32,
+    d_x: i32,
+    d_y: i32,
+    turn: usize,
+}
+
+impl Cart {
+    fn advance(&mut self) {
+        self.x += self.d_x;
+        self.y += self.d_y;
+    }
+
+    fn turn(&mut self, map: &Map) {
+        let corner = map.get(&(self.x, self.y));
+        let (d_x, d_y) = match corner {
+            Some(Corner::SWNE) => (-self.d_y, -self.d_x),
+            Some(Corner::NWSE) => (self.d_y, self.d_x),
+            Some(Corner::INT) => {
+                let (d_x, d_y) = match self.turn {
+                    0 => (self.d_y, -self.d_x),
+                    2 => (-self.d_y, self.d_x),
+                    _ => (self.d_x, self.d_y),
+                };
+                self.turn = (self.turn + 1) % 3;
+                (d_x, d_y)
+            }
+            None => (self.d_x, self.d_y),
+        };
+
+        self.d_x = d_x;
+        self.d_y = d_y;
+    }
+}
+
+type Map = HashMap<(i32, i32), Corner>;
+
+type Carts = Vec<Cart>;
+
+fn check_for_collision(carts: &Carts) -> Option<(usize, usize, i32, i32)> {
+    let mut seen = HashMap::new();
+    for (i, cart) in carts.iter().enumerate() {
+        if let Some(existing) = seen.insert((cart.x, cart.y), i) {
+            return Some((existing, i, cart.x, cart.y));
+        }
+    }
+
+    None
+}
+
+fn parse_map(input: &str) -> Map {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            let corners: Vec<((i32, i32), Corner)> = line
+                .chars()
+                .enumerate()
+                .filter_map(|(x, c)| match c {
+                    '/' => Some(((x, y), Corner::SWNE)),
+                    '\\' => Some(((x, y), Corner::NWSE)),
+                    '+' => Some(((x, y), Corner::INT)),
+                    _ => None,
+                })
+                .map(|((x, y), c)| ((x as i32, y as i32), c))
+                .collect();
+
+            corners
+        })
+        .collect()
+}
+
+fn parse_carts(input: &str) -> Carts {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            let carts: Vec<Cart> = line
+                .chars()
+                .enumerate()
+                .filter_map(|(x, c)| {
+                    let dir = match c {
+                        '^' => Some((0, -1)),
+                        '>' => Some((1, 0)),
+                        'v' => Some((0, 1)),
+                        '<' => Some((-1, 0)),
+                        _ => None,
+                    };
+
+                    dir.map(|(d_x, d_y)| Cart {
+                        x: x as i32,
+                        y: y as i32,
+                        d_x,
+                        d_y,
+                        turn: 0,
+                    })
+                })
+                .collect();
+
+            carts
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("/->-\\\n\\-<-/\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected an odd number of carts, more than one, but there are 2"
+        );
+
+        let err = Puzzle::parse("/->-\\\n|   |\n\\-<-x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 5: expected a track or a cart, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use aoc_parse::{each_char, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 14);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        line(input, |s| {
+            each_char(s, "a digit", |c| c.to_digit(10).map(|d| d as usize))
+        })
+    }
+
+    /// The digits are read as a number of recipes for part one, and as the
+    /// scores to look for in part two.
+    fn part_one(digits: &Vec<usize>) -> Answer {
+        next_10(digits.iter().fold(0, |acc, d| 10 * acc + d)).into()
+    }
+
+    fn part_two(digits: &Vec<usize>) -> Answer {
+        how_many_until(digits).into()
+    }
+}
+
+fn next_10(find_after: usize) -> usize {
+    let mut scores = vec![3, 7];
+    let mut elf1 = 0;
+    let mut elf2 = 1;
+
+    while scores.len() < find_after + 10 {
+        let recipe1 = *scores.get(elf1).unwrap();
+        let recipe2 = *scores.get(elf2).unwrap();
+
+        let new_recipe = recipe1 + recipe2;
+        if new_recipe >= 10 {
+            scores.push(new_recipe / 10);
+        }
+        scores.push(new_recipe % 10);
+
+        elf1 = (elf1 + 1 + recipe1) % scores.len();
+        elf2 = (elf2 + 1 + recipe2) % scores.len();
+    }
+
+    scores[find_after..find_after + 10]
+        .iter()
+        .fold(0, |acc, x| 10 * acc + x)
+}
+
+fn how_many_until(looking_for: &[usize]) -> usize {
+    let mut scores = vec![3, 7];
+    let mut elf1 = 0;
+    let mut elf2 = 1;
+
+    loop {
+        let recipe1 = *scores.get(elf1).unwrap();
+        let recipe2 = *scores.get(elf2).unwrap();
+
+        let new_recipe = recipe1 + recipe2;
+        if new_recipe >= 10 {
+            scores.push(new_recipe / 10);
+            if scores.ends_with(looking_for) {
+                return scores.len() - looking_for.len();
+            }
+        }
+
+        scores.push(new_recipe % 10);
+        if scores.ends_with(looking_for) {
+            return scores.len() - looking_for.len();
+        }
+
+        elf1 = (elf1 + 1 + recipe1) % scores.len();
+        elf2 = (elf2 + 1 + recipe2) % scores.len();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn next_10_test() {
+        assert_eq!(5158916779, next_10(9));
+        assert_eq!(124515891, next_10(5));
+        assert_eq!(9251071085, next_10(18));
+        assert_eq!(5941429882, next_10(2018));
+    }
+
+    #[test]
+    fn how_many_until_test() {
+        assert_eq!(9, how_many_until(&[5, 1, 5, 8, 9]));
+        assert_eq!(5, how_many_until(&[0, 1, 2, 4, 5]));
+    }
+}
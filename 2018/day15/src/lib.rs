@@ -0,0 +1,341 @@
+use aoc_grid::{Grid, Pos};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use aoc_search::bfs;
+use std::collections::HashSet;
+use std::fmt;
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 15);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Battle;
+
+    fn parse(input: &str) -> Result<Battle, ParseError> {
+        Battle::parse(input)
+    }
+
+    fn part_one(battle: &Battle) -> Answer {
+        part_one(battle).into()
+    }
+
+    fn part_two(battle: &Battle) -> Answer {
+        part_two(battle).into()
+    }
+}
+
+fn part_one(battle: &Battle) -> usize {
+    let mut battle = battle.clone();
+    while battle.round() {}
+    battle.outcome()
+}
+
+/// The outcome with the least attack power that lets the elves win without
+/// any of them dying.
+fn part_two(battle: &Battle) -> usize {
+    let elves = battle.count(Kind::Elf);
+    (4..)
+        .find_map(|power| {
+            let mut battle = Battle {
+                elf_power: power,
+                ..battle.clone()
+            };
+            // Give up on this power as soon as an elf dies
+            while battle.round() && battle.count(Kind::Elf) == elves {}
+            (battle.count(Kind::Elf) == elves).then(|| battle.outcome())
+        })
+        .unwrap()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Elf,
+    Goblin,
+}
+
+#[derive(Clone, Debug)]
+struct Unit {
+    kind: Kind,
+    pos: Pos,
+    /// Dead units have none left, and are cleared away at the end of the
+    /// round.
+    hp: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Battle {
+    walls: Grid<bool>,
+    units: Vec<Unit>,
+    elf_power: u32,
+    /// How many rounds have been fought all the way through.
+    rounds: usize,
+}
+
+impl Battle {
+    fn parse(input: &str) -> Result<Battle, ParseError> {
+        let map = Grid::try_parse(input, |c| match c {
+            '#' | '.' | 'E' | 'G' => Ok(c),
+            _ => Err(ParseError::expected("'#', '.', 'E' or 'G'", &c.to_string())),
+        })?;
+
+        let units = map
+            .iter()
+            .filter_map(|(pos, &c)| {
+                let kind = match c {
+                    'E' => Kind::Elf,
+                    'G' => Kind::Goblin,
+                    _ => return None,
+                };
+                Some(Unit { kind, pos, hp: 200 })
+            })
+            .collect();
+
+        Ok(Battle {
+            walls: map.map(|&c| c == '#'),
+            units,
+            elf_power: 3,
+            rounds: 0,
+        })
+    }
+
+    /// Gives every unit its turn, in reading order. Returns false if the
+    /// battle ended partway through because a unit had no enemies left.
+    fn round(&mut self) -> bool {
+        self.units.sort_by_key(|unit| reading_order(unit.pos));
+
+        for i in 0..self.units.len() {
+            let kind = self.units[i].kind;
+            if self.units[i].hp == 0 {
+                continue;
+            }
+            if !self.units.iter().any(|u| u.hp > 0 && u.kind != kind) {
+                self.units.retain(|u| u.hp > 0);
+                return false;
+            }
+
+            self.step(i);
+            self.attack(i);
+        }
+
+        self.units.retain(|u| u.hp > 0);
+        self.rounds += 1;
+        true
+    }
+
+    /// Moves unit `i` a step towards the nearest square next to an enemy,
+    /// unless it's next to one already. Ties between squares, and between
+    /// the steps towards the chosen square, go to the first in reading order.
+    fn step(&mut self, i: usize) {
+        let Unit { kind, pos, .. } = self.units[i];
+        let enemies: Vec<Pos> = self
+            .units
+            .iter()
+            .filter(|u| u.hp > 0 && u.kind != kind)
+            .map(|u| u.pos)
+            .collect();
+        if enemies.iter().any(|&enemy| adjacent(pos, enemy)) {
+            return;
+        }
+
+        let occupied: HashSet<Pos> = self
+            .units
+            .iter()
+            .filter(|u| u.hp > 0)
+            .map(|u| u.pos)
+            .collect();
+        let open = |pos: &Pos| {
+            self.walls
+                .neighbours(*pos)
+                .filter(|next| !self.walls[*next] && !occupied.contains(next))
+                .collect::<Vec<_>>()
+        };
+
+        let in_range: HashSet<Pos> = enemies.iter().flat_map(|&enemy| open(&enemy)).collect();
+        let nearest = bfs(pos, open, |square| in_range.contains(square));
+        let Some(&target) = nearest.goals().iter().min_by_key(|&&p| reading_order(p)) else {
+            return;
+        };
+
+        // Work back from the target to see which first steps are on a
+        // shortest path to it
+        let back = bfs(target, open, |square| adjacent(*square, pos));
+        let step = back.goals().iter().min_by_key(|&&p| reading_order(p));
+        self.units[i].pos = *step.unwrap();
+    }
+
+    /// Has unit `i` hit the enemy next to it with the fewest hit points, if
+    /// there is one.
+    fn attack(&mut self, i: usize) {
+        let Unit { kind, pos, .. } = self.units[i];
+        let power = match kind {
+            Kind::Elf => self.elf_power,
+            Kind::Goblin => 3,
+        };
+
+        let target = (0..self.units.len())
+            .filter(|&j| {
+                let unit = &self.units[j];
+                unit.hp > 0 && unit.kind != kind && adjacent(unit.pos, pos)
+            })
+            .min_by_key(|&j| (self.units[j].hp, reading_order(self.units[j].pos)));
+
+        if let Some(j) = target {
+            self.units[j].hp = self.units[j].hp.saturating_sub(power);
+        }
+    }
+
+    fn count(&self, kind: Kind) -> usize {
+        self.units
+            .iter()
+            .filter(|u| u.hp > 0 && u.kind == kind)
+            .count()
+    }
+
+    /// The full rounds fought times the hit points left.
+    fn outcome(&self) -> usize {
+        self.rounds * self.units.iter().map(|u| u.hp as usize).sum::<usize>()
+    }
+}
+
+/// Draws the map the way the puzzle does, with the hit points of the units
+/// on each row after it.
+impl fmt::Display for Battle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.walls.height() {
+            let mut units: Vec<&Unit> = self
+                .units
+                .iter()
+                .filter(|u| u.hp > 0 && u.pos.1 == y)
+                .collect();
+            units.sort_by_key(|u| u.pos.0);
+
+            for x in 0..self.walls.width() {
+                let c = match units.iter().find(|u| u.pos.0 == x) {
+                    Some(unit) if unit.kind == Kind::Elf => 'E',
+                    Some(_) => 'G',
+                    None if self.walls[(x, y)] => '#',
+                    None => '.',
+                };
+                write!(f, "{}", c)?;
+            }
+
+            let hps: Vec<String> = units
+                .iter()
+                .map(|u| match u.kind {
+                    Kind::Elf => format!("E({})", u.hp),
+                    Kind::Goblin => format!("G({})", u.hp),
+                })
+                .collect();
+            if !hps.is_empty() {
+                write!(f, "   {}", hps.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sorts positions top to bottom, then left to right.
+fn reading_order((x, y): Pos) -> (usize, usize) {
+    (y, x)
+}
+
+fn adjacent(a: Pos, b: Pos) -> bool {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1) == 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+    const EXAMPLE4: &str = include_str!("../example4.txt");
+    const EXAMPLE5: &str = include_str!("../example5.txt");
+    const EXAMPLE6: &str = include_str!("../example6.txt");
+    const MOVEMENT: &str = include_str!("../movement.txt");
+
+    fn parse(input: &str) -> Battle {
+        Puzzle::parse(input).unwrap()
+    }
+
+    #[test]
+    fn part_one_test() {
+        assert_eq!(part_one(&parse(EXAMPLE)), 27730);
+        assert_eq!(part_one(&parse(EXAMPLE2)), 36334);
+        assert_eq!(part_one(&parse(EXAMPLE3)), 39514);
+        assert_eq!(part_one(&parse(EXAMPLE4)), 27755);
+        assert_eq!(part_one(&parse(EXAMPLE5)), 28944);
+        assert_eq!(part_one(&parse(EXAMPLE6)), 18740);
+    }
+
+    #[test]
+    fn part_two_test() {
+        assert_eq!(part_two(&parse(EXAMPLE)), 4988);
+        assert_eq!(part_two(&parse(EXAMPLE3)), 31284);
+        assert_eq!(part_two(&parse(EXAMPLE4)), 3478);
+        assert_eq!(part_two(&parse(EXAMPLE5)), 6474);
+        assert_eq!(part_two(&parse(EXAMPLE6)), 1140);
+    }
+
+    #[test]
+    fn rounds_test() {
+        let mut battle = parse(EXAMPLE);
+        battle.round();
+        battle.round();
+        assert_eq!(
+            battle.to_string(),
+            "#######\n\
+             #...G.#   G(200)\n\
+             #..GEG#   G(200), E(188), G(194)\n\
+             #.#.#G#   G(194)\n\
+             #...#E#   E(194)\n\
+             #.....#\n\
+             #######\n"
+        );
+
+        while battle.round() {}
+        assert_eq!(battle.rounds, 47);
+        assert_eq!(
+            battle.to_string(),
+            "#######\n\
+             #G....#   G(200)\n\
+             #.G...#   G(131)\n\
+             #.#.#G#   G(59)\n\
+             #...#.#\n\
+             #....G#   G(200)\n\
+             #######\n"
+        );
+    }
+
+    #[test]
+    fn movement_test() {
+        let mut battle = parse(MOVEMENT);
+        for _ in 0..3 {
+            battle.round();
+        }
+
+        // Just the map, without the hit points after it
+        let drawing = battle.to_string();
+        let map: Vec<&str> = drawing
+            .lines()
+            .map(|line| line.split(' ').next().unwrap())
+            .collect();
+        assert_eq!(
+            map,
+            [
+                "#########",
+                "#.......#",
+                "#..GGG..#",
+                "#..GEG..#",
+                "#G..G...#",
+                "#......G#",
+                "#.......#",
+                "#.......#",
+                "#########",
+            ]
+        );
+    }
+}
@@ -1,3 +1,3 @@
 fn main() {
-    println!("Hello, world!");
+    aoc_runner::run(&aoc_2018_day15::DAY);
 }
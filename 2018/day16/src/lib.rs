@@ -1,95 +1,168 @@
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-pub enum Op {
-    Addr,
-    Addi,
-    Mulr,
-    Muli,
-    Banr,
-    Bani,
-    Borr,
-    Bori,
-    Setr,
-    Seti,
-    Gtir,
-    Gtri,
-    Gtrr,
-    Eqir,
-    Eqri,
-    Eqrr,
+mod ops;
+
+use aoc_parse::{blocks, lines, number, split_once, strip_prefix, strip_suffix};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use ops::{all_ops, apply_op, Op};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 16);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Vec<Sample>, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<(Vec<Sample>, Vec<Instruction>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((samples, _): &(Vec<Sample>, Vec<Instruction>)) -> Answer {
+        part_one(samples).into()
+    }
+
+    fn part_two((samples, program): &(Vec<Sample>, Vec<Instruction>)) -> Answer {
+        part_two(samples, program).into()
+    }
+}
+
+type Instruction = [usize; 4];
+
+/// The input is the samples, then three blank lines, then the test program.
+fn parse_input(input: &str) -> Result<(Vec<Sample>, Vec<Instruction>), ParseError> {
+    let (samples, program) = split_once(input, "\n\n\n\n")?;
+
+    let first_instruction = samples.lines().count() + 4;
+    let program =
+        lines(program, parse_instruction).map_err(|err| err.within(first_instruction, program))?;
+
+    Ok((blocks(samples, parse_sample)?, program))
 }
 
-pub fn all_ops() -> Vec<Op> {
-    use self::Op::*;
-    vec![
-        Addr, Addi, Mulr, Muli, Banr, Bani, Borr, Bori, Setr, Seti, Gtir, Gtri, Gtrr, Eqir, Eqri,
-        Eqrr,
-    ]
+/// Counts the samples that behave like three or more opcodes.
+fn part_one(samples: &[Sample]) -> usize {
+    samples
+        .iter()
+        // Passing 3 or more means failing 13 or fewer
+        .filter(|sample| test_sample(sample).len() <= 13)
+        .count()
 }
 
-pub fn apply_op(op: &Op, r: &[usize; 4], a: usize, b: usize) -> usize {
-    match op {
-        Op::Addr => r[a] + r[b],
-        Op::Addi => r[a] + b,
-        Op::Mulr => r[a] * r[b],
-        Op::Muli => r[a] * b,
-        Op::Banr => r[a] & r[b],
-        Op::Bani => r[a] & b,
-        Op::Borr => r[a] | r[b],
-        Op::Bori => r[a] | b,
-        Op::Setr => r[a],
-        Op::Seti => a,
-        Op::Gtir => {
-            if a > r[b] {
-                1
-            } else {
-                0
-            }
-        }
-        Op::Gtri => {
-            if r[a] > b {
-                1
-            } else {
-                0
-            }
-        }
-        Op::Gtrr => {
-            if r[a] > r[b] {
-                1
-            } else {
-                0
-            }
+/// Works out which number is which opcode, then runs the test program and
+/// returns what's left in register 0.
+fn part_two(samples: &[Sample], program: &[Instruction]) -> usize {
+    let mut possible: HashSet<(usize, Op)> = HashSet::new();
+
+    // Create a list of every possible combination
+    for n in 0..16 {
+        for op in all_ops() {
+            possible.insert((n, op));
         }
-        Op::Eqir => {
-            if a == r[b] {
-                1
-            } else {
-                0
-            }
+    }
+
+    // Find the ones that fail and remove them from the set
+    for sample in samples {
+        for op in test_sample(sample) {
+            possible.remove(&(sample.instruction[0], op));
         }
-        Op::Eqri => {
-            if r[a] == b {
-                1
-            } else {
-                0
-            }
+    }
+
+    let mut op_map: HashMap<usize, Op> = HashMap::new();
+
+    // Repeatedly find codes that map to a single instruction
+    // and remove them (and any related ones) from the set
+    for _ in 0..16 {
+        let (instr, op) = find_single(&possible);
+        op_map.insert(instr, op);
+
+        possible.remove(&(instr, op));
+        for i in 0..16 {
+            possible.remove(&(i, op));
         }
-        Op::Eqrr => {
-            if r[a] == r[b] {
-                1
-            } else {
-                0
-            }
+    }
+
+    // Run operations on the instructions
+    let mut r = [0, 0, 0, 0];
+    for i in program {
+        let op = op_map.get(&i[0]).unwrap();
+        let ans = apply_op(op, &r, i[1], i[2]);
+        r[i[3]] = ans;
+    }
+
+    r[0]
+}
+
+fn find_single(ops: &HashSet<(usize, Op)>) -> (usize, Op) {
+    for i in 0..16 {
+        let matches: Vec<(usize, Op)> = ops.iter().filter(|(n, _)| *n == i).cloned().collect();
+        if matches.len() == 1 {
+            return matches[0];
         }
     }
+
+    panic!("no instructions with a single op");
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+fn test_sample(sample: &Sample) -> Vec<Op> {
+    all_ops()
+        .into_iter()
+        .filter(|op| {
+            apply_op(
+                op,
+                &sample.before,
+                sample.instruction[1],
+                sample.instruction[2],
+            ) != sample.after[sample.instruction[3]]
+        })
+        .collect()
+}
 
-    #[test]
-    fn ops_test() {
-        assert_eq!(5, apply_op(&Op::Addr, &[1, 3, 2, 5], 1, 2));
-        assert_eq!(8, apply_op(&Op::Addi, &[1, 3, 2, 5], 1, 5));
-    }
+#[derive(Debug)]
+pub struct Sample {
+    before: [usize; 4],
+    instruction: Instruction,
+    after: [usize; 4],
+}
+
+/// Parses a sample like:
+///
+/// ```text
+/// Before: [3, 2, 1, 1]
+/// 9 2 1 2
+/// After:  [3, 2, 2, 1]
+/// ```
+fn parse_sample(block: &str) -> Result<Sample, ParseError> {
+    let mut lines = block.lines();
+    let mut next = || {
+        lines
+            .next()
+            .ok_or_else(|| ParseError::new("the sample ends early"))
+    };
+
+    let before = parse_registers(strip_prefix(next()?, "Before:")?)?;
+    let instruction = parse_instruction(next()?)?;
+    let after = parse_registers(strip_prefix(next()?, "After:")?)?;
+
+    Ok(Sample {
+        before,
+        instruction,
+        after,
+    })
+}
+
+fn parse_registers(s: &str) -> Result<[usize; 4], ParseError> {
+    let s = strip_suffix(strip_prefix(s.trim(), "[")?, "]")?;
+    four_numbers(s, s.split(", "))
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    four_numbers(line, line.split_whitespace())
+}
+
+fn four_numbers<'a>(
+    s: &str,
+    parts: impl Iterator<Item = &'a str>,
+) -> Result<[usize; 4], ParseError> {
+    let nums: Vec<usize> = parts.map(number).collect::<Result<_, _>>()?;
+    nums.try_into()
+        .map_err(|_| ParseError::expected("four numbers", s))
 }
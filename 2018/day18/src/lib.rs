@@ -0,0 +1,148 @@
+use aoc_grid::Grid;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2018, 18);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Yard;
+
+    fn parse(input: &str) -> Result<Yard, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(yard: &Yard) -> Answer {
+        part_one(yard).into()
+    }
+
+    fn part_two(yard: &Yard) -> Answer {
+        part_two(yard).into()
+    }
+}
+
+fn part_one(yard: &Yard) -> usize {
+    let mut yard = yard.clone();
+
+    for _ in 0..10 {
+        yard = transform(&yard);
+    }
+    total_resource_value(&yard)
+}
+
+fn part_two(yard: &Yard) -> usize {
+    // Try and find a repeat period for the scores
+    let mut yard = yard.clone();
+    let mut scores = Vec::new();
+    let period;
+    loop {
+        yard = transform(&yard);
+        let val = total_resource_value(&yard);
+        scores.push(val);
+
+        let prev = scores
+            .iter()
+            .enumerate()
+            .rev()
+            .skip(1)
+            .find(|&(_, x)| x == &val)
+            .map(|(i, _)| i);
+
+        if prev.is_none() {
+            continue;
+        }
+        let prev = prev.unwrap();
+
+        // Try and find 3 repeating values
+        if prev > 5 {
+            let mut matches = true;
+            for i in 1..5 {
+                if scores[prev - i] != scores[scores.len() - i - 1] {
+                    matches = false;
+                    break;
+                }
+            }
+
+            if matches {
+                period = scores.len() - prev - 1;
+                break;
+            }
+        }
+    }
+
+    let target = 1_000_000_000;
+    let offset = (target - scores.len()) % period;
+    scores[scores.len() - 1 - period + offset]
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Acre {
+    Open,
+    Tree,
+    Lumber,
+}
+
+pub type Yard = Grid<Acre>;
+
+fn transform(yard: &Yard) -> Yard {
+    Grid::from_fn(yard.width(), yard.height(), |pos| {
+        let adjacent: Vec<Acre> = yard.neighbours8(pos).map(|n| yard[n]).collect();
+        contents(yard[pos], adjacent)
+    })
+}
+
+fn contents(current: Acre, adjacent: Vec<Acre>) -> Acre {
+    match current {
+        Acre::Open => {
+            let trees = adjacent.iter().filter(|&a| *a == Acre::Tree).count();
+            if trees >= 3 {
+                Acre::Tree
+            } else {
+                Acre::Open
+            }
+        }
+        Acre::Tree => {
+            let lumberyards = adjacent.iter().filter(|&a| *a == Acre::Lumber).count();
+            if lumberyards >= 3 {
+                Acre::Lumber
+            } else {
+                Acre::Tree
+            }
+        }
+        Acre::Lumber => {
+            if adjacent.contains(&Acre::Lumber) && adjacent.contains(&Acre::Tree) {
+                Acre::Lumber
+            } else {
+                Acre::Open
+            }
+        }
+    }
+}
+
+fn total_resource_value(yard: &Yard) -> usize {
+    let trees = yard.values().filter(|&&a| a == Acre::Tree).count();
+    let lumberyards = yard.values().filter(|&&a| a == Acre::Lumber).count();
+
+    trees * lumberyards
+}
+
+fn parse_input(input: &str) -> Result<Yard, ParseError> {
+    Grid::try_parse(input, |c| match c {
+        '.' => Ok(Acre::Open),
+        '|' => Ok(Acre::Tree),
+        '#' => Ok(Acre::Lumber),
+        _ => Err(ParseError::expected("'.', '|' or '#'", &c.to_string())),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 1147);
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp::max;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        lines(input, number)
+    }
+
+    fn part_one(masses: &Vec<i64>) -> Answer {
+        masses
+            .iter()
+            .map(|&mass| fuel_needed(mass))
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part_two(masses: &Vec<i64>) -> Answer {
+        masses
+            .iter()
+            .map(|&mass| fuel_needed_total(mass))
+            .sum::<i64>()
+            .into()
+    }
+}
+
+fn fuel_needed(mass: i64) -> i64 {
+    max(mass / 3 - 2, 0)
+}
+
+fn fuel_needed_total(mass: i64) -> i64 {
+    match fuel_needed(mass) {
+        0 => 0,
+        fuel => fuel + fuel_needed_total(fuel),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuel_needed_test() {
+        assert_eq!(fuel_needed(14), 2);
+        assert_eq!(fuel_needed(12), 2);
+        assert_eq!(fuel_needed(1969), 654);
+        assert_eq!(fuel_needed(100756), 33583);
+        assert_eq!(fuel_needed(5), 0);
+    }
+
+    #[test]
+    fn fuel_needed_rec_test() {
+        assert_eq!(fuel_needed_total(14), 2);
+        assert_eq!(fuel_needed_total(1969), 966);
+        assert_eq!(fuel_needed_total(100756), 50346);
+    }
+}
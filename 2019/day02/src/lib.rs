@@ -0,0 +1,64 @@
+use aoc_intcode::{read_program, IntCodeComputer};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        run_simulation(program, 12, 2).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        part_two(program).into()
+    }
+}
+
+/// Finds the noun and verb that produce 19690720.
+fn part_two(program: &[i64]) -> i64 {
+    for i in 0..100 {
+        for j in 0..100 {
+            if run_simulation(program, i, j) == 19690720 {
+                return 100 * i + j;
+            }
+        }
+    }
+
+    panic!("no noun and verb give 19690720")
+}
+
+fn run_simulation(program: &[i64], noun: i64, verb: i64) -> i64 {
+    let mut computer = IntCodeComputer::new(program);
+    computer.set(1, noun);
+    computer.set(2, verb);
+    process(computer)
+}
+
+fn process(mut computer: IntCodeComputer) -> i64 {
+    computer.run_to_end().expect("program failed");
+    computer.get(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process_str(program: &str) -> i64 {
+        process(program.parse().unwrap())
+    }
+
+    #[test]
+    fn process_test() {
+        assert_eq!(process_str("1,0,0,0,99"), 2);
+        assert_eq!(process_str("2,3,0,3,99"), 2);
+        assert_eq!(process_str("2,4,4,5,99,0"), 2);
+        assert_eq!(process_str("1,1,1,4,99,5,6,0,99"), 30);
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_parse::{line, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::ops::Range;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Range<usize>;
+
+    fn parse(input: &str) -> Result<Range<usize>, ParseError> {
+        line(input, parse_range)
+    }
+
+    fn part_one(range: &Range<usize>) -> Answer {
+        part_one(range.clone()).into()
+    }
+
+    fn part_two(range: &Range<usize>) -> Answer {
+        part_two(range.clone()).into()
+    }
+}
+
+fn part_one(range: Range<usize>) -> usize {
+    range
+        .map(split)
+        .filter(is_ascending)
+        .filter(has_duplicates)
+        .count()
+}
+
+fn part_two(range: Range<usize>) -> usize {
+    range
+        .map(split)
+        .filter(is_ascending)
+        .filter(has_duplicate_pair)
+        .count()
+}
+
+/// Reads a range like `245318-765747`.
+fn parse_range(input: &str) -> Result<Range<usize>, ParseError> {
+    let (start, end) = split_once(input, "-")?;
+    Ok(number(start)?..number(end)?)
+}
+
+fn split(mut n: usize) -> [usize; 6] {
+    let mut s: [usize; 6] = [0, 0, 0, 0, 0, 0];
+
+    for i in 0..6 {
+        s[5 - i] = n % 10;
+        n /= 10;
+    }
+
+    s
+}
+
+fn is_ascending(n: &[usize; 6]) -> bool {
+    for i in 0..5 {
+        if n[i] > n[i + 1] {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn has_duplicates(n: &[usize; 6]) -> bool {
+    let mut current = n[0];
+    for i in 1..6 {
+        if n[i] == current {
+            return true;
+        }
+        current = n[i]
+    }
+
+    false
+}
+
+fn has_duplicate_pair(n: &[usize; 6]) -> bool {
+    let mut current = n[0];
+    let mut size = 1;
+
+    for i in 1..6 {
+        if n[i] == current {
+            size += 1;
+            continue;
+        }
+
+        // New digit is different, check if previous
+        // section had length 2
+        if size == 2 {
+            return true;
+        }
+
+        current = n[i];
+        size = 1;
+    }
+
+    // check whether the final two are part of a pair
+    size == 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_test() {
+        assert_eq!(split(123456), [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn is_ascending_test() {
+        assert!(is_ascending(&[1, 2, 3, 4, 5, 6]), "123456");
+        assert!(!is_ascending(&[1, 2, 3, 4, 3, 5]), "123435");
+    }
+
+    #[test]
+    fn has_duplicates_test() {
+        assert!(!has_duplicates(&[1, 2, 3, 4, 5, 6]), "123456");
+        assert!(has_duplicates(&[1, 2, 3, 3, 4, 5]), "123345");
+    }
+
+    #[test]
+    fn has_just_duplicates_test() {
+        assert!(has_duplicate_pair(&[1, 1, 2, 2, 3, 3]), "112233");
+        assert!(!has_duplicate_pair(&[1, 2, 3, 4, 4, 4]), "123444");
+        assert!(has_duplicate_pair(&[1, 1, 1, 1, 2, 2]), "111122");
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_intcode::{read_program, IntCodeComputer};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 5);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        run(program, 1).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        run(program, 5).into()
+    }
+}
+
+/// Runs the diagnostic program with the given system ID, returning the final
+/// diagnostic code.
+fn run(codes: &[i64], input: i64) -> i64 {
+    let mut computer = IntCodeComputer::new(codes);
+    computer.push_input(input);
+
+    let outputs = computer.run_to_end().expect("program failed");
+    *outputs.last().expect("no diagnostic code")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_mode_test() {
+        let codes = vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+        assert_eq!(run(&codes, 5), 1);
+        assert_eq!(run(&codes, 0), 0);
+    }
+
+    #[test]
+    fn immediate_mode_test() {
+        let codes = vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+        assert_eq!(run(&codes, 5), 1);
+        assert_eq!(run(&codes, 0), 0);
+    }
+
+    #[test]
+    fn larger_test() {
+        let codes = vec![
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+        assert_eq!(run(&codes, 5), 999);
+        assert_eq!(run(&codes, 8), 1000);
+        assert_eq!(run(&codes, 57), 1001);
+    }
+}
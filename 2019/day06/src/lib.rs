@@ -0,0 +1,70 @@
+use aoc_parse::{lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = HashMap<&'a str, &'a str>;
+
+    fn parse(input: &str) -> Result<HashMap<&str, &str>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(orbits: &HashMap<&str, &str>) -> Answer {
+        part_one(orbits).into()
+    }
+
+    fn part_two(orbits: &HashMap<&str, &str>) -> Answer {
+        part_two(orbits).into()
+    }
+}
+
+fn part_one(orbits: &HashMap<&str, &str>) -> usize {
+    orbits.keys().map(|key| get_path_length(orbits, key)).sum()
+}
+
+/// The number of transfers between the objects YOU and SAN are orbiting.
+fn part_two(orbits: &HashMap<&str, &str>) -> usize {
+    let mut my_path = get_path_to_com(orbits, "YOU");
+    let mut santas_path = get_path_to_com(orbits, "SAN");
+
+    // Follow paths until they diverge
+    loop {
+        let m = my_path.remove(0);
+        let s = santas_path.remove(0);
+        if m != s {
+            return my_path.len() + santas_path.len();
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<&str, &str>, ParseError> {
+    let orbits = lines(input, |line| {
+        let (centre, object) = split_once(line, ")")?;
+        Ok((object, centre))
+    })?;
+
+    Ok(orbits.into_iter().collect())
+}
+
+fn get_path_length(orbits: &HashMap<&str, &str>, object: &str) -> usize {
+    if object == "COM" {
+        return 0;
+    }
+
+    1 + get_path_length(orbits, orbits.get(object).expect("couldn't find path"))
+}
+
+fn get_path_to_com<'a>(orbits: &HashMap<&str, &'a str>, object: &'a str) -> Vec<&'a str> {
+    if object == "COM" {
+        return vec!["COM"];
+    }
+
+    let next = orbits.get(object).expect("couldn't find object");
+    let mut path = get_path_to_com(orbits, next);
+    path.push(object);
+    path
+}
@@ -0,0 +1,122 @@
+use aoc_intcode::{read_program, Addressing, Network};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 7);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        find_max(program, 0).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        find_max(program, 5).into()
+    }
+}
+
+fn find_max(codes: &[i64], start: i64) -> i64 {
+    combinations(start)
+        .iter()
+        .map(|c| test_combination(codes, c))
+        .max()
+        .unwrap()
+}
+
+fn test_combination(codes: &[i64], phases: &[i64; 5]) -> i64 {
+    let mut amps = Network::with_copies(codes, phases.len(), Addressing::Ring);
+    for (i, &phase) in phases.iter().enumerate() {
+        amps.push_input(i, phase);
+    }
+    amps.push_input(0, 0);
+
+    amps.run(&mut ()).expect("amplifier failed");
+    amps.last_output(phases.len() - 1)
+        .expect("no thruster signal")
+}
+
+fn combinations(min: i64) -> Vec<[i64; 5]> {
+    let mut combos = Vec::new();
+    for a in min..(min + 5) {
+        for b in min..(min + 5) {
+            if a == b {
+                continue;
+            }
+
+            for c in min..(min + 5) {
+                if a == c || b == c {
+                    continue;
+                }
+
+                for d in min..(min + 5) {
+                    if a == d || b == d || c == d {
+                        continue;
+                    }
+
+                    for e in min..(min + 5) {
+                        if a == e || b == e || c == e || d == e {
+                            continue;
+                        }
+
+                        combos.push([a, b, c, d, e]);
+                    }
+                }
+            }
+        }
+    }
+
+    combos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example1() {
+        let codes = read_program("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0").unwrap();
+        assert_eq!(test_combination(&codes, &[4, 3, 2, 1, 0]), 43210);
+    }
+
+    #[test]
+    fn part1_example2() {
+        let codes = read_program(
+            "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0",
+        )
+        .unwrap();
+        assert_eq!(find_max(&codes, 0), 54321);
+    }
+
+    #[test]
+    fn part1_example3() {
+        let codes = read_program(
+            "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0"
+        )
+        .unwrap();
+        assert_eq!(find_max(&codes, 0), 65210);
+    }
+
+    #[test]
+    fn part2_example1() {
+        let codes = read_program(
+            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+        )
+        .unwrap();
+        assert_eq!(test_combination(&codes, &[9, 8, 7, 6, 5]), 139629729);
+    }
+
+    #[test]
+    fn part2_example2() {
+        let codes = read_program(
+            "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10",
+        )
+        .unwrap();
+        assert_eq!(find_max(&codes, 5), 18216);
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_grid::Grid;
+use aoc_parse::{each_char, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+const AREA: usize = WIDTH * HEIGHT;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Pixel>;
+
+    fn parse(input: &str) -> Result<Vec<Pixel>, ParseError> {
+        let pixels = line(input, get_pixels)?;
+        if pixels.is_empty() || pixels.len() % AREA != 0 {
+            return Err(ParseError::new(format!(
+                "expected whole layers of {} pixels, but there are {}",
+                AREA,
+                pixels.len()
+            )));
+        }
+
+        Ok(pixels)
+    }
+
+    fn part_one(pixels: &Vec<Pixel>) -> Answer {
+        part_one(pixels).into()
+    }
+
+    fn part_two(pixels: &Vec<Pixel>) -> Answer {
+        part_two(pixels).into()
+    }
+}
+
+/// Multiplies the 1s by the 2s on the layer with the fewest 0s.
+fn part_one(pixels: &[Pixel]) -> usize {
+    layer_counts(pixels)
+        .iter()
+        .min_by(|x, y| x.0.cmp(&y.0))
+        .map(|c| c.1 * c.2)
+        .unwrap()
+}
+
+/// Reads the letters in the picture the layers make.
+fn part_two(pixels: &[Pixel]) -> String {
+    let image = build_image(pixels, AREA);
+    let grid = Grid::from_fn(WIDTH, HEIGHT, |(x, y)| image[y * WIDTH + x] == Pixel::White);
+    aoc_ocr::read_grid(&grid).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Pixel {
+    Black,
+    White,
+    Transparent,
+}
+
+fn get_pixels(input: &str) -> Result<Vec<Pixel>, ParseError> {
+    each_char(input, "'0', '1' or '2'", |d| match d {
+        '0' => Some(Pixel::Black),
+        '1' => Some(Pixel::White),
+        '2' => Some(Pixel::Transparent),
+        _ => None,
+    })
+}
+
+fn layer_counts(pixels: &[Pixel]) -> Vec<(usize, usize, usize)> {
+    let mut counts = Vec::new();
+
+    let (mut b, mut w, mut t) = (0, 0, 0);
+    pixels.iter().enumerate().for_each(|(i, p)| {
+        match p {
+            Pixel::Black => b += 1,
+            Pixel::White => w += 1,
+            Pixel::Transparent => t += 1,
+        }
+
+        if i % AREA == AREA - 1 {
+            counts.push((b, w, t));
+            b = 0;
+            w = 0;
+            t = 0;
+        }
+    });
+
+    counts
+}
+
+fn build_image(pixels: &[Pixel], size: usize) -> Vec<Pixel> {
+    pixels
+        .iter()
+        .enumerate()
+        .fold(vec![Pixel::Transparent; size], |mut acc, (i, p)| {
+            let i = i % size;
+            if acc[i] == Pixel::Transparent {
+                acc[i] = *p;
+            }
+
+            acc
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_image_test() {
+        let input = "0222112222120000";
+        let pixels = get_pixels(input).unwrap();
+        let image = build_image(&pixels, 4);
+        assert_eq!(
+            vec![Pixel::Black, Pixel::White, Pixel::White, Pixel::Black],
+            image
+        );
+    }
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("0222112222120000\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected whole layers of 150 pixels, but there are 16"
+        );
+
+        let err = Puzzle::parse("02231\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 4: expected '0', '1' or '2', found \"3\""
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_intcode::{read_program, IntCodeComputer};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 9);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        run(program, 1).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        run(program, 2).into()
+    }
+}
+
+/// Runs the BOOST program, returning everything it outputs. That's just the
+/// answer, unless some opcodes are broken.
+fn run(codes: &[i64], input: i64) -> String {
+    let mut computer = IntCodeComputer::new(codes);
+    computer.push_input(input);
+
+    let outputs = computer.run_to_end().expect("program failed");
+    outputs
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
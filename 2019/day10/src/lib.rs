@@ -0,0 +1,197 @@
+use aoc_parse::{each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::PI;
+use std::iter::FromIterator;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 10);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(i32, i32)>;
+
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+        let locations = parse_locations(input)?;
+        if locations.is_empty() {
+            return Err(ParseError::new("expected at least one asteroid"));
+        }
+
+        Ok(locations)
+    }
+
+    fn part_one(locations: &Vec<(i32, i32)>) -> Answer {
+        part_one(locations).into()
+    }
+
+    fn part_two(locations: &Vec<(i32, i32)>) -> Answer {
+        part_two(locations).into()
+    }
+}
+
+/// The asteroid that can see the most others, and how many it can see.
+fn best_location(locations: &[(i32, i32)]) -> ((i32, i32), usize) {
+    locations
+        .iter()
+        .map(|&(x, y)| ((x, y), visible_asteroids(locations, (x, y))))
+        .max_by(|x, y| x.1.cmp(&y.1))
+        .unwrap()
+}
+
+fn part_one(locations: &[(i32, i32)]) -> usize {
+    best_location(locations).1
+}
+
+/// Where the 200th asteroid to be vaporised is, as `x * 100 + y`.
+fn part_two(locations: &[(i32, i32)]) -> i32 {
+    let (centre, _) = best_location(locations);
+    let mut rel_vecs = relative_vectors(locations, &centre);
+
+    let mut keys = Vec::from_iter(rel_vecs.keys().cloned());
+    keys.sort_by(|a, b| angle(a.0, a.1).partial_cmp(&angle(b.0, b.1)).unwrap());
+
+    let mut i = 0;
+    for key in keys.iter().cycle() {
+        let list = rel_vecs.get_mut(key).unwrap();
+        if list.is_empty() {
+            continue;
+        }
+
+        let loc = list.remove(0);
+        i += 1;
+        if i == 200 {
+            return (loc.0 + centre.0) * 100 + loc.1 + centre.1;
+        }
+    }
+
+    unreachable!()
+}
+
+fn parse_locations(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let rows = lines(input, |line| {
+        each_char(line, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    })?;
+
+    Ok((0..)
+        .zip(rows)
+        .flat_map(|(y, row)| {
+            (0..)
+                .zip(row)
+                .filter(|&(_, asteroid)| asteroid)
+                .map(move |(x, _)| (x, y))
+        })
+        .collect())
+}
+
+fn visible_asteroids(locations: &[(i32, i32)], pos: (i32, i32)) -> usize {
+    let visible: HashSet<(i32, i32)> = locations
+        .iter()
+        .filter(|&loc| loc != &pos)
+        .map(|&(x, y)| minimal_vector(x - pos.0, y - pos.1))
+        .collect();
+
+    visible.len()
+}
+
+fn relative_vectors(
+    locations: &[(i32, i32)],
+    centre: &(i32, i32),
+) -> HashMap<(i32, i32), Vec<(i32, i32)>> {
+    let mut map: HashMap<(i32, i32), Vec<(i32, i32)>> = HashMap::new();
+
+    locations
+        .iter()
+        .filter(|&loc| loc != centre)
+        .for_each(|loc| {
+            let x = loc.0 - centre.0;
+            let y = loc.1 - centre.1;
+
+            let min_vec = minimal_vector(x, y);
+            if let std::collections::hash_map::Entry::Vacant(e) = map.entry(min_vec) {
+                e.insert(vec![(x, y)]);
+            } else {
+                map.get_mut(&min_vec).unwrap().push((x, y));
+            }
+        });
+
+    // Sort the vectors from closest to center to furthest.
+    // Because they're parallel we can just use a single coordinate
+    // here to compare
+    for val in map.values_mut() {
+        val.sort_by_key(|a| a.0.abs());
+    }
+
+    map
+}
+
+fn minimal_vector(x: i32, y: i32) -> (i32, i32) {
+    if x == 0 && y == 0 {
+        return (0, 0);
+    }
+
+    if x == 0 {
+        return (0, y / y.abs());
+    }
+
+    if y == 0 {
+        return (x / x.abs(), 0);
+    }
+
+    let d = gcd(x.abs(), y.abs());
+    (x / d, y / d)
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if a % b == 0 {
+        return b;
+    }
+
+    gcd(b, a % b)
+}
+
+fn angle(x: i32, y: i32) -> f64 {
+    let x = f64::from(x);
+    let y = f64::from(y);
+
+    // Dot product used to calculate angle between positive y-axis
+    // and vector
+    let a = (y / (x.powi(2) + y.powi(2)).sqrt()).acos();
+    if x < 0.0 {
+        return a + PI;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_locations_test() {
+        let input = ".#..#\n.....\n#####\n....#\n...##";
+        let expected = vec![
+            (1, 0),
+            (4, 0),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (3, 2),
+            (4, 2),
+            (4, 3),
+            (3, 4),
+            (4, 4),
+        ];
+        assert_eq!(Ok(expected), parse_locations(input));
+    }
+
+    #[test]
+    fn visible_asteroids_test() {
+        let input = ".#..#\n.....\n#####\n....#\n...##";
+        let locations = parse_locations(input).unwrap();
+        assert_eq!(visible_asteroids(&locations, (3, 4)), 8);
+    }
+}
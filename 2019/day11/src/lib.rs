@@ -0,0 +1,96 @@
+use aoc_intcode::{read_program, IntCodeComputer, Status};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 11);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        run(program, 0).len().into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        read_panels(&run(program, 1)).into()
+    }
+}
+
+fn run(program: &[i64], start: i64) -> HashMap<(i32, i32), i64> {
+    let mut computer = IntCodeComputer::new(program);
+    let mut grid: HashMap<(i32, i32), i64> = HashMap::new();
+    let mut bot = Bot::new();
+
+    grid.insert((0, 0), start);
+
+    loop {
+        let pos = bot.pos();
+        computer.push_input(*grid.get(&pos).unwrap_or(&0));
+
+        let (color, dir) = match (computer.run(), computer.run()) {
+            (Ok(Status::Output(color)), Ok(Status::Output(dir))) => (color, dir),
+            (Ok(Status::Finished), _) => return grid,
+            (a, b) => panic!("unexpected status from robot: {:?}, {:?}", a, b),
+        };
+
+        grid.insert(pos, color);
+        match dir {
+            0 => bot.turn_left(),
+            1 => bot.turn_right(),
+            x => panic!("unknown turn: {}", x),
+        }
+    }
+}
+
+/// Reads the letters painted in white. The robot's `y` goes up the hull, so
+/// it's turned over to go down the page.
+fn read_panels(grid: &HashMap<(i32, i32), i64>) -> String {
+    let white = grid
+        .iter()
+        .filter(|&(_, &color)| color == 1)
+        .map(|(&(x, y), _)| (x as i64, -y as i64));
+    aoc_ocr::read_points(white).unwrap_or_else(|err| panic!("{}", err))
+}
+
+struct Bot {
+    x: i32,
+    y: i32,
+    d_x: i32,
+    d_y: i32,
+}
+
+impl Bot {
+    fn new() -> Bot {
+        Bot {
+            x: 0,
+            y: 0,
+            d_x: 0,
+            d_y: 1,
+        }
+    }
+
+    fn pos(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+
+    fn turn_left(&mut self) {
+        self.update(-self.d_y, self.d_x);
+    }
+
+    fn turn_right(&mut self) {
+        self.update(self.d_y, -self.d_x);
+    }
+
+    fn update(&mut self, d_x: i32, d_y: i32) {
+        self.x += d_x;
+        self.y += d_y;
+        self.d_x = d_x;
+        self.d_y = d_y;
+    }
+}
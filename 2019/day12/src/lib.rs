@@ -0,0 +1,200 @@
+use aoc_parse::combinators::{complete, field, int, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp::max;
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 12);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = [Dimension; 3];
+
+    fn parse(input: &str) -> Result<[Dimension; 3], ParseError> {
+        parse_moons(input)
+    }
+
+    fn part_one([x, y, z]: &[Dimension; 3]) -> Answer {
+        energy_after(x, y, z, 1000).into()
+    }
+
+    fn part_two([x, y, z]: &[Dimension; 3]) -> Answer {
+        first_repeat(x, y, z).into()
+    }
+}
+
+/// Reads the four moons, one per line like `<x=-8, y=-18, z=6>`, and
+/// splits them up by axis.
+fn parse_moons(input: &str) -> Result<[Dimension; 3], ParseError> {
+    let moons = lines(input, complete(moon))?;
+    let [a, b, c, d]: [[i64; 3]; 4] = moons.try_into().map_err(|moons: Vec<_>| {
+        ParseError::new(format!("expected four moons, found {}", moons.len()))
+    })?;
+
+    let axis = |i: usize| Dimension::new(a[i], b[i], c[i], d[i]);
+    Ok([axis(0), axis(1), axis(2)])
+}
+
+fn moon(s: &str) -> Parsed<'_, [i64; 3]> {
+    let (x, s) = ("<", field("x", int)).read(s)?;
+    let (y, s) = (", ", field("y", int)).read(s)?;
+    let (z, s) = (", ", field("z", int)).read(s)?;
+    let (_, rest) = ">".read(s)?;
+    Ok(([x, y, z], rest))
+}
+
+fn energy_after(x: &Dimension, y: &Dimension, z: &Dimension, steps: usize) -> i64 {
+    let mut x = x.clone();
+    let mut y = y.clone();
+    let mut z = z.clone();
+
+    for _ in 0..steps {
+        x = x.next();
+        y = y.next();
+        z = z.next();
+    }
+
+    total_energy(&x, &y, &z)
+}
+
+fn first_repeat(x: &Dimension, y: &Dimension, z: &Dimension) -> usize {
+    let (a_x, d_x) = find_loop(x);
+    let (a_y, d_y) = find_loop(y);
+    let (a_z, d_z) = find_loop(z);
+
+    max(max(a_x, a_y), a_z) + lcm(lcm(d_x, d_y), d_z)
+}
+
+fn find_loop(d: &Dimension) -> (usize, usize) {
+    let mut n = 0;
+    let mut previous: HashMap<Dimension, usize> = HashMap::new();
+
+    let mut d = d.clone();
+
+    loop {
+        if previous.contains_key(&d) {
+            let p = previous.get(&d).unwrap();
+            return (*p, n - p);
+        }
+
+        let next = d.next();
+        previous.insert(d, n);
+
+        d = next;
+        n += 1;
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Dimension {
+    pos: [i64; 4],
+    v: [i64; 4],
+}
+
+impl Dimension {
+    fn new(a: i64, b: i64, c: i64, d: i64) -> Dimension {
+        Dimension {
+            pos: [a, b, c, d],
+            v: [0, 0, 0, 0],
+        }
+    }
+
+    fn next(&self) -> Dimension {
+        let mut v = self.v;
+        for i in 0..4 {
+            for j in 0..4 {
+                if i == j {
+                    continue;
+                }
+                if self.pos[i] < self.pos[j] {
+                    v[i] += 1;
+                }
+                if self.pos[i] > self.pos[j] {
+                    v[i] -= 1;
+                }
+            }
+        }
+
+        let pos = [
+            self.pos[0] + v[0],
+            self.pos[1] + v[1],
+            self.pos[2] + v[2],
+            self.pos[3] + v[3],
+        ];
+        Dimension { pos, v }
+    }
+}
+
+fn total_energy(x: &Dimension, y: &Dimension, z: &Dimension) -> i64 {
+    let pe: Vec<i64> = (0..4)
+        .map(|m| x.pos[m].abs() + y.pos[m].abs() + z.pos[m].abs())
+        .collect();
+
+    let ke: Vec<i64> = (0..4)
+        .map(|m| x.v[m].abs() + y.v[m].abs() + z.v[m].abs())
+        .collect();
+
+    pe.iter().zip(ke.iter()).map(|(ke, pe)| ke * pe).sum()
+}
+
+fn hcf(a: usize, b: usize) -> usize {
+    if a.is_multiple_of(b) {
+        return b;
+    }
+
+    hcf(b, a % b)
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a * b / hcf(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn energy_after_test() {
+        let x = Dimension::new(-1, 2, 4, 3);
+        let y = Dimension::new(0, -10, -8, 5);
+        let z = Dimension::new(2, -7, 8, -1);
+        assert_eq!(energy_after(&x, &y, &z, 10), 179);
+    }
+
+    #[test]
+    fn first_repeat_example1() {
+        let x = Dimension::new(-1, 2, 4, 3);
+        let y = Dimension::new(0, -10, -8, 5);
+        let z = Dimension::new(2, -7, 8, -1);
+        assert_eq!(first_repeat(&x, &y, &z), 2772);
+    }
+
+    #[test]
+    fn parse_test() {
+        let [x, y, z] = Puzzle::parse(
+            "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>\n",
+        )
+        .unwrap();
+        assert_eq!(x, Dimension::new(-1, 2, 4, 3));
+        assert_eq!(y, Dimension::new(0, -10, -8, 5));
+        assert_eq!(z, Dimension::new(2, -7, 8, -1));
+
+        let err = Puzzle::parse("<x=-1, y=0, z=2>\n<x=2, y=?, z=-7>\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 9: expected a number, found \"?, z=-7>\""
+        );
+
+        let err = Puzzle::parse("<x=-1, y=0, z=2>\n").unwrap_err();
+        assert_eq!(err.to_string(), "expected four moons, found 1");
+    }
+
+    #[test]
+    fn first_repeat_example2() {
+        let x = Dimension::new(-8, 5, 2, 9);
+        let y = Dimension::new(-10, 5, -7, -8);
+        let z = Dimension::new(0, 10, 3, -3);
+        assert_eq!(first_repeat(&x, &y, &z), 4686774924);
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_intcode::{read_program, IntCodeComputer, Status};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+use std::io;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 13);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        part_one(program).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        part_two(program, true).into()
+    }
+}
+
+/// The number of block tiles on the screen when the game starts.
+fn part_one(program: &[i64]) -> usize {
+    let mut computer = IntCodeComputer::new(program);
+    let mut tiles: HashMap<(i64, i64), i64> = HashMap::new();
+    loop {
+        let x = match computer.run().expect("arcade failed") {
+            Status::Finished => break,
+            Status::Output(x) => x,
+            Status::WaitingForInput => panic!("arcade wants input in part one"),
+        };
+
+        let y = next_output(&mut computer);
+        let tile = next_output(&mut computer);
+        tiles.insert((x, y), tile);
+    }
+
+    tiles.iter().filter(|&(_, v)| *v == 2).count()
+}
+
+/// Plays the game, either by following the ball with the paddle or by
+/// asking for each move, and returns the final score.
+fn part_two(program: &[i64], autoplay: bool) -> i64 {
+    let mut computer = IntCodeComputer::new(program);
+    // Insert two quarters to play for free
+    computer.set(0, 2);
+    let mut tiles: HashMap<(i64, i64), i64> = HashMap::new();
+
+    // Keep track of where the ball is so we can move the paddle
+    // there
+    let mut ball_pos = 0;
+    let mut paddle_pos = 0;
+    let mut score = 0;
+
+    loop {
+        let x = match computer.run().expect("arcade failed") {
+            Status::Finished => break,
+            Status::Output(x) => x,
+            Status::WaitingForInput => {
+                if !autoplay {
+                    print_grid(&tiles);
+                }
+
+                let input = if autoplay {
+                    if ball_pos > paddle_pos {
+                        1
+                    } else if ball_pos < paddle_pos {
+                        -1
+                    } else {
+                        0
+                    }
+                } else {
+                    get_input()
+                };
+
+                computer.push_input(input);
+                continue;
+            }
+        };
+
+        let y = next_output(&mut computer);
+        let tile = next_output(&mut computer);
+
+        // Update tile and paddle positions
+        match tile {
+            3 => paddle_pos = x,
+            4 => ball_pos = x,
+            _ => {}
+        }
+
+        if x == -1 && y == 0 {
+            score = tile;
+        } else {
+            tiles.insert((x, y), tile);
+        }
+    }
+
+    score
+}
+
+fn next_output(computer: &mut IntCodeComputer) -> i64 {
+    match computer.run().expect("arcade failed") {
+        Status::Output(x) => x,
+        status => panic!("expected output, got {:?}", status),
+    }
+}
+
+fn get_input() -> i64 {
+    println!("Move:");
+    let mut input = String::new();
+
+    io::stdin()
+        .read_line(&mut input)
+        .expect("nothing was entered");
+    let input = input.trim();
+    match input {
+        "\\" => -1,
+        "" => 0,
+        "/" => 1,
+        _ => {
+            println!("Unknown input");
+            get_input()
+        }
+    }
+}
+
+fn print_grid(tiles: &HashMap<(i64, i64), i64>) {
+    let min_x = *tiles.keys().map(|(x, _)| x).min().unwrap();
+    let max_x = *tiles.keys().map(|(x, _)| x).max().unwrap();
+    let min_y = *tiles.keys().map(|(_, y)| y).min().unwrap();
+    let max_y = *tiles.keys().map(|(_, y)| y).max().unwrap();
+
+    for y in min_y..max_y + 1 {
+        for x in min_x..max_x + 1 {
+            match tiles.get(&(x, y)) {
+                None | Some(0) => print!(" "),
+                Some(1) => print!("|"),
+                Some(2) => print!("■"),
+                Some(3) => print!("-"),
+                Some(4) => print!("●"),
+                Some(x) => panic!("unknown tile: {}", x),
+            }
+        }
+
+        println!();
+    }
+}
@@ -0,0 +1,174 @@
+use aoc_parse::{each_char, lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 14);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = HashMap<String, Recipe>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Recipe>, ParseError> {
+        let recipes: HashMap<String, Recipe> = lines(input, parse_recipe)?.into_iter().collect();
+
+        let needed = recipes.values().flat_map(|recipe| &recipe.requires);
+        let needed = needed.map(|(s, _)| s.as_str()).chain(["FUEL"]);
+        for s in needed {
+            if s != "ORE" && !recipes.contains_key(s) {
+                return Err(ParseError::new(format!("expected a recipe for {}", s)));
+            }
+        }
+
+        Ok(recipes)
+    }
+
+    fn part_one(recipes: &HashMap<String, Recipe>) -> Answer {
+        part_one(recipes).into()
+    }
+
+    fn part_two(recipes: &HashMap<String, Recipe>) -> Answer {
+        part_two(recipes, 1000000000000, part_one(recipes)).into()
+    }
+}
+
+fn part_one(recipes: &HashMap<String, Recipe>) -> usize {
+    let order = dependency_order(recipes);
+    ore_required(recipes, &order, 1)
+}
+
+fn part_two(recipes: &HashMap<String, Recipe>, target: usize, ore_per_fuel: usize) -> usize {
+    let mut n = target / ore_per_fuel;
+    let order = dependency_order(recipes);
+
+    let mut ans = ore_required(recipes, &order, n);
+    while ans < target {
+        n += max((target - ans) / ore_per_fuel, 1);
+        ans = ore_required(recipes, &order, n);
+    }
+
+    while ans > target {
+        n -= 1;
+        ans = ore_required(recipes, &order, n);
+    }
+
+    n
+}
+
+fn ore_required(recipes: &HashMap<String, Recipe>, order: &[String], fuel: usize) -> usize {
+    let mut amounts: HashMap<String, usize> = HashMap::new();
+    amounts.insert("FUEL".to_string(), fuel);
+
+    for s in order.iter().rev() {
+        let recipe = recipes.get(s).unwrap();
+        let amount = amounts.get(s).unwrap();
+        for (k, n) in recipe.for_n(amount) {
+            let current = *amounts.get(&k).unwrap_or(&0);
+            amounts.insert(k, current + n);
+        }
+    }
+
+    *amounts.get("ORE").unwrap()
+}
+
+fn dependency_order(recipes: &HashMap<String, Recipe>) -> Vec<String> {
+    let mut satisfied: HashSet<String> = HashSet::new();
+    satisfied.insert("ORE".to_string());
+    let mut order = Vec::new();
+
+    loop {
+        let mut found = false;
+
+        for (s, recipe) in recipes {
+            if order.contains(s) {
+                continue;
+            }
+
+            let deps_satisfied = recipe.requires.iter().all(|(s, _)| satisfied.contains(s));
+            if deps_satisfied {
+                satisfied.insert(s.clone());
+                order.push(s.clone());
+                found = true;
+            }
+        }
+
+        if !found {
+            break;
+        }
+    }
+
+    order
+}
+
+#[derive(Debug)]
+pub struct Recipe {
+    produces: usize,
+    requires: Vec<(String, usize)>,
+}
+
+impl Recipe {
+    fn for_n(&self, n: &usize) -> Vec<(String, usize)> {
+        let multiple = n.div_ceil(self.produces);
+
+        self.requires
+            .iter()
+            .cloned()
+            .map(|(s, n)| (s, n * multiple))
+            .collect()
+    }
+}
+
+/// A recipe like `7 A, 1 E => 1 FUEL`.
+fn parse_recipe(line: &str) -> Result<(String, Recipe), ParseError> {
+    let (req, output) = split_once(line, " => ")?;
+
+    let req = req.split(", ").map(parse_req).collect::<Result<_, _>>()?;
+    let (output, n) = parse_req(output)?;
+
+    Ok((
+        output,
+        Recipe {
+            produces: n,
+            requires: req,
+        },
+    ))
+}
+
+/// An amount of a chemical, like `7 A`.
+fn parse_req(input: &str) -> Result<(String, usize), ParseError> {
+    let (n, s) = split_once(input, " ")?;
+    let s = each_char(s, "an uppercase letter", |c| {
+        c.is_ascii_uppercase().then_some(c)
+    })?;
+    Ok((s.into_iter().collect(), number(n)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ore(input: &str) -> usize {
+        part_one(&Puzzle::parse(input).unwrap())
+    }
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(ore(include_str!("../example0")), 165, "example 0");
+        assert_eq!(ore(include_str!("../example1")), 13312, "example 1");
+        assert_eq!(ore(include_str!("../example2")), 180697, "example 2");
+        assert_eq!(ore(include_str!("../example3")), 2210736, "example 3");
+    }
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("10 ORE => 10 A\n7 A, 1 B => 1 FUEL\n").unwrap_err();
+        assert_eq!(err.to_string(), "expected a recipe for B");
+
+        let err = Puzzle::parse("10 ORE => 10 A\n7 A; 1 B => 1 FUEL\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected an uppercase letter, found \";\""
+        );
+    }
+}
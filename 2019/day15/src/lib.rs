@@ -0,0 +1,205 @@
+use aoc_intcode::{read_program, IntCodeComputer, Status};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, VecDeque};
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 15);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        oxygen_distances(program)[&(0, 0)].into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        (*oxygen_distances(program).values().max().unwrap()).into()
+    }
+}
+
+/// How far every open tile is from the oxygen system.
+fn oxygen_distances(program: &[i64]) -> HashMap<(i64, i64), usize> {
+    let mut computer = IntCodeComputer::new(program);
+    let map = explore(&mut computer);
+
+    let oxygen = *map
+        .iter()
+        .find(|(_, &tile)| tile == Tile::Oxygen)
+        .expect("didn't find the oxygen system")
+        .0;
+    distances(&map, oxygen)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Open,
+    Oxygen,
+}
+
+/// Something that can be told to move, and reports what it ran into.
+trait Droid {
+    fn try_move(&mut self, dir: i64) -> Tile;
+}
+
+impl Droid for IntCodeComputer {
+    fn try_move(&mut self, dir: i64) -> Tile {
+        self.push_input(dir);
+        match self.run().expect("droid failed") {
+            Status::Output(0) => Tile::Wall,
+            Status::Output(1) => Tile::Open,
+            Status::Output(2) => Tile::Oxygen,
+            status => panic!("unexpected status from droid: {:?}", status),
+        }
+    }
+}
+
+/// The movement commands, in order: north, south, west and east.
+const DIRS: [(i64, (i64, i64)); 4] = [(1, (0, -1)), (2, (0, 1)), (3, (-1, 0)), (4, (1, 0))];
+
+fn reverse(dir: i64) -> i64 {
+    match dir {
+        1 => 2,
+        2 => 1,
+        3 => 4,
+        4 => 3,
+        _ => panic!("unknown direction: {}", dir),
+    }
+}
+
+/// Maps out the whole area by walking the droid everywhere it can go,
+/// starting from (0, 0).
+fn explore<D: Droid>(droid: &mut D) -> HashMap<(i64, i64), Tile> {
+    let mut map = HashMap::new();
+    map.insert((0, 0), Tile::Open);
+    explore_from(droid, (0, 0), &mut map);
+    map
+}
+
+fn explore_from<D: Droid>(droid: &mut D, pos: (i64, i64), map: &mut HashMap<(i64, i64), Tile>) {
+    for (dir, (dx, dy)) in DIRS {
+        let next = (pos.0 + dx, pos.1 + dy);
+        if map.contains_key(&next) {
+            continue;
+        }
+
+        let tile = droid.try_move(dir);
+        map.insert(next, tile);
+        if tile == Tile::Wall {
+            continue;
+        }
+
+        explore_from(droid, next, map);
+
+        // Step back to where we were
+        droid.try_move(reverse(dir));
+    }
+}
+
+/// The number of steps from `start` to every open tile.
+fn distances(map: &HashMap<(i64, i64), Tile>, start: (i64, i64)) -> HashMap<(i64, i64), usize> {
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    distances.insert(start, 0);
+    queue.push_back(start);
+
+    while let Some(pos) = queue.pop_front() {
+        let dist = distances[&pos];
+        for (_, (dx, dy)) in DIRS {
+            let next = (pos.0 + dx, pos.1 + dy);
+            if map.get(&next).unwrap_or(&Tile::Wall) == &Tile::Wall || distances.contains_key(&next)
+            {
+                continue;
+            }
+
+            distances.insert(next, dist + 1);
+            queue.push_back(next);
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A droid in a maze drawn with `#`, `.` and `O`, starting at `D`.
+    struct Maze {
+        grid: Vec<Vec<char>>,
+        pos: (i64, i64),
+    }
+
+    impl Maze {
+        fn new(maze: &str) -> Maze {
+            let grid: Vec<Vec<char>> = maze.lines().map(|l| l.chars().collect()).collect();
+            let pos = grid
+                .iter()
+                .enumerate()
+                .find_map(|(y, row)| {
+                    row.iter()
+                        .position(|&c| c == 'D')
+                        .map(|x| (x as i64, y as i64))
+                })
+                .unwrap();
+
+            Maze { grid, pos }
+        }
+    }
+
+    impl Droid for Maze {
+        fn try_move(&mut self, dir: i64) -> Tile {
+            let (_, (dx, dy)) = DIRS[dir as usize - 1];
+            let next = (self.pos.0 + dx, self.pos.1 + dy);
+            let tile = match self.grid[next.1 as usize][next.0 as usize] {
+                '#' => return Tile::Wall,
+                'O' => Tile::Oxygen,
+                _ => Tile::Open,
+            };
+
+            self.pos = next;
+            tile
+        }
+    }
+
+    fn oxygen(map: &HashMap<(i64, i64), Tile>) -> (i64, i64) {
+        *map.iter().find(|(_, &t)| t == Tile::Oxygen).unwrap().0
+    }
+
+    #[test]
+    fn part1_example() {
+        let mut maze = Maze::new(
+            "\
+#####
+#...#
+#D#O#
+#####",
+        );
+
+        let map = explore(&mut maze);
+        assert_eq!(maze.pos, (1, 2));
+        assert_eq!(distances(&map, oxygen(&map))[&(0, 0)], 4);
+    }
+
+    #[test]
+    fn part2_example() {
+        let mut maze = Maze::new(
+            "\
+######
+#D.###
+#.#..#
+#.O.##
+######",
+        );
+
+        let map = explore(&mut maze);
+        let distances = distances(&map, oxygen(&map));
+        assert_eq!(distances.values().max(), Some(&4));
+    }
+}
@@ -0,0 +1,358 @@
+use aoc_intcode::{read_program, IntCodeComputer, Status};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 17);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        intersection_sum(&parse_scaffold(program).0).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        part_two(program).into()
+    }
+}
+
+fn part_two(program: &[i64]) -> i64 {
+    let (scaffold, pos, dir) = parse_scaffold(program);
+    let route = find_route(&scaffold, &pos, &dir);
+    let movement = compress(&route).expect("couldn't compress route");
+    collect_dust(program, &movement)
+}
+
+fn parse_scaffold(program: &[i64]) -> (HashSet<(i64, i64)>, (i64, i64), (i64, i64)) {
+    let mut computer = IntCodeComputer::new(program);
+
+    let output = computer.read_ascii().expect("camera failed");
+    if output.status == Status::WaitingForInput {
+        panic!("expecting input");
+    }
+
+    parse_view(&output.text)
+}
+
+fn parse_view(view: &str) -> (HashSet<(i64, i64)>, (i64, i64), (i64, i64)) {
+    let mut scaffold = HashSet::new();
+    let mut robot_pos = (0, 0);
+    let mut robot_dir = (0, 0);
+
+    for (y, line) in view.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let pos = (x as i64, y as i64);
+            let dir = match c {
+                '#' => {
+                    scaffold.insert(pos);
+                    continue;
+                }
+                '.' => continue,
+                '^' => (0, -1),
+                'v' => (0, 1),
+                '<' => (-1, 0),
+                '>' => (1, 0),
+                _ => panic!("unknown code: {}", c),
+            };
+
+            robot_pos = pos;
+            robot_dir = dir;
+            scaffold.insert(pos);
+        }
+    }
+
+    (scaffold, robot_pos, robot_dir)
+}
+
+fn intersection_sum(scaffold: &HashSet<(i64, i64)>) -> i64 {
+    scaffold
+        .iter()
+        .filter(|(x, y)| {
+            if x == &0 || y == &0 {
+                return false;
+            }
+
+            for pos in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+                if !scaffold.contains(&(x + pos.0, y + pos.1)) {
+                    return false;
+                }
+            }
+
+            true
+        })
+        .map(|(x, y)| x * y)
+        .sum()
+}
+
+/// The most characters the robot accepts on any line of its movement program.
+const MAX_LEN: usize = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Instruction {
+    Left,
+    Right,
+    Forward(i64),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Left => write!(f, "L"),
+            Instruction::Right => write!(f, "R"),
+            Instruction::Forward(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+/// A route split into a main routine that calls up to three movement
+/// functions.
+#[derive(Debug)]
+struct Movement {
+    main: Vec<usize>,
+    functions: Vec<Vec<Instruction>>,
+}
+
+impl Movement {
+    /// The lines the robot asks for: the main routine, then functions A, B
+    /// and C.
+    fn lines(&self) -> Vec<String> {
+        let main = self
+            .main
+            .iter()
+            .map(|&f| ((b'A' + f as u8) as char).to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut lines = vec![main];
+        for i in 0..3 {
+            lines.push(self.functions.get(i).map_or(String::new(), |f| join(f)));
+        }
+
+        lines
+    }
+}
+
+fn join(instructions: &[Instruction]) -> String {
+    instructions
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn find_route(
+    scaffold: &HashSet<(i64, i64)>,
+    pos: &(i64, i64),
+    dir: &(i64, i64),
+) -> Vec<Instruction> {
+    let mut pos = *pos;
+    let mut dir = *dir;
+
+    let mut instructions = Vec::new();
+
+    let mut current = 0;
+    loop {
+        // try to advance
+        if scaffold.contains(&(pos.0 + dir.0, pos.1 + dir.1)) {
+            current += 1;
+            pos = (pos.0 + dir.0, pos.1 + dir.1);
+            continue;
+        }
+
+        // if we can't, we're at the end of an instruction
+        if current > 0 {
+            instructions.push(Instruction::Forward(current));
+            // println!("Moved forward: {}, now at ({}, {})", current, pos.0, pos.1);
+        }
+
+        // if we can't, try to turn left and then right
+        let left = turn_left(dir);
+        // println!(
+        //     "Current dir: ({}, {}), Left: ({}, {})",
+        //     dir.0, dir.1, left.0, left.1
+        // );
+        if scaffold.contains(&(pos.0 + left.0, pos.1 + left.1)) {
+            instructions.push(Instruction::Left);
+            current = 1;
+            dir = left;
+            pos = (pos.0 + left.0, pos.1 + left.1);
+            continue;
+        }
+
+        let right = turn_right(dir);
+        // println!(
+        //     "Current dir: ({}, {}), Right: ({}, {})",
+        //     dir.0, dir.1, right.0, right.1
+        // );
+        if scaffold.contains(&(pos.0 + right.0, pos.1 + right.1)) {
+            instructions.push(Instruction::Right);
+            current = 1;
+            dir = right;
+            pos = (pos.0 + right.0, pos.1 + right.1);
+            continue;
+        }
+
+        // If we're at a dead-end, we must be finished
+        return instructions;
+    }
+}
+
+fn turn_left(dir: (i64, i64)) -> (i64, i64) {
+    (dir.1, -dir.0)
+}
+
+fn turn_right(dir: (i64, i64)) -> (i64, i64) {
+    (-dir.1, dir.0)
+}
+
+/// Splits the route into at most three functions that all fit on a line,
+/// called from a main routine that fits on a line too.
+fn compress(route: &[Instruction]) -> Option<Movement> {
+    let mut movement = Movement {
+        main: Vec::new(),
+        functions: Vec::new(),
+    };
+
+    if compress_from(route, &mut movement) {
+        Some(movement)
+    } else {
+        None
+    }
+}
+
+/// Covers the rest of the route with the functions found so far, trying new
+/// functions while there's room for them.
+fn compress_from(route: &[Instruction], movement: &mut Movement) -> bool {
+    if route.is_empty() {
+        return true;
+    }
+
+    // Each call takes a letter and a comma
+    if (movement.main.len() + 1) * 2 - 1 > MAX_LEN {
+        return false;
+    }
+
+    for f in 0..movement.functions.len() {
+        let len = movement.functions[f].len();
+        if route.starts_with(&movement.functions[f]) {
+            movement.main.push(f);
+            if compress_from(&route[len..], movement) {
+                return true;
+            }
+            movement.main.pop();
+        }
+    }
+
+    if movement.functions.len() == 3 {
+        return false;
+    }
+
+    let longest = (1..=route.len())
+        .take_while(|&len| join(&route[..len]).len() <= MAX_LEN)
+        .last()
+        .unwrap_or(0);
+
+    for len in (1..=longest).rev() {
+        movement.functions.push(route[..len].to_vec());
+        movement.main.push(movement.functions.len() - 1);
+        if compress_from(&route[len..], movement) {
+            return true;
+        }
+        movement.main.pop();
+        movement.functions.pop();
+    }
+
+    false
+}
+
+fn collect_dust(program: &[i64], movement: &Movement) -> i64 {
+    let mut computer = IntCodeComputer::new(program);
+
+    // Wake the robot up
+    computer.set(0, 2);
+
+    for line in movement.lines() {
+        computer.push_line(&line);
+    }
+    // No continuous video feed
+    computer.push_line("n");
+
+    let output = computer.read_ascii().expect("robot failed");
+    if output.status == Status::WaitingForInput {
+        panic!("robot wants more input:\n{}", output.text);
+    }
+
+    *output.values.last().expect("robot didn't report any dust")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let view = "\
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..";
+
+        let (scaffold, _, _) = parse_view(view);
+        assert_eq!(intersection_sum(&scaffold), 76);
+    }
+
+    #[test]
+    fn part2_example() {
+        let view = "\
+#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......";
+
+        let (scaffold, pos, dir) = parse_view(view);
+        let route = find_route(&scaffold, &pos, &dir);
+        assert_eq!(
+            join(&route),
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+
+        let movement = compress(&route).unwrap();
+        assert!(movement.functions.len() <= 3);
+        assert!(movement.lines().iter().all(|line| line.len() <= MAX_LEN));
+
+        let expanded: Vec<Instruction> = movement
+            .main
+            .iter()
+            .flat_map(|&f| movement.functions[f].iter().copied())
+            .collect();
+        assert_eq!(expanded, route);
+    }
+
+    #[test]
+    fn compress_test() {
+        // Too varied to fit in three functions
+        let route: Vec<Instruction> = (1..=30).map(Instruction::Forward).collect();
+        assert!(compress(&route).is_none());
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_intcode::{read_program, IntCodeComputer, Status};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 19);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        part_one(program).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        part_two(program).into()
+    }
+}
+
+fn part_one(program: &[i64]) -> usize {
+    affected(|x, y| pulled(program, x, y), 50)
+}
+
+fn part_two(program: &[i64]) -> i64 {
+    let (x, y) = find_square(|x, y| pulled(program, x, y), 100);
+    x * 10000 + y
+}
+
+/// Whether the drone is pulled at (x, y). The program halts after every
+/// query, so each one needs a fresh computer.
+fn pulled(program: &[i64], x: i64, y: i64) -> bool {
+    let mut computer = IntCodeComputer::new(program);
+    computer.extend_input([x, y]);
+
+    match computer.run().expect("drone failed") {
+        Status::Output(x) => x == 1,
+        status => panic!("unexpected status from drone: {:?}", status),
+    }
+}
+
+/// The number of points affected by the beam in the `size` by `size` area
+/// closest to the emitter.
+fn affected<F: Fn(i64, i64) -> bool>(beam: F, size: i64) -> usize {
+    (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .filter(|&(x, y)| beam(x, y))
+        .count()
+}
+
+/// Finds the top left corner of the closest `size` by `size` square that
+/// fits entirely in the beam.
+fn find_square<F: Fn(i64, i64) -> bool>(beam: F, size: i64) -> (i64, i64) {
+    let mut left = 0;
+    for y in size - 1.. {
+        // The beam's left edge only moves right as it gets further away, but
+        // near the emitter some rows miss the beam altogether
+        let start = match (left..=left + y).find(|&x| beam(x, y)) {
+            Some(x) => x,
+            None => continue,
+        };
+        left = start;
+
+        // The square's bottom left corner sits on the left edge, so only
+        // the top right corner needs checking
+        if beam(left + size - 1, y - size + 1) {
+            return (left, y - size + 1);
+        }
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_beam(grid: &str) -> impl Fn(i64, i64) -> bool + '_ {
+        let rows: Vec<&[u8]> = grid.lines().map(|l| l.as_bytes()).collect();
+        move |x, y| {
+            rows.get(y as usize)
+                .and_then(|row| row.get(x as usize))
+                .is_some_and(|&c| c == b'#' || c == b'O')
+        }
+    }
+
+    #[test]
+    fn part1_example() {
+        let grid = "\
+#.........
+.#........
+..##......
+...###....
+....###...
+.....####.
+......####
+......####
+.......###
+........##";
+
+        assert_eq!(affected(grid_beam(grid), 10), 27);
+    }
+
+    #[test]
+    fn part2_example() {
+        let grid = include_str!("../example.txt");
+        assert_eq!(find_square(grid_beam(grid), 10), (25, 20));
+    }
+}
@@ -0,0 +1,134 @@
+use aoc_intcode::{read_program, IntCodeComputer, Status};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+/// Jump if there's a hole in the next three tiles and somewhere to land.
+const WALK: &str = "\
+NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+WALK";
+
+/// As above, but only if after landing the droid can either take a step or
+/// jump again straight away.
+const RUN: &str = "\
+NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+NOT E T
+NOT T T
+OR H T
+AND T J
+RUN";
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 21);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        survey(program, WALK).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        survey(program, RUN).into()
+    }
+}
+
+/// Runs a springscript program, returning the hull damage it reports.
+fn survey(program: &[i64], script: &str) -> i64 {
+    let mut computer = IntCodeComputer::new(program);
+    for line in script.lines() {
+        computer.push_line(line);
+    }
+
+    let output = computer.read_ascii().expect("springdroid failed");
+    match (output.status, output.values.last()) {
+        (Status::Finished, Some(&damage)) => damage,
+        _ => panic!("springdroid didn't make it:\n{}", output.text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Walks a springdroid across a hull drawn with `#` and `.`, returning
+    /// whether it makes it to the end. Everything past the end is hull.
+    fn survives(script: &str, hull: &str) -> bool {
+        let hull = hull.as_bytes();
+        let ground = |i: usize| hull.get(i).is_none_or(|&c| c == b'#');
+
+        let mut pos = 0;
+        while pos < hull.len() {
+            if !ground(pos) {
+                return false;
+            }
+
+            let mut t = false;
+            let mut j = false;
+            for line in script.lines() {
+                let parts: Vec<&str> = line.split(' ').collect();
+                let (op, x, y) = match parts[..] {
+                    [op, x, y] => (op, x, y),
+                    _ => continue,
+                };
+
+                let x = match x {
+                    "T" => t,
+                    "J" => j,
+                    sensor => ground(pos + (sensor.as_bytes()[0] - b'A') as usize + 1),
+                };
+                let y = if y == "T" { &mut t } else { &mut j };
+                match op {
+                    "AND" => *y &= x,
+                    "OR" => *y |= x,
+                    "NOT" => *y = !x,
+                    _ => panic!("unknown instruction: {}", op),
+                }
+            }
+
+            pos += if j { 4 } else { 1 };
+        }
+
+        true
+    }
+
+    #[test]
+    fn walk_test() {
+        for hull in [
+            "#####.###########",
+            "#####...#########",
+            "#####..#.########",
+        ] {
+            assert!(survives(WALK, hull), "{}", hull);
+        }
+    }
+
+    #[test]
+    fn run_test() {
+        for hull in [
+            "#####.###########",
+            "#####...#########",
+            "#####..#.########",
+            "#####.#.##..#####",
+            "#####.##.#.#.####",
+        ] {
+            assert!(survives(RUN, hull), "{}", hull);
+        }
+
+        // Jumping as soon as there's somewhere to land strands the droid
+        assert!(!survives(WALK, "#####.#.##..#####"));
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_intcode::{read_program, Addressing, Nat, Network, Packet};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::ops::ControlFlow;
+
+/// Where packets for the NAT are sent.
+const NAT: i64 = 255;
+
+pub const DAY: Day = Day::new::<Puzzle>(2019, 23);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        part_one(program).into()
+    }
+
+    fn part_two(program: &Vec<i64>) -> Answer {
+        part_two(program).into()
+    }
+}
+
+fn part_one(program: &[i64]) -> i64 {
+    let ans = boot(program, 50)
+        .run(&mut FirstPacket)
+        .expect("network failed");
+    ans.expect("network halted")
+}
+
+fn part_two(program: &[i64]) -> i64 {
+    let mut nat = Monitor {
+        last: None,
+        last_sent: None,
+    };
+    let ans = boot(program, 50).run(&mut nat).expect("network failed");
+    ans.expect("network halted")
+}
+
+/// Starts `count` computers, each told its own address. A computer with no
+/// packets waiting reads -1.
+fn boot(program: &[i64], count: usize) -> Network {
+    let mut network =
+        Network::with_copies(program, count, Addressing::Packets(2)).with_empty_input(-1);
+    for i in 0..count {
+        network.push_input(i, i as i64);
+    }
+
+    network
+}
+
+/// Stops at the first packet sent to the NAT, with its Y value.
+struct FirstPacket;
+
+impl Nat for FirstPacket {
+    type Output = i64;
+
+    fn receive(&mut self, packet: Packet) -> ControlFlow<i64> {
+        match packet.to {
+            NAT => ControlFlow::Break(packet.values[1]),
+            to => panic!("packet sent to unknown address {}", to),
+        }
+    }
+
+    fn idle(&mut self) -> ControlFlow<i64, Packet> {
+        panic!("network went idle before anything was sent to the NAT")
+    }
+}
+
+/// Restarts the network with the last packet it received whenever it goes
+/// idle, and stops when it sends the same Y value twice in a row.
+struct Monitor {
+    last: Option<Vec<i64>>,
+    last_sent: Option<i64>,
+}
+
+impl Nat for Monitor {
+    type Output = i64;
+
+    fn receive(&mut self, packet: Packet) -> ControlFlow<i64> {
+        match packet.to {
+            NAT => self.last = Some(packet.values),
+            to => panic!("packet sent to unknown address {}", to),
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    fn idle(&mut self) -> ControlFlow<i64, Packet> {
+        let values = self
+            .last
+            .clone()
+            .expect("network went idle with nothing to send");
+        if self.last_sent == Some(values[1]) {
+            return ControlFlow::Break(values[1]);
+        }
+
+        self.last_sent = Some(values[1]);
+        ControlFlow::Continue(Packet { to: 0, values })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computer 0 sends (1, 42) to the NAT when it boots. After that, every
+    /// computer echoes any packet it gets back to the NAT.
+    const ECHO: &str = "3,100,1005,100,11,104,255,104,1,104,42,3,101,1008,101,-1,103,\
+                        1005,103,11,3,102,104,255,4,101,4,102,1105,1,11";
+
+    #[test]
+    fn part1_test() {
+        let program = read_program(ECHO).unwrap();
+        let ans = boot(&program, 3).run(&mut FirstPacket);
+        assert_eq!(ans, Ok(Some(42)));
+    }
+
+    #[test]
+    fn part2_test() {
+        let program = read_program(ECHO).unwrap();
+        let mut nat = Monitor {
+            last: None,
+            last_sent: None,
+        };
+
+        let ans = boot(&program, 3).run(&mut nat);
+        assert_eq!(ans, Ok(Some(42)));
+        assert_eq!(nat.last, Some(vec![1, 42]));
+    }
+}
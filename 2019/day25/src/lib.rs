@@ -0,0 +1,392 @@
+use aoc_intcode::{read_program, IntCodeComputer, Status};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+/// Items that end the game or stop the droid from moving once picked up.
+const DANGEROUS: [&str; 5] = [
+    "escape pod",
+    "giant electromagnet",
+    "infinite loop",
+    "molten lava",
+    "photons",
+];
+
+const CHECKPOINT: &str = "Security Checkpoint";
+
+pub const DAY: Day = Day::one_part::<Puzzle>(2019, 25);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        read_program(input)
+    }
+
+    fn part_one(program: &Vec<i64>) -> Answer {
+        part_one(program).into()
+    }
+
+    fn part_two(_: &Vec<i64>) -> Answer {
+        unreachable!("there's only the one part")
+    }
+}
+
+fn part_one(program: &[i64]) -> String {
+    let mut computer = IntCodeComputer::new(program);
+    find_password(&mut computer)
+}
+
+/// The droid's text interface.
+trait Terminal {
+    /// Sends a line to the droid, or nothing if it's empty, and returns
+    /// what it printed before asking for the next command.
+    fn send(&mut self, command: &str) -> String;
+}
+
+impl Terminal for IntCodeComputer {
+    fn send(&mut self, command: &str) -> String {
+        if !command.is_empty() {
+            self.push_line(command);
+        }
+
+        let output = self.read_ascii().expect("droid failed");
+        if output.status == Status::Finished && password(&output.text).is_none() {
+            panic!("droid stopped:\n{}", output.text);
+        }
+
+        output.text
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Room {
+    name: String,
+    doors: Vec<String>,
+    items: Vec<String>,
+}
+
+/// Parses the last room described in some output.
+fn parse_room(text: &str) -> Option<Room> {
+    let start = text.rfind("== ")?;
+    let mut lines = text[start..].lines();
+
+    let name = lines.next()?.trim_matches(|c| c == '=' || c == ' ');
+    let mut room = Room {
+        name: name.to_string(),
+        doors: Vec::new(),
+        items: Vec::new(),
+    };
+
+    let mut list = None;
+    for line in lines {
+        match line {
+            "Doors here lead:" => list = Some(&mut room.doors),
+            "Items here:" => list = Some(&mut room.items),
+            _ => match (line.strip_prefix("- "), list.as_mut()) {
+                (Some(entry), Some(list)) => list.push(entry.to_string()),
+                _ => list = None,
+            },
+        }
+    }
+
+    Some(room)
+}
+
+/// Finds the airlock password in the output, if the droid has been let in.
+fn password(text: &str) -> Option<String> {
+    let (_, rest) = text.split_once("by typing ")?;
+    let code: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if code.is_empty() {
+        None
+    } else {
+        Some(code)
+    }
+}
+
+fn reverse(dir: &str) -> &'static str {
+    match dir {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        "west" => "east",
+        _ => panic!("unknown direction: {}", dir),
+    }
+}
+
+/// What's been learnt about the ship so far.
+struct Explorer<'a, T: Terminal> {
+    terminal: &'a mut T,
+    visited: HashSet<String>,
+    items: Vec<String>,
+    /// The way from the start to the security checkpoint.
+    checkpoint: Option<Vec<String>>,
+    /// The door from the checkpoint to the pressure-sensitive floor.
+    floor: Option<String>,
+    password: Option<String>,
+}
+
+impl<T: Terminal> Explorer<'_, T> {
+    /// Visits every room reachable from this one, picking up any safe items
+    /// along the way, and comes back.
+    fn explore(&mut self, room: Room, path: &mut Vec<String>) {
+        self.visited.insert(room.name.clone());
+
+        for item in &room.items {
+            if !DANGEROUS.contains(&item.as_str()) {
+                self.terminal.send(&format!("take {}", item));
+                self.items.push(item.clone());
+            }
+        }
+
+        if room.name == CHECKPOINT {
+            self.checkpoint = Some(path.clone());
+        }
+
+        for door in &room.doors {
+            let output = self.terminal.send(door);
+            if let Some(password) = password(&output) {
+                // We happened to be carrying the right things
+                self.password = Some(password);
+                return;
+            }
+
+            let next = parse_room(&output).expect("droid didn't say where it is");
+            if next.name == room.name {
+                // Thrown back out by the pressure-sensitive floor
+                self.floor = Some(door.clone());
+                continue;
+            }
+
+            if !self.visited.contains(&next.name) {
+                path.push(door.clone());
+                self.explore(next, path);
+                path.pop();
+
+                if self.password.is_some() {
+                    return;
+                }
+            }
+
+            self.terminal.send(reverse(door));
+        }
+    }
+
+    /// Tries every combination of items on the pressure-sensitive floor.
+    fn weigh(&mut self) -> Option<String> {
+        let floor = self.floor.clone()?;
+        let mut held = vec![true; self.items.len()];
+
+        for mask in 0..1usize << self.items.len() {
+            for (i, item) in self.items.iter().enumerate() {
+                let wanted = mask & (1 << i) != 0;
+                if wanted != held[i] {
+                    let verb = if wanted { "take" } else { "drop" };
+                    self.terminal.send(&format!("{} {}", verb, item));
+                    held[i] = wanted;
+                }
+            }
+
+            if let Some(password) = password(&self.terminal.send(&floor)) {
+                return Some(password);
+            }
+        }
+
+        None
+    }
+}
+
+/// Maps the ship, gathers everything that's safe to carry, then works out
+/// which of it gets the droid past the pressure-sensitive floor.
+fn find_password<T: Terminal>(terminal: &mut T) -> String {
+    let start = parse_room(&terminal.send("")).expect("droid didn't say where it is");
+
+    let mut explorer = Explorer {
+        terminal,
+        visited: HashSet::new(),
+        items: Vec::new(),
+        checkpoint: None,
+        floor: None,
+        password: None,
+    };
+    explorer.explore(start, &mut Vec::new());
+
+    if let Some(password) = explorer.password.take() {
+        return password;
+    }
+
+    let path = explorer
+        .checkpoint
+        .clone()
+        .expect("didn't find the checkpoint");
+    for dir in &path {
+        explorer.terminal.send(dir);
+    }
+
+    explorer
+        .weigh()
+        .expect("no combination of items was the right weight")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// A small ship that talks like the real one.
+    struct Ship {
+        rooms: HashMap<&'static str, Vec<(&'static str, &'static str)>>,
+        items: HashMap<&'static str, Vec<&'static str>>,
+        weights: HashMap<&'static str, u32>,
+        inventory: Vec<&'static str>,
+        room: &'static str,
+    }
+
+    impl Ship {
+        fn new() -> Ship {
+            let rooms = HashMap::from([
+                (
+                    "Hull Breach",
+                    vec![("north", "Kitchen"), ("east", "Hallway")],
+                ),
+                ("Kitchen", vec![("south", "Hull Breach")]),
+                (
+                    "Hallway",
+                    vec![
+                        ("north", "Arcade"),
+                        ("east", CHECKPOINT),
+                        ("west", "Hull Breach"),
+                    ],
+                ),
+                ("Arcade", vec![("south", "Hallway")]),
+                (
+                    CHECKPOINT,
+                    vec![("north", "Pressure-Sensitive Floor"), ("west", "Hallway")],
+                ),
+            ]);
+            let items = HashMap::from([
+                ("Kitchen", vec!["mug"]),
+                ("Hallway", vec!["molten lava"]),
+                ("Arcade", vec!["spool of cat6", "hypercube"]),
+                (CHECKPOINT, vec!["coin"]),
+            ]);
+            let weights = HashMap::from([
+                ("mug", 3),
+                ("spool of cat6", 6),
+                ("hypercube", 9),
+                ("coin", 2),
+            ]);
+
+            Ship {
+                rooms,
+                items,
+                weights,
+                inventory: Vec::new(),
+                room: "Hull Breach",
+            }
+        }
+
+        fn describe(&self) -> String {
+            let mut text = format!("\n\n\n== {} ==\nA room.\n\nDoors here lead:\n", self.room);
+            for (door, _) in &self.rooms[self.room] {
+                text += &format!("- {}\n", door);
+            }
+
+            let items = self.items.get(self.room).cloned().unwrap_or_default();
+            if !items.is_empty() {
+                text += "\nItems here:\n";
+                for item in items {
+                    text += &format!("- {}\n", item);
+                }
+            }
+
+            text + "\nCommand?\n"
+        }
+    }
+
+    impl Terminal for Ship {
+        fn send(&mut self, command: &str) -> String {
+            if command.is_empty() {
+                return self.describe();
+            }
+
+            if let Some(item) = command.strip_prefix("take ") {
+                assert_ne!(item, "molten lava", "the droid melted");
+                let here = self.items.get_mut(self.room).unwrap();
+                let i = here.iter().position(|&i| i == item).unwrap();
+                self.inventory.push(here.remove(i));
+                return format!("\nYou take the {}.\n\nCommand?\n", item);
+            }
+
+            if let Some(item) = command.strip_prefix("drop ") {
+                let i = self.inventory.iter().position(|&i| i == item).unwrap();
+                let item = self.inventory.remove(i);
+                self.items.entry(self.room).or_default().push(item);
+                return format!("\nYou drop the {}.\n\nCommand?\n", item);
+            }
+
+            let (_, to) = *self.rooms[self.room]
+                .iter()
+                .find(|(door, _)| *door == command)
+                .expect("no door that way");
+
+            if to != "Pressure-Sensitive Floor" {
+                self.room = to;
+                return self.describe();
+            }
+
+            let weight: u32 = self.inventory.iter().map(|i| self.weights[i]).sum();
+            let floor = "\n\n\n== Pressure-Sensitive Floor ==\nAnalyzing...\n\n\
+                         Doors here lead:\n- south\n\n";
+            match weight.cmp(&14) {
+                std::cmp::Ordering::Equal => format!(
+                    "{}A loud, robotic voice says \"Analysis complete! You may proceed.\" \
+                     and you enter the cockpit.\nSanta notices your small droid, looks \
+                     puzzled for a moment, realizes what has happened, and radios your \
+                     ship directly.\n\"Oh, hello! You should be able to get in by typing \
+                     2424308736 on the keypad at the main airlock.\"\n",
+                    floor
+                ),
+                order => {
+                    let heavier = if order == std::cmp::Ordering::Less {
+                        "heavier"
+                    } else {
+                        "lighter"
+                    };
+                    format!(
+                        "{}A loud, robotic voice says \"Alert! Droids on this ship are {} \
+                         than the detected value!\" and you are ejected back to the \
+                         checkpoint.\n{}",
+                        floor,
+                        heavier,
+                        self.describe()
+                    )
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_room_test() {
+        let ship = Ship::new();
+        assert_eq!(
+            parse_room(&ship.describe()),
+            Some(Room {
+                name: "Hull Breach".to_string(),
+                doors: vec!["north".to_string(), "east".to_string()],
+                items: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn find_password_test() {
+        let mut ship = Ship::new();
+        assert_eq!(find_password(&mut ship), "2424308736");
+
+        let mut inventory = ship.inventory.clone();
+        inventory.sort();
+        assert_eq!(inventory, vec!["coin", "hypercube", "mug"]);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_runner::run(&aoc_2019_day25::DAY);
+}
@@ -0,0 +1,52 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The entries, largest first.
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        let mut entries: Vec<i64> = lines(input, number)?;
+        entries.sort_by(|a, b| b.cmp(a));
+        Ok(entries)
+    }
+
+    fn part_one(entries: &Vec<i64>) -> Answer {
+        expense_sum(entries, 2020, 2).unwrap_or(0).into()
+    }
+
+    fn part_two(entries: &Vec<i64>) -> Answer {
+        expense_sum(entries, 2020, 3).unwrap_or(0).into()
+    }
+}
+
+fn expense_sum(entries: &[i64], total: i64, depth: i8) -> Option<i64> {
+    if depth == 1 {
+        return entries.iter().find(|&&x| x == total).copied();
+    }
+
+    entries.iter().enumerate().find_map(|(i, &x)| {
+        if x < total {
+            expense_sum(&entries[i + 1..entries.len()], total - x, depth - 1).map(|y| x * y)
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expense_sum_test() {
+        let entries = Puzzle::parse("1721\n979\n366\n299\n675\n1456\n").unwrap();
+
+        assert_eq!(expense_sum(&entries, 2020, 2), Some(514579));
+        assert_eq!(expense_sum(&entries, 2020, 3), Some(241861950));
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_parse::combinators::{complete, int, take_while, word, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Password<'a>>;
+
+    fn parse(input: &str) -> Result<Vec<Password<'_>>, ParseError> {
+        lines(input, complete(Password::parse))
+    }
+
+    fn part_one(passwords: &Vec<Password<'_>>) -> Answer {
+        passwords
+            .iter()
+            .map(Password::is_valid)
+            .filter(|(a, _)| *a)
+            .count()
+            .into()
+    }
+
+    fn part_two(passwords: &Vec<Password<'_>>) -> Answer {
+        passwords
+            .iter()
+            .map(Password::is_valid)
+            .filter(|(_, b)| *b)
+            .count()
+            .into()
+    }
+}
+
+pub struct Password<'a> {
+    min: usize,
+    max: usize,
+    chr: char,
+    word: &'a str,
+}
+
+impl Password<'_> {
+    /// Parses a password and its policy, like `1-3 a: abcde`.
+    fn parse(line: &str) -> Parsed<'_, Password<'_>> {
+        let (min, s) = int(line)?;
+        let (max, s) = ("-", int).read(s)?;
+        let (chr, s) = (" ", take_while("a letter", |c| c.is_alphabetic())).read(s)?;
+        let (word, s) = (": ", word).read(s)?;
+
+        let password = Password {
+            min,
+            max,
+            chr: chr
+                .parse()
+                .map_err(|_| ParseError::expected("a letter", chr))?,
+            word,
+        };
+        Ok((password, s))
+    }
+
+    fn is_valid(&self) -> (bool, bool) {
+        let count = self.word.chars().filter(|&c| c == self.chr).count();
+
+        let first = self.word.chars().nth(self.min - 1) == Some(self.chr);
+        let second = self.word.chars().nth(self.max - 1) == Some(self.chr);
+
+        (count >= self.min && count <= self.max, first ^ second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_valid(line: &str) -> (bool, bool) {
+        complete(Password::parse)(line).unwrap().is_valid()
+    }
+
+    #[test]
+    fn is_valid_test() {
+        assert_eq!(is_valid("1-3 a: abcde"), (true, true));
+        assert_eq!(is_valid("1-3 b: cdefg"), (false, false));
+        assert_eq!(is_valid("2-9 c: ccccccccc"), (true, false));
+    }
+}
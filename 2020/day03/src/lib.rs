@@ -0,0 +1,48 @@
+use aoc_grid::Grid;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        let map = Grid::try_parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            c => Err(ParseError::expected("'#' or '.'", &c.to_string())),
+        })?;
+
+        if map.width() == 0 {
+            return Err(ParseError::new("expected a map of the trees"));
+        }
+
+        Ok(map)
+    }
+
+    fn part_one(map: &Grid<bool>) -> Answer {
+        trees_hit(map, 3, 1).into()
+    }
+
+    fn part_two(map: &Grid<bool>) -> Answer {
+        part_two(map).into()
+    }
+}
+
+fn part_two(map: &Grid<bool>) -> usize {
+    [(3, 1), (1, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|&(x, y)| trees_hit(map, x, y))
+        .product()
+}
+
+fn trees_hit(map: &Grid<bool>, x: usize, y: usize) -> usize {
+    let width = map.width();
+
+    map.rows()
+        .enumerate()
+        .filter(|(i, line)| i % y == 0 && line[(i / y * x) % width])
+        .count()
+}
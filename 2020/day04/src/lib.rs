@@ -0,0 +1,143 @@
+use aoc_parse::blocks;
+use aoc_parse::combinators::{complete, take_while, word, Parser};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Passport<'a>>;
+
+    fn parse(input: &str) -> Result<Vec<Passport<'_>>, ParseError> {
+        blocks(input, parse_passport)
+    }
+
+    fn part_one(passports: &Vec<Passport<'_>>) -> Answer {
+        validity(passports).0.into()
+    }
+
+    fn part_two(passports: &Vec<Passport<'_>>) -> Answer {
+        validity(passports).1.into()
+    }
+}
+
+/// A passport's fields, by their keys.
+type Passport<'a> = HashMap<&'a str, &'a str>;
+
+/// Parses the `key:value` fields of a passport, which are separated by
+/// spaces or line breaks.
+fn parse_passport(p: &str) -> Result<Passport<'_>, ParseError> {
+    p.split_whitespace()
+        .map(complete(|field| {
+            let (key, rest) = word(field)?;
+            (":", take_while("a value", |_| true))
+                .read(rest)
+                .map(|(value, rest)| ((key, value), rest))
+        }))
+        .collect()
+}
+
+/// How many passports have all the fields, and how many are also valid.
+fn validity(passports: &[Passport<'_>]) -> (usize, usize) {
+    passports
+        .iter()
+        .map(validate_passport)
+        .fold((0, 0), |(acc_p, acc_v), (p, v)| {
+            (
+                if p { acc_p + 1 } else { acc_p },
+                if v { acc_v + 1 } else { acc_v },
+            )
+        })
+}
+
+fn validate_passport(values: &Passport<'_>) -> (bool, bool) {
+    let mut validity: Vec<(bool, bool)> = Vec::new();
+
+    validity.push(
+        values
+            .get("byr")
+            .map_or((false, false), |val| (true, validate_num(val, 1920, 2002))),
+    );
+
+    validity.push(
+        values
+            .get("iyr")
+            .map_or((false, false), |val| (true, validate_num(val, 2010, 2020))),
+    );
+
+    validity.push(
+        values
+            .get("eyr")
+            .map_or((false, false), |val| (true, validate_num(val, 2020, 2030))),
+    );
+
+    validity.push(
+        values
+            .get("hgt")
+            .map_or((false, false), |val| (true, validate_hgt(val))),
+    );
+
+    validity.push(
+        values
+            .get("hcl")
+            .map_or((false, false), |val| (true, validate_hcl(val))),
+    );
+
+    validity.push(
+        values
+            .get("ecl")
+            .map_or((false, false), |val| (true, validate_ecl(val))),
+    );
+
+    validity.push(
+        values
+            .get("pid")
+            .map_or((false, false), |val| (true, validate_pid(val))),
+    );
+
+    validity
+        .iter()
+        .fold((true, true), |(acc_p, acc_v), &(p, v)| {
+            (acc_p && p, acc_v && v)
+        })
+}
+
+fn validate_num(val: &str, min: usize, max: usize) -> bool {
+    val.parse::<usize>()
+        .map(|v| v >= min && v <= max)
+        .unwrap_or(false)
+}
+
+fn validate_hgt(val: &str) -> bool {
+    if val.ends_with("cm") {
+        return validate_num(val.strip_suffix("cm").unwrap(), 150, 193);
+    }
+
+    if val.ends_with("in") {
+        return validate_num(val.strip_suffix("in").unwrap(), 59, 76);
+    }
+
+    false
+}
+
+fn validate_hcl(val: &str) -> bool {
+    val.strip_prefix('#').is_some_and(|hex| {
+        hex.len() == 6 && hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+    })
+}
+
+fn validate_ecl(val: &str) -> bool {
+    val == "amb"
+        || val == "blu"
+        || val == "brn"
+        || val == "gry"
+        || val == "grn"
+        || val == "hzl"
+        || val == "oth"
+}
+
+fn validate_pid(val: &str) -> bool {
+    val.len() == 9 && val.chars().all(|c| c.is_ascii_digit())
+}
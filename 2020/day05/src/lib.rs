@@ -0,0 +1,73 @@
+use aoc_parse::{each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 5);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The seat IDs on the boarding passes.
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        let passes = lines(input, seat_id)?;
+        if passes.is_empty() {
+            return Err(ParseError::new("expected at least one boarding pass"));
+        }
+
+        Ok(passes)
+    }
+
+    fn part_one(passes: &Vec<usize>) -> Answer {
+        passes.iter().max().copied().unwrap_or_default().into()
+    }
+
+    fn part_two(passes: &Vec<usize>) -> Answer {
+        part_two(passes.clone()).into()
+    }
+}
+
+/// Our seat is the only gap in the sorted IDs.
+fn part_two(mut passes: Vec<usize>) -> usize {
+    passes.sort();
+
+    let mut current = passes.first().unwrap() - 1;
+    for id in passes {
+        if id - current == 2 {
+            return current + 1;
+        }
+        current = id;
+    }
+
+    panic!("no empty seat")
+}
+
+/// Reads a boarding pass like `FBFBBFFRLR` as binary, with `B` and `R` as
+/// the ones.
+fn seat_id(spec: &str) -> Result<usize, ParseError> {
+    let bits = each_char(spec, "'F', 'B', 'L' or 'R'", |c| match c {
+        'F' | 'L' => Some(0),
+        'B' | 'R' => Some(1),
+        _ => None,
+    })?;
+
+    Ok(bits.into_iter().fold(0, |acc, bit| acc * 2 + bit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seat_id_test() {
+        assert_eq!(seat_id("BFFFBBFRRR"), Ok(567));
+        assert_eq!(seat_id("FFFBBBFRRR"), Ok(119));
+        assert_eq!(seat_id("BBFFBBFRLL"), Ok(820));
+
+        let err = Puzzle::parse("BFFFBBFRRR\nFFFBXBFRRR\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected 'F', 'B', 'L' or 'R', found \"X\""
+        );
+    }
+}
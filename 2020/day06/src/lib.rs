@@ -0,0 +1,55 @@
+use aoc_parse::{blocks, each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The questions each person in each group answered "yes" to.
+    type Input<'a> = Vec<Vec<HashSet<char>>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<HashSet<char>>>, ParseError> {
+        blocks(input, |group| lines(group, parse_answers))
+    }
+
+    fn part_one(groups: &Vec<Vec<HashSet<char>>>) -> Answer {
+        groups
+            .iter()
+            .map(|group| unique_answers(group))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(groups: &Vec<Vec<HashSet<char>>>) -> Answer {
+        groups
+            .iter()
+            .map(|group| joint_answers(group))
+            .sum::<usize>()
+            .into()
+    }
+}
+
+fn parse_answers(person: &str) -> Result<HashSet<char>, ParseError> {
+    let answers = each_char(person, "a question from 'a' to 'z'", |c| {
+        c.is_ascii_lowercase().then_some(c)
+    })?;
+
+    Ok(answers.into_iter().collect())
+}
+
+fn unique_answers(group: &[HashSet<char>]) -> usize {
+    group.iter().flatten().collect::<HashSet<&char>>().len()
+}
+
+fn joint_answers(group: &[HashSet<char>]) -> usize {
+    let (first, rest) = group.split_first().expect("blocks aren't empty");
+
+    let mut answered = first.clone();
+    for person in rest {
+        answered.retain(|c| person.contains(c))
+    }
+
+    answered.len()
+}
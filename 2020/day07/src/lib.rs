@@ -0,0 +1,129 @@
+use aoc_parse::combinators::{complete, either, int, list, map, word, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+// use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 7);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Rules<'a>;
+
+    fn parse(input: &str) -> Result<Rules<'_>, ParseError> {
+        let rules = lines(input, complete(parse_line))?;
+        Ok(rules.into_iter().collect())
+    }
+
+    fn part_one(rules: &Rules<'_>) -> Answer {
+        part_one(rules).into()
+    }
+
+    fn part_two(rules: &Rules<'_>) -> Answer {
+        part_two(rules).into()
+    }
+}
+
+/// The bags that each colour of bag has to hold, and how many of them.
+type Rules<'a> = HashMap<&'a str, Vec<(usize, &'a str)>>;
+
+/// The number of bags that can eventually hold a shiny gold one.
+fn part_one(rules: &Rules<'_>) -> usize {
+    let mut results: HashMap<&str, bool> = HashMap::new();
+    for bag in rules.keys().clone() {
+        contains_bag(rules, &mut results, bag, "shiny gold");
+    }
+    results.iter().filter(|&(_, &v)| v).count()
+}
+
+/// The number of bags inside a shiny gold one.
+fn part_two(rules: &Rules<'_>) -> usize {
+    let mut results: HashMap<&str, usize> = HashMap::new();
+    descendants(rules, &mut results, "shiny gold") - 1 // -1 to remove the initial shiny gold
+}
+
+/// Parses a rule like
+/// `light red bags contain 1 bright white bag, 2 muted yellow bags.`
+fn parse_line(line: &str) -> Parsed<'_, (&str, Vec<(usize, &str)>)> {
+    let (container, s) = colour(line)?;
+    let (_, s) = " bags contain ".read(s)?;
+    let none = map("no other bags", |_| Vec::new());
+    let (contents, s) = either(none, list(contents)).read(s)?;
+    let (_, s) = ".".read(s)?;
+
+    Ok(((container, contents), s))
+}
+
+/// Some number of bags of one colour, like `2 muted yellow bags`.
+fn contents(s: &str) -> Parsed<'_, (usize, &str)> {
+    let (n, s) = int(s)?;
+    let (colour, s) = (" ", colour).read(s)?;
+    let (_, s) = either(" bags", " bag").read(s)?;
+    Ok(((n, colour), s))
+}
+
+/// A colour, which is two words like `muted yellow`.
+fn colour(input: &str) -> Parsed<'_, &str> {
+    let (first, s) = word(input)?;
+    let (second, s) = (" ", word).read(s)?;
+    Ok((&input[..first.len() + 1 + second.len()], s))
+}
+
+fn contains_bag<'a>(
+    rules: &HashMap<&str, Vec<(usize, &'a str)>>,
+    results: &mut HashMap<&'a str, bool>,
+    bag: &'a str,
+    target: &str,
+) -> bool {
+    if let Some(&x) = results.get(bag) {
+        return x;
+    }
+
+    if bag == target {
+        return true;
+    }
+
+    let mut any_contains_target = false;
+    if let Some(contains) = rules.get(bag) {
+        for (_, bag) in contains {
+            let bag_contains_target = contains_bag(rules, results, bag, target);
+            any_contains_target |= bag_contains_target;
+        }
+    }
+
+    results.insert(bag, any_contains_target);
+    any_contains_target
+}
+
+fn descendants<'a>(
+    rules: &HashMap<&str, Vec<(usize, &'a str)>>,
+    results: &mut HashMap<&'a str, usize>,
+    bag: &'a str,
+) -> usize {
+    if let Some(&x) = results.get(bag) {
+        return x;
+    }
+
+    let mut count = 1;
+    if let Some(bag_rules) = rules.get(bag) {
+        for (n, bag) in bag_rules {
+            count += n * descendants(rules, results, bag);
+        }
+    }
+
+    results.insert(bag, count);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line_test() {
+        let line = "light red bags contain 1 bright white bag, 2 muted yellow bags.";
+        let expected = vec![(1, "bright white"), (2, "muted yellow")];
+        assert_eq!(parse_line(line), Ok((("light red", expected), "")));
+    }
+}
@@ -0,0 +1,157 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+pub enum Op {
+    Nop,
+    Acc,
+    Jmp,
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Op, ParseError> {
+        match s {
+            "nop" => Ok(Op::Nop),
+            "jmp" => Ok(Op::Jmp),
+            "acc" => Ok(Op::Acc),
+            _ => Err(ParseError::expected("an operation", s)),
+        }
+    }
+}
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(Op, isize)>;
+
+    fn parse(input: &str) -> Result<Vec<(Op, isize)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(ops: &Vec<(Op, isize)>) -> Answer {
+        last_accumulator(ops).into()
+    }
+
+    fn part_two(ops: &Vec<(Op, isize)>) -> Answer {
+        find_terminator(ops, &HashSet::new(), 0, 0, true)
+            .unwrap()
+            .into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Op, isize)>, ParseError> {
+    lines(input, |line| {
+        let (op, n) = split_once(line, " ")?;
+        Ok((op.parse()?, number(n)?))
+    })
+}
+
+fn last_accumulator(ops: &[(Op, isize)]) -> isize {
+    let mut acc: isize = 0;
+    let mut pos: usize = 0;
+    let mut visited: HashSet<usize> = HashSet::new();
+
+    loop {
+        let is_new = visited.insert(pos);
+        if !is_new {
+            return acc;
+        }
+
+        let (op, n) = ops.get(pos).unwrap();
+        match op {
+            Op::Nop => pos += 1,
+            Op::Acc => {
+                acc += n;
+                pos += 1;
+            }
+            Op::Jmp => pos = usize::try_from(isize::try_from(pos).unwrap() + n).unwrap(),
+        }
+    }
+}
+
+fn find_terminator(
+    ops: &Vec<(Op, isize)>,
+    visited: &HashSet<usize>,
+    acc: isize,
+    pos: usize,
+    can_switch: bool,
+) -> Option<isize> {
+    // If we get to the end of the ops, we've terminated!
+    if pos == ops.len() {
+        return Some(acc);
+    }
+
+    if pos > ops.len() {
+        return None;
+    }
+
+    let mut visited = visited.clone();
+    let is_new = visited.insert(pos);
+    if !is_new {
+        return None;
+    }
+
+    let (op, n) = ops.get(pos).unwrap();
+    match op {
+        Op::Acc => find_terminator(ops, &visited, acc + n, pos + 1, can_switch),
+        Op::Nop => {
+            let nop = find_terminator(ops, &visited, acc, pos + 1, can_switch);
+            if can_switch {
+                return nop.or({
+                    let jmp_pos = usize::try_from(isize::try_from(pos).unwrap() + n);
+                    if jmp_pos.is_err() {
+                        return None;
+                    }
+                    find_terminator(ops, &visited, acc, jmp_pos.unwrap(), false)
+                });
+            }
+            nop
+        }
+        Op::Jmp => {
+            let jmp_pos = usize::try_from(isize::try_from(pos).unwrap() + n);
+            if jmp_pos.is_err() {
+                return None;
+            }
+            let jmp = find_terminator(ops, &visited, acc, jmp_pos.unwrap(), can_switch);
+
+            if can_switch {
+                return jmp.or(find_terminator(ops, &visited, acc, pos + 1, false));
+            }
+            jmp
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn last_accumulator_test() {
+        let ops = parse_input(INPUT).unwrap();
+        assert_eq!(last_accumulator(&ops), 5);
+    }
+
+    #[test]
+    fn find_terminator_test() {
+        let ops = parse_input(INPUT).unwrap();
+        let visited = HashSet::new();
+        assert_eq!(find_terminator(&ops, &visited, 0, 0, true), Some(8));
+    }
+}
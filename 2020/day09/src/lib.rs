@@ -0,0 +1,123 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 9);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        lines(input, number)
+    }
+
+    fn part_one(codes: &Vec<usize>) -> Answer {
+        first_invalid(codes, 25).into()
+    }
+
+    fn part_two(codes: &Vec<usize>) -> Answer {
+        contiguous_range(codes, first_invalid(codes, 25)).into()
+    }
+}
+
+fn first_invalid(codes: &[usize], preamble_size: usize) -> usize {
+    for i in preamble_size..codes.len() {
+        if !is_valid(codes, i, preamble_size) {
+            return *codes.get(i).unwrap();
+        }
+    }
+
+    0
+}
+
+fn is_valid(codes: &[usize], i: usize, preamble_size: usize) -> bool {
+    let target = *codes.get(i).unwrap();
+
+    for j in i - preamble_size..i {
+        let first = *codes.get(j).unwrap();
+        for k in j + 1..i {
+            if *codes.get(k).unwrap() + first == target {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn contiguous_range(codes: &[usize], target: usize) -> usize {
+    for i in 0..codes.len() {
+        if let Some(j) = contiguous_range_from(codes, target, i) {
+            let mut min = *codes.get(i).unwrap();
+            let mut max = *codes.get(i).unwrap();
+            for k in i..j + 1 {
+                let x = *codes.get(k).unwrap();
+                if x < min {
+                    min = x;
+                }
+                if x > max {
+                    max = x;
+                }
+            }
+
+            return min + max;
+        }
+    }
+
+    0
+}
+
+fn contiguous_range_from(codes: &[usize], target: usize, i: usize) -> Option<usize> {
+    let mut j = i;
+    let mut sum = *codes.get(j).unwrap();
+
+    loop {
+        j += 1;
+        sum += *codes.get(j).unwrap();
+
+        if sum > target {
+            return None;
+        }
+
+        if sum == target {
+            return Some(j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+    #[test]
+    fn first_invalid_test() {
+        assert_eq!(first_invalid(&Puzzle::parse(INPUT).unwrap(), 5), 127);
+    }
+
+    #[test]
+    fn contiguous_range_test() {
+        assert_eq!(contiguous_range(&Puzzle::parse(INPUT).unwrap(), 127), 62);
+    }
+}
@@ -0,0 +1,129 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 10);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The adapters' joltages, in order.
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        let mut joltages: Vec<usize> = lines(input, number)?;
+        joltages.sort();
+
+        // Every adapter has to be able to take the one before it
+        let mut previous = 0;
+        for &joltage in &joltages {
+            if joltage == 0 || joltage - previous > 3 {
+                return Err(ParseError::new(format!(
+                    "expected an adapter within 3 jolts of {}, but the next is {}",
+                    previous, joltage
+                )));
+            }
+            previous = joltage;
+        }
+
+        if joltages.is_empty() {
+            return Err(ParseError::new("expected at least one adapter"));
+        }
+
+        Ok(joltages)
+    }
+
+    fn part_one(joltages: &Vec<usize>) -> Answer {
+        part_one(joltages).into()
+    }
+
+    fn part_two(joltages: &Vec<usize>) -> Answer {
+        arrangements(joltages).into()
+    }
+}
+
+fn part_one(joltages: &[usize]) -> usize {
+    let diffs = diffs(joltages);
+    diffs[1] * diffs[3]
+}
+
+fn diffs(joltages: &[usize]) -> [usize; 4] {
+    let mut joltages = joltages.to_vec();
+    joltages.insert(0, 0);
+
+    let mut diffs: [usize; 4] = [0, 0, 0, 1];
+    for i in 1..joltages.len() {
+        let diff = joltages[i] - joltages[i - 1];
+        diffs[diff] += 1;
+    }
+
+    diffs
+}
+
+fn arrangements(joltages: &[usize]) -> usize {
+    let joltages: HashSet<usize> = joltages.iter().copied().collect();
+    let target = joltages.iter().max().unwrap() + 3;
+    let mut m: HashMap<usize, usize> = HashMap::new();
+    arrangements_rec(&joltages, &mut m, 0, target)
+}
+
+fn arrangements_rec(
+    joltages: &HashSet<usize>,
+    m: &mut HashMap<usize, usize>,
+    n: usize,
+    target: usize,
+) -> usize {
+    if let Some(x) = m.get(&n) {
+        return *x;
+    }
+
+    // Add up the number of ways
+    let mut sum = 0;
+    for i in 1..4 {
+        if n + i == target {
+            sum += 1;
+        } else if joltages.contains(&(n + i)) {
+            sum += arrangements_rec(joltages, m, n + i, target);
+        }
+    }
+
+    m.insert(n, sum);
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4";
+
+    #[test]
+    fn diffs_test() {
+        assert_eq!(diffs(&Puzzle::parse(INPUT).unwrap()), [0, 7, 0, 5]);
+    }
+
+    #[test]
+    fn arrangements_test() {
+        assert_eq!(arrangements(&Puzzle::parse(INPUT).unwrap()), 8);
+    }
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("1\n4\n8\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected an adapter within 3 jolts of 4, but the next is 8"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use aoc_grid::{Grid, ADJACENT};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::fmt;
+
+pub const DAY: Day = Day::new::<Puzzle>(2020, 11);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Layout;
+
+    fn parse(input: &str) -> Result<Layout, ParseError> {
+        Layout::parse(input)
+    }
+
+    fn part_one(layout: &Layout) -> Answer {
+        settle(layout, &Rules::adjacent(layout)).into()
+    }
+
+    fn part_two(layout: &Layout) -> Answer {
+        settle(layout, &Rules::in_sight(layout)).into()
+    }
+}
+
+/// How many seats are taken once people stop moving around.
+fn settle(layout: &Layout, rules: &Rules) -> usize {
+    generations(layout, rules)
+        .last()
+        .unwrap_or_else(|| layout.clone())
+        .occupied()
+}
+
+/// Every layout after the first, up to the one that doesn't change any more.
+pub fn generations<'a>(layout: &Layout, rules: &'a Rules) -> Generations<'a> {
+    Generations {
+        layout: layout.clone(),
+        rules,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Position {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+/// The seats a row at a time, in one `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    width: usize,
+    positions: Vec<Position>,
+}
+
+impl Layout {
+    fn parse(input: &str) -> Result<Layout, ParseError> {
+        let grid = Grid::try_parse(input, |c| match c {
+            '.' => Ok(Position::Floor),
+            'L' => Ok(Position::Empty),
+            '#' => Ok(Position::Occupied),
+            _ => Err(ParseError::expected("'.', 'L' or '#'", &c.to_string())),
+        })?;
+
+        Ok(Layout {
+            width: grid.width(),
+            positions: grid.values().copied().collect(),
+        })
+    }
+
+    fn height(&self) -> usize {
+        self.positions.len() / self.width.max(1)
+    }
+
+    fn occupied(&self) -> usize {
+        self.positions
+            .iter()
+            .filter(|&&p| p == Position::Occupied)
+            .count()
+    }
+
+    /// The seats each seat can see, either only those next to it or the first
+    /// seat in each direction past any floor.
+    fn neighbours(&self, far: bool) -> Vec<Vec<usize>> {
+        (0..self.positions.len())
+            .map(|i| match self.positions[i] {
+                Position::Floor => Vec::new(),
+                _ => ADJACENT
+                    .iter()
+                    .filter_map(|&direction| self.look(i, direction, far))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// The first seat from `i` in a direction, looking over floor only if
+    /// `far` is set.
+    fn look(&self, i: usize, (dx, dy): (isize, isize), far: bool) -> Option<usize> {
+        let (mut x, mut y) = (i % self.width, i / self.width);
+        loop {
+            x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+            y = y.checked_add_signed(dy).filter(|&y| y < self.height())?;
+
+            let j = y * self.width + x;
+            match self.positions[j] {
+                Position::Floor if far => continue,
+                Position::Floor => return None,
+                _ => return Some(j),
+            }
+        }
+    }
+
+    /// Everyone moves at once: people sit in empty seats with no one around
+    /// them, and leave seats with too many people around them.
+    fn step(&self, rules: &Rules) -> Layout {
+        let positions = self
+            .positions
+            .iter()
+            .zip(&rules.neighbours)
+            .map(|(&position, neighbours)| {
+                let taken = neighbours
+                    .iter()
+                    .filter(|&&j| self.positions[j] == Position::Occupied)
+                    .count();
+                match position {
+                    Position::Empty if taken == 0 => Position::Occupied,
+                    Position::Occupied if taken >= rules.tolerance => Position::Empty,
+                    position => position,
+                }
+            })
+            .collect();
+
+        Layout {
+            width: self.width,
+            positions,
+        }
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.positions.chunks(self.width.max(1)) {
+            for position in row {
+                let c = match position {
+                    Position::Floor => '.',
+                    Position::Empty => 'L',
+                    Position::Occupied => '#',
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Which seats people pay attention to, worked out once for every seat, and
+/// how many of them being taken makes someone get up.
+pub struct Rules {
+    neighbours: Vec<Vec<usize>>,
+    tolerance: usize,
+}
+
+impl Rules {
+    /// People look at the eight seats around them, and leave if four are
+    /// taken.
+    pub fn adjacent(layout: &Layout) -> Rules {
+        Rules {
+            neighbours: layout.neighbours(false),
+            tolerance: 4,
+        }
+    }
+
+    /// People look at the first seat they can see in each of the eight
+    /// directions, and leave if five are taken.
+    pub fn in_sight(layout: &Layout) -> Rules {
+        Rules {
+            neighbours: layout.neighbours(true),
+            tolerance: 5,
+        }
+    }
+}
+
+/// The layouts one after another, stopping once people stop moving.
+pub struct Generations<'a> {
+    layout: Layout,
+    rules: &'a Rules,
+}
+
+impl Iterator for Generations<'_> {
+    type Item = Layout;
+
+    fn next(&mut self) -> Option<Layout> {
+        let next = self.layout.step(self.rules);
+        if next == self.layout {
+            return None;
+        }
+
+        self.layout = next.clone();
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let layout = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(settle(&layout, &Rules::adjacent(&layout)), 37);
+        assert_eq!(settle(&layout, &Rules::in_sight(&layout)), 26);
+    }
+
+    #[test]
+    fn generations_test() {
+        let layout = Puzzle::parse(EXAMPLE).unwrap();
+
+        let rules = Rules::adjacent(&layout);
+        let layouts: Vec<Layout> = generations(&layout, &rules).collect();
+        assert_eq!(layouts.len(), 5);
+        assert_eq!(layouts[0].to_string(), EXAMPLE.replace('L', "#"));
+        assert_eq!(
+            layouts[1].to_string(),
+            "#.LL.L#.##\n\
+             #LLLLLL.L#\n\
+             L.L.L..L..\n\
+             #LLL.LL.L#\n\
+             #.LL.LL.LL\n\
+             #.LLLL#.##\n\
+             ..L.L.....\n\
+             #LLLLLLLL#\n\
+             #.LLLLLL.L\n\
+             #.#LLLL.##\n"
+        );
+
+        let rules = Rules::in_sight(&layout);
+        let layouts: Vec<Layout> = generations(&layout, &rules).collect();
+        assert_eq!(layouts.len(), 6);
+        assert_eq!(
+            layouts[1].to_string(),
+            "#.LL.LL.L#\n\
+             #LLLLLL.LL\n\
+             L.L.L..L..\n\
+             LLLL.LL.LL\n\
+             L.LL.LL.LL\n\
+             L.LLLLL.LL\n\
+             ..L.L.....\n\
+             LLLLLLLLL#\n\
+             #.LLLLLL.L\n\
+             #.LLLLL.L#\n"
+        );
+    }
+}
@@ -1,11 +1,3 @@
 fn main() {
-    println!("Hello, world!");
+    aoc_runner::run(&aoc_2020_day11::DAY);
 }
-
-enum Position {
-    Floor,
-    Empty,
-    Occupied,
-}
-
-fn neighbours(pos: usize, width: usize, height: usize) -> Vec<usize> {}
@@ -0,0 +1,47 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(measurements: &Vec<i32>) -> Answer {
+        count_increases(measurements, 1).into()
+    }
+
+    fn part_two(measurements: &Vec<i32>) -> Answer {
+        count_increases(measurements, 3).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    lines(input, number)
+}
+
+// count_increases counts the number
+fn count_increases(measurements: &[i32], offset: usize) -> usize {
+    measurements
+        .iter()
+        .zip(measurements.iter().skip(offset))
+        .filter(|(a, b)| a < b)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(count_increases(&values, 1), 7, "part one");
+        assert_eq!(count_increases(&values, 3), 5, "part two");
+    }
+}
@@ -0,0 +1,83 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::str::FromStr;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_file(input)
+    }
+
+    fn part_one(input: &Vec<Instruction>) -> Answer {
+        let (x, y) = part_one(input);
+        (x * y).into()
+    }
+
+    fn part_two(input: &Vec<Instruction>) -> Answer {
+        let (x, y, _) = part_two(input);
+        (x * y).into()
+    }
+}
+
+pub enum Instruction {
+    Forward(i64),
+    Up(i64),
+    Down(i64),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, i) = split_once(s, " ")?;
+
+        let i = number(i)?;
+        match direction {
+            "forward" => Ok(Instruction::Forward(i)),
+            "up" => Ok(Instruction::Up(i)),
+            "down" => Ok(Instruction::Down(i)),
+            _ => Err(ParseError::expected("forward, up or down", direction)),
+        }
+    }
+}
+
+fn parse_file(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input, str::parse)
+}
+
+fn part_one(instructions: &[Instruction]) -> (i64, i64) {
+    instructions.iter().fold((0, 0), |(x, y), ins| match ins {
+        Instruction::Forward(i) => (x + i, y),
+        Instruction::Up(i) => (x, y - i),
+        Instruction::Down(i) => (x, y + i),
+    })
+}
+
+fn part_two(instructions: &[Instruction]) -> (i64, i64, i64) {
+    instructions
+        .iter()
+        .fold((0, 0, 0), |(x, y, aim), ins| match ins {
+            Instruction::Forward(i) => (x + i, y + aim * i, aim),
+            Instruction::Up(i) => (x, y, aim - i),
+            Instruction::Down(i) => (x, y, aim + i),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let instructions = parse_file(EXAMPLE).unwrap();
+        assert_eq!(part_one(&instructions), (15, 10), "part one");
+        assert_eq!(part_two(&instructions), (15, 60, 10), "part two");
+    }
+}
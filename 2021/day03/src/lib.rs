@@ -0,0 +1,139 @@
+use aoc_parse::{each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The numbers in the report, all in binary with the same number of bits.
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        let lines = lines(input, |line| {
+            each_char(line, "'0' or '1'", |c| matches!(c, '0' | '1').then_some(c))?;
+            Ok(line)
+        })?;
+
+        let bits = lines.first().map_or(0, |line| line.len());
+        if bits == 0 {
+            return Err(ParseError::new("expected a binary number"));
+        }
+        if let Some(i) = lines.iter().position(|line| line.len() != bits) {
+            return Err(ParseError::new(format!(
+                "expected {} bits, like the first line, but there are {}",
+                bits,
+                lines[i].len()
+            ))
+            .at(i + 1, 1));
+        }
+
+        Ok(lines)
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Answer {
+        part_one(lines).into()
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Answer {
+        part_two(lines).into()
+    }
+}
+
+fn part_one(lines: &[&str]) -> usize {
+    let gamma = power_consumption(lines, false);
+    let epsilon = power_consumption(lines, true);
+    gamma * epsilon
+}
+
+fn part_two(lines: &[&str]) -> usize {
+    let oxygen = life_support_rating(lines, false);
+    let co2 = life_support_rating(lines, true);
+    oxygen * co2
+}
+
+fn power_consumption(lines: &[&str], least: bool) -> usize {
+    let cols: usize = lines.first().unwrap().len();
+    let mut n: usize = 0;
+
+    for col in 0..cols {
+        n *= 2;
+        if most_common(lines, "", col, least) == '1' {
+            n += 1
+        };
+    }
+
+    n
+}
+
+fn life_support_rating(lines: &[&str], least: bool) -> usize {
+    let cols: usize = lines.first().unwrap().len();
+    let mut n: usize = 0;
+    let mut prefix: String = String::from("");
+
+    for col in 0..cols {
+        n *= 2;
+        let c = most_common(lines, &prefix, col, least);
+        if c == '1' {
+            n += 1
+        };
+        prefix.push(c);
+    }
+
+    n
+}
+
+fn most_common(lines: &[&str], prefix: &str, col: usize, least: bool) -> char {
+    let counts: Vec<i64> = lines
+        .iter()
+        .filter(|line| line.starts_with(prefix))
+        .map(|line| match line.as_bytes()[col] {
+            b'1' => 1,
+            _ => -1,
+        })
+        .collect();
+
+    let len = counts.len();
+    let sum: i64 = counts.iter().sum();
+
+    if len == 1 {
+        if sum > 0 {
+            return '1';
+        } else {
+            return '0';
+        }
+    };
+
+    if (sum >= 0 && !least) || (sum < 0 && least) {
+        '1'
+    } else {
+        '0'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let lines = Puzzle::parse(EXAMPLE).unwrap();
+
+        assert_eq!(power_consumption(&lines, false), 22, "gamma rate");
+        assert_eq!(power_consumption(&lines, true), 9, "epsilon rate");
+
+        assert_eq!(life_support_rating(&lines, false), 23, "oxygen");
+        assert_eq!(life_support_rating(&lines, true), 10, "co2");
+    }
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("00100\n11110\n1011\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected 5 bits, like the first line, but there are 4"
+        );
+    }
+}
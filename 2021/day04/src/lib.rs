@@ -0,0 +1,151 @@
+use aoc_parse::{blocks, line, lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Bingo;
+
+    fn parse(input: &str) -> Result<Bingo, ParseError> {
+        let (nums, boards) = split_once(input, "\n\n")?;
+        let nums = line(nums, |s| s.split(',').map(number).collect())?;
+        let boards = blocks(boards, parse_board).map_err(|err| err.within(3, boards))?;
+        Ok(Bingo { nums, boards })
+    }
+
+    fn part_one(bingo: &Bingo) -> Answer {
+        solve(bingo).0.into()
+    }
+
+    fn part_two(bingo: &Bingo) -> Answer {
+        solve(bingo).1.into()
+    }
+}
+
+/// The numbers that are drawn, and the rows of each board.
+#[derive(Debug)]
+pub struct Bingo {
+    nums: Vec<u64>,
+    boards: Vec<Vec<Vec<u64>>>,
+}
+
+fn parse_board(block: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    let rows = lines(block, |line| {
+        line.split_whitespace()
+            .map(number)
+            .collect::<Result<Vec<u64>, _>>()
+    })?;
+
+    if rows.len() != 5 || rows.iter().any(|row| row.len() != 5) {
+        return Err(ParseError::new("expected a board of 5 rows of 5 numbers").at(1, 1));
+    }
+
+    Ok(rows)
+}
+
+fn solve(bingo: &Bingo) -> (u64, u64) {
+    let nums = &bingo.nums;
+
+    let mut first = (nums.len(), 0);
+    let mut last = (0, 0);
+    for rows in &bingo.boards {
+        let mut board = Board::new();
+        for row in rows {
+            board.add_row(row);
+        }
+
+        let (x, score) = board.score(nums);
+        if x < first.0 {
+            first = (x, score);
+        }
+        if x > last.0 {
+            last = (x, score);
+        }
+    }
+
+    (first.1, last.1)
+}
+
+struct Board {
+    rows: Vec<HashSet<u64>>,
+    cols: Vec<HashSet<u64>>,
+}
+
+impl Board {
+    fn new() -> Board {
+        Board {
+            rows: Vec::new(),
+            cols: Vec::new(),
+        }
+    }
+
+    fn add_row(&mut self, nums: &[u64]) {
+        nums.iter().enumerate().for_each(|(i, val)| {
+            if let Some(col) = self.cols.get_mut(i) {
+                col.insert(*val);
+            } else {
+                let mut col = HashSet::new();
+                col.insert(*val);
+                self.cols.insert(i, col);
+            }
+        });
+
+        let row: HashSet<u64> = nums.iter().copied().collect();
+        self.rows.push(row);
+    }
+
+    fn score(&mut self, nums: &[u64]) -> (usize, u64) {
+        for (i, v) in nums.iter().enumerate() {
+            // Remove the number from the rows and cols.
+            let mut done = false;
+            for row in &mut self.rows {
+                row.remove(v);
+                done = done || row.is_empty();
+            }
+            for col in &mut self.cols {
+                col.remove(v);
+                done = done || col.is_empty();
+            }
+
+            if !done {
+                continue;
+            }
+
+            let sum: u64 = self.rows.iter().flatten().sum();
+            return (i, sum * v);
+        }
+
+        panic!("Unreachable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let bingo = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&bingo), (4512, 1924), "Example");
+    }
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("1,2\n\n1 2 3 4 5\n6 7 8 9 10\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a board of 5 rows of 5 numbers"
+        );
+
+        let err = Puzzle::parse("1,2\n\n1 2 3 4 5\n6 7 x 9 10\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 5: expected a number, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_parse::combinators::{complete, int, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::str::FromStr;
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 5);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Segment>;
+
+    fn parse(input: &str) -> Result<Vec<Segment>, ParseError> {
+        lines(input, str::parse)
+    }
+
+    fn part_one(input: &Vec<Segment>) -> Answer {
+        intersection_count(input, false).into()
+    }
+
+    fn part_two(input: &Vec<Segment>) -> Answer {
+        intersection_count(input, true).into()
+    }
+}
+
+
+#[derive(PartialEq, Debug)]
+pub struct Segment {
+    x0: i16,
+    y0: i16,
+    x1: i16,
+    y1: i16,
+}
+
+impl FromStr for Segment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        complete(|s| {
+            let (x0, s) = int(s)?;
+            let (y0, s) = (",", int).read(s)?;
+            let (x1, s) = (" -> ", int).read(s)?;
+            let (y1, s) = (",", int).read(s)?;
+            Ok((Segment{x0, y0, x1, y1}, s))
+        })(s)
+    }
+}
+
+
+fn intersection_count(segments: &[Segment], diagonals: bool) -> usize {
+    let segments: Vec<&Segment> = segments
+        .iter()
+        .filter(|seg| diagonals || seg.x0 == seg.x1 || seg.y0 == seg.y1)
+        .collect();
+
+    let mut intersections: HashMap<(i16, i16), usize> = HashMap::new();
+    segments.iter().for_each(|segment| {
+        let dx = if segment.x0 == segment.x1 { 0 } else if segment.x0 > segment.x1 { -1 } else { 1 };
+        let dy = if segment.y0 == segment.y1 { 0 } else if segment.y0 > segment.y1 { -1 } else { 1 };
+
+        let mut x = segment.x0;
+        let mut y = segment.y0;
+
+        while x != segment.x1 || y != segment.y1 {
+                let count = intersections.entry((x,y)).or_insert(0);
+                *count += 1;
+                x += dx;
+                y += dy;
+        }
+
+        // Insert final point
+                let count = intersections.entry((x,y)).or_insert(0);
+                *count += 1;
+    });
+
+    intersections.values().filter(|&count| count > &1).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn parse_segment() {
+        let line = "12,25 -> 19,11".parse::<Segment>().unwrap();
+        let exp = Segment{x0: 12, y0: 25, x1: 19, y1: 11};
+        assert_eq!(line, exp, "failed to parse correctly");
+    }
+
+    #[test]
+    fn intersections() {
+        let segments = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(5, intersection_count(&segments, false), "Wrong answer for part one");
+        assert_eq!(12, intersection_count(&segments, true), "Wrong answer for part two");
+    }
+}
@@ -0,0 +1,69 @@
+use aoc_parse::{line, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// How many fish there are with each number of days left on their timer.
+    type Input<'a> = [u64; 9];
+
+    fn parse(input: &str) -> Result<[u64; 9], ParseError> {
+        line(input, |s| {
+            let mut counts = [0; 9];
+            for x in s.split(',') {
+                match number::<usize>(x)? {
+                    timer @ 0..=8 => counts[timer] += 1,
+                    _ => return Err(ParseError::expected("a timer from 0 to 8", x)),
+                }
+            }
+
+            Ok(counts)
+        })
+    }
+
+    fn part_one(counts: &[u64; 9]) -> Answer {
+        population(*counts, 80).into()
+    }
+
+    fn part_two(counts: &[u64; 9]) -> Answer {
+        population(*counts, 256).into()
+    }
+}
+
+fn population(mut counts: [u64; 9], days: usize) -> u64 {
+    for _ in 0..days {
+        counts = iterate(counts);
+    }
+    counts.iter().sum()
+}
+
+fn iterate(fish: [u64; 9]) -> [u64;9] {
+    [
+        fish[1],
+        fish[2],
+        fish[3],
+        fish[4],
+        fish[5],
+        fish[6],
+        fish[0] + fish[7],
+        fish[8],
+        fish[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterate_test() {
+        let state = [0,1,1,2,1,0,0,0,0,];
+        let state = iterate(state);
+        assert_eq!(state, [1,1,2,1,0,0,0,0,0], "First pass");
+
+        let state = iterate(state);
+        assert_eq!(state, [1, 2, 1, 0,0,0,1,0,1], "Second pass");
+    }
+}
@@ -0,0 +1,67 @@
+use aoc_parse::{line, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 7);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The crabs' positions, in order.
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        let mut crabs: Vec<u64> = line(input, |s| s.split(',').map(number).collect())?;
+        if crabs.is_empty() {
+            return Err(ParseError::new("expected at least one crab"));
+        }
+
+        crabs.sort();
+        Ok(crabs)
+    }
+
+    fn part_one(crabs: &Vec<u64>) -> Answer {
+        minimum_fuel_median(crabs).into()
+    }
+
+    fn part_two(crabs: &Vec<u64>) -> Answer {
+        minimum_fuel_mean(crabs).into()
+    }
+}
+
+fn minimum_fuel_median(crabs: &[u64]) -> u64 {
+    let median = if crabs.len().is_multiple_of(2) {
+        let a = *crabs.get((crabs.len()/2) - 1).unwrap();
+        let b = *crabs.get(crabs.len()/2 ).unwrap();
+        (a + b) / 2
+    } else {
+        *crabs.get((crabs.len() -1) / 2).unwrap()
+    };
+
+    crabs.iter()
+        .map(|x| if x > &median { x - median } else { median - x })
+        .sum()
+}
+
+fn minimum_fuel_mean(crabs: &[u64]) -> u64 {
+    let mean = crabs.iter().sum::<u64>() + (crabs.len() as u64 / 2);
+    let mean = mean / crabs.len() as u64;
+
+    crabs.iter()
+        .map(|x| if x > &mean { x - mean } else { mean - x })
+        .map(|x| x*(x+1)/2)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let crabs = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(minimum_fuel_median(&crabs), 37, "Part one");
+        assert_eq!(minimum_fuel_mean(&crabs), 168, "Part one");
+    }
+}
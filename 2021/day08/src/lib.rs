@@ -0,0 +1,161 @@
+use aoc_parse::{each_char, lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Entry<'a>>;
+
+    fn parse(input: &str) -> Result<Vec<Entry<'_>>, ParseError> {
+        lines(input, parse_entry)
+    }
+
+    fn part_one(entries: &Vec<Entry<'_>>) -> Answer {
+        unique_segments(entries).into()
+    }
+
+    fn part_two(entries: &Vec<Entry<'_>>) -> Answer {
+        sum(entries).into()
+    }
+}
+
+/// The ten patterns seen on a display, and the four digits it's showing.
+type Entry<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+/// An entry like `acedgfb cdfbe ... ab | cdfeb fcadb cdfeb cdbaf`.
+fn parse_entry(line: &str) -> Result<Entry<'_>, ParseError> {
+    let (patterns, output) = split_once(line, " | ")?;
+    let patterns = parse_patterns(patterns, 10)?;
+    let output = parse_patterns(output, 4)?;
+    Ok((patterns, output))
+}
+
+fn parse_patterns(s: &str, count: usize) -> Result<Vec<&str>, ParseError> {
+    let patterns = s
+        .split(' ')
+        .map(|pattern| {
+            each_char(pattern, "a segment from 'a' to 'g'", |c| {
+                ('a'..='g').contains(&c).then_some(c)
+            })?;
+            Ok(pattern)
+        })
+        .collect::<Result<Vec<&str>, ParseError>>()?;
+
+    if patterns.len() != count {
+        return Err(ParseError::expected(format!("{} patterns", count), s));
+    }
+
+    Ok(patterns)
+}
+
+fn unique_segments(entries: &[Entry<'_>]) -> usize {
+    entries
+        .iter()
+        .flat_map(|(_, output)| output)
+        .filter(|pattern| {
+            let l = pattern.len();
+            l == 2 || l == 3 || l == 4 || l == 7
+        })
+        .count()
+}
+
+fn sum(entries: &[Entry<'_>]) -> u64 {
+    entries
+        .iter()
+        .map(|(codes, output)| {
+            let values = decode(codes);
+            output.iter().fold(0, |acc, code| {
+                let code = sort_string(code);
+                10 * acc + values.get(&code).unwrap()
+            })
+        })
+        .sum()
+}
+
+fn decode(codes: &[&str]) -> HashMap<String, u64> {
+    let mut map: HashMap<String, u64> = HashMap::new();
+
+    // Work out the easy ones first, with unique lengths.
+    let mut one: &str = "";
+    let mut four: &str = "";
+    for code in codes {
+        match code.len() {
+            2 => {
+                one = code;
+                map.insert(sort_string(code), 1)
+            }
+            3 => map.insert(sort_string(code), 7),
+            4 => {
+                four = code;
+                map.insert(sort_string(code), 4)
+            }
+            7 => map.insert(sort_string(code), 8),
+            _ => None,
+        };
+    }
+
+    // Now we can eliminate the length 6:
+    // 6 is the only length 6 one that doesn't contain 1.
+    // 9 is the only length 9 one that contains 4.
+    // 0 is the other one.
+    let mut nine: &str = "";
+    for code in codes {
+        if code.len() != 6 {
+            continue;
+        }
+        if !contains(code, one) {
+            map.insert(sort_string(code), 6);
+        } else if contains(code, four) {
+            nine = code;
+            map.insert(sort_string(code), 9);
+        } else {
+            map.insert(sort_string(code), 0);
+        }
+    }
+
+    // Finally, we can eliminate the length 5 codes.
+    // 3 is the only one that contains 1.
+    // 5 is contained within 9 (as is 3), 2 isn't.
+    for code in codes {
+        if code.len() != 5 {
+            continue;
+        }
+
+        if contains(code, one) {
+            map.insert(sort_string(code), 3);
+        } else if contains(nine, code) {
+            map.insert(sort_string(code), 5);
+        } else {
+            map.insert(sort_string(code), 2);
+        }
+    }
+
+    map
+}
+
+fn contains(code: &str, sub: &str) -> bool {
+    sub.chars().find(|c| !code.contains(*c)).is_none()
+}
+
+fn sort_string(code: &str) -> String {
+    let mut chars: Vec<char> = code.chars().collect();
+    chars.sort();
+    String::from_iter(chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let entries = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(unique_segments(&entries), 26, "Part one");
+        assert_eq!(sum(&entries), 61229, "Part two");
+    }
+}
@@ -0,0 +1,160 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 9);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Grid::new(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        risk_level(grid).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        basin_sizes(grid).into()
+    }
+}
+
+pub struct Grid {
+    points: Vec<Vec<u64>>,
+    height: usize,
+    width: usize,
+}
+
+impl Grid {
+    fn new(input: &str) -> Result<Grid, ParseError> {
+        let heights = aoc_grid::Grid::try_parse(input, |c| {
+            c.to_digit(10)
+                .map(u64::from)
+                .ok_or_else(|| ParseError::expected("a height", &c.to_string()))
+        })?;
+
+        if heights.width() == 0 {
+            return Err(ParseError::new("expected a height map"));
+        }
+
+        Ok(Grid {
+            points: heights.rows().map(<[u64]>::to_vec).collect(),
+            height: heights.height(),
+            width: heights.width(),
+        })
+    }
+
+    fn get(&self, x: usize, y: usize) -> u64 {
+        *self.points.get(y).unwrap().get(x).unwrap()
+    }
+
+    fn neighbours(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let mut neighbours: Vec<(usize, usize)> = Vec::new();
+
+        if x > 0 {
+            neighbours.push((x - 1, y));
+        }
+        if y > 0 {
+            neighbours.push((x, y - 1));
+        }
+        if x < self.width - 1 {
+            neighbours.push((x + 1, y));
+        }
+        if y < self.height - 1 {
+            neighbours.push((x, y + 1));
+        }
+
+        neighbours
+    }
+}
+
+fn risk_level(grid: &Grid) -> u64 {
+    let mut risk_sum: u64 = 0;
+
+    for x in 0..grid.width {
+        'outer: for y in 0..grid.height {
+            let val = grid.get(x, y);
+
+            for n in grid.neighbours(x, y) {
+                if grid.get(n.0, n.1) <= val {
+                    continue 'outer;
+                }
+            }
+
+            risk_sum += 1 + val;
+        }
+    }
+
+    risk_sum
+}
+
+fn basin_sizes(grid: &Grid) -> usize {
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut sizes: Vec<usize> = Vec::new();
+
+    for x in 0..grid.width {
+        for y in 0..grid.height {
+            if grid.get(x, y) == 9 {
+                continue;
+            }
+
+            if visited.contains(&(x, y)) {
+                continue;
+            }
+
+            let locs = basin_locations(grid, x, y);
+            sizes.push(locs.len());
+            for (p, q) in locs {
+                visited.insert((p, q));
+            }
+        }
+    }
+
+    sizes.sort();
+    sizes.pop().unwrap() * sizes.pop().unwrap() * sizes.pop().unwrap()
+}
+
+fn basin_locations(grid: &Grid, x: usize, y: usize) -> HashSet<(usize, usize)> {
+    let mut locs: HashSet<(usize, usize)> = HashSet::new();
+    locs.insert((x, y));
+
+    let mut neighbours = grid.neighbours(x, y);
+    while !neighbours.is_empty() {
+        let mut new_neighbours: Vec<(usize, usize)> = Vec::new();
+
+        for (p, q) in neighbours {
+            if grid.get(p, q) == 9 {
+                continue;
+            }
+
+            if locs.contains(&(p, q)) {
+                continue;
+            }
+
+            locs.insert((p, q));
+            for n in grid.neighbours(p, q) {
+                new_neighbours.push(n);
+            }
+        }
+
+        neighbours = new_neighbours;
+    }
+
+    locs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let grid = Grid::new(EXAMPLE).unwrap();
+        assert_eq!(risk_level(&grid), 15, "Part one");
+        assert_eq!(basin_sizes(&grid), 1134, "Part two");
+    }
+}
@@ -0,0 +1,119 @@
+use aoc_parse::{each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 10);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<Bracket>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Bracket>>, ParseError> {
+        lines(input, |line| {
+            each_char(line, "a bracket", |c| match c {
+                '(' => Some((Chunk::Round, true)),
+                '[' => Some((Chunk::Square, true)),
+                '{' => Some((Chunk::Curly, true)),
+                '<' => Some((Chunk::Angle, true)),
+                ')' => Some((Chunk::Round, false)),
+                ']' => Some((Chunk::Square, false)),
+                '}' => Some((Chunk::Curly, false)),
+                '>' => Some((Chunk::Angle, false)),
+                _ => None,
+            })
+        })
+    }
+
+    fn part_one(lines: &Vec<Vec<Bracket>>) -> Answer {
+        solve(lines).0.into()
+    }
+
+    fn part_two(lines: &Vec<Vec<Bracket>>) -> Answer {
+        solve(lines).1.into()
+    }
+}
+
+fn solve(lines: &[Vec<Bracket>]) -> (u64, u64) {
+    let statuses: Vec<LineStatus> = lines.iter().map(|line| evaluate_line(line)).collect();
+
+    let corrupt: u64 = statuses
+        .iter()
+        .map(|s| match s {
+            LineStatus::Corrupt(x) => *x,
+            _ => 0,
+        })
+        .sum();
+
+    let mut incomplete: Vec<u64> = statuses
+        .iter()
+        .filter_map(|s| match s {
+            LineStatus::Incomplete(x) => Some(*x),
+            _ => None,
+        })
+        .collect();
+
+    incomplete.sort();
+    (corrupt, *incomplete.get((incomplete.len()) / 2).unwrap())
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Chunk {
+    Round,
+    Square,
+    Curly,
+    Angle,
+}
+
+/// A bracket, and whether it opens its chunk or closes it.
+type Bracket = (Chunk, bool);
+
+enum LineStatus {
+    Ok,
+    Corrupt(u64),
+    Incomplete(u64),
+}
+
+fn evaluate_line(line: &[Bracket]) -> LineStatus {
+    let mut previous: Vec<Chunk> = Vec::new();
+
+    for &(chunk, opens) in line {
+        if opens {
+            previous.push(chunk);
+        } else if previous.last() == Some(&chunk) {
+            previous.pop();
+        } else {
+            return LineStatus::Corrupt(match chunk {
+                Chunk::Round => 3,
+                Chunk::Square => 57,
+                Chunk::Curly => 1197,
+                Chunk::Angle => 25137,
+            });
+        }
+    }
+
+    if previous.is_empty() {
+        return LineStatus::Ok;
+    }
+
+    let score: u64 = previous.iter().rev().fold(0, |acc, status| match status {
+        Chunk::Round => 5 * acc + 1,
+        Chunk::Square => 5 * acc + 2,
+        Chunk::Curly => 5 * acc + 3,
+        Chunk::Angle => 5 * acc + 4,
+    });
+    LineStatus::Incomplete(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (a, b) = solve(&Puzzle::parse(EXAMPLE).unwrap());
+        assert_eq!(a, 26397, "Part one");
+        assert_eq!(b, 288957, "Part two");
+    }
+}
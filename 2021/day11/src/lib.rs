@@ -0,0 +1,156 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 11);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Grid::new(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        part_one(grid.clone()).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        part_two(grid.clone()).into()
+    }
+}
+
+fn part_one(mut grid: Grid) -> usize {
+    (0..100).map(|_| grid.step()).sum()
+}
+
+fn part_two(mut grid: Grid) -> usize {
+    let mut count = 1;
+    while grid.step() != grid.height * grid.width {
+        count += 1;
+    }
+    count
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    octopi: Vec<Vec<u8>>,
+    height: usize,
+    width: usize,
+}
+
+impl Grid {
+    fn new(input: &str) -> Result<Grid, ParseError> {
+        let energy = aoc_grid::Grid::try_parse(input, |c| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| ParseError::expected("an energy level", &c.to_string()))
+        })?;
+
+        if energy.width() == 0 {
+            return Err(ParseError::new("expected a grid of octopi"));
+        }
+
+        Ok(Grid {
+            octopi: energy.rows().map(<[u8]>::to_vec).collect(),
+            height: energy.height(),
+            width: energy.width(),
+        })
+    }
+
+    fn get(&self, x: usize, y: usize) -> u8 {
+        *self.octopi.get(y).unwrap().get(x).unwrap()
+    }
+
+    fn inc(&mut self, x: usize, y: usize) {
+        let octopus = self.octopi.get_mut(y).unwrap().get_mut(x).unwrap();
+        *octopus += 1;
+    }
+
+    fn reset(&mut self) {
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let octopus = self.octopi.get_mut(y).unwrap().get_mut(x).unwrap();
+                if *octopus > 9 {
+                    *octopus = 0;
+                }
+            }
+        }
+    }
+
+    fn step(&mut self) -> usize {
+        let mut flashed: HashSet<(usize, usize)> = HashSet::new();
+        let mut flashes: usize = 0;
+
+        // first, increment all octopi by one
+        for x in 0..self.width {
+            for y in 0..self.height {
+                self.inc(x, y);
+            }
+        }
+
+        let mut has_flashed = true;
+        while has_flashed {
+            has_flashed = false;
+
+            for x in 0..self.width {
+                for y in 0..self.height {
+                    if self.get(x, y) <= 9 || flashed.contains(&(x, y)) {
+                        continue;
+                    }
+
+                    has_flashed = true;
+                    flashed.insert((x, y));
+                    flashes += 1;
+
+                    let min_x = if x > 0 { x - 1 } else { x };
+                    let max_x = if x < self.width - 1 { x + 1 } else { x };
+                    let min_y = if y > 0 { y - 1 } else { y };
+                    let max_y = if y < self.height - 1 { y + 1 } else { y };
+
+                    for p in min_x..=max_x {
+                        for q in min_y..=max_y {
+                            self.inc(p, q);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.reset();
+        flashes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn flash_count() {
+        let mut grid = Grid::new(EXAMPLE).unwrap();
+        let mut flashes = 0;
+        for _ in 0..10 {
+            flashes += grid.step();
+        }
+        assert_eq!(flashes, 204, "After 10 steps");
+
+        for _ in 10..100 {
+            flashes += grid.step();
+        }
+        assert_eq!(flashes, 1656, "After 100 steps");
+    }
+
+    #[test]
+    fn synchro_flash() {
+        let mut grid = Grid::new(EXAMPLE).unwrap();
+        let mut count = 1;
+        while grid.step() != grid.height * grid.width {
+            count += 1;
+        }
+        assert_eq!(count, 195, "Synchronised flash");
+    }
+}
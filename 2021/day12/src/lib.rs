@@ -0,0 +1,125 @@
+use aoc_parse::{lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 12);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The pairs of caves that are joined.
+    type Input<'a> = Vec<(&'a str, &'a str)>;
+
+    fn parse(input: &str) -> Result<Vec<(&str, &str)>, ParseError> {
+        lines(input, |line| split_once(line, "-"))
+    }
+
+    fn part_one(pairs: &Vec<(&str, &str)>) -> Answer {
+        unique_paths(pairs, false).into()
+    }
+
+    fn part_two(pairs: &Vec<(&str, &str)>) -> Answer {
+        unique_paths(pairs, true).into()
+    }
+}
+
+fn unique_paths(pairs: &[(&str, &str)], second_visit: bool) -> usize {
+    let mut paths: HashMap<&str, Vec<&str>> = HashMap::new();
+    for &(a, b) in pairs {
+        if let Some(list) = paths.get_mut(a) {
+            list.push(b);
+        } else {
+            let list = vec![b];
+            paths.insert(a, list);
+        }
+
+        if let Some(list) = paths.get_mut(b) {
+            list.push(a);
+        } else {
+            let list = vec![a];
+            paths.insert(b, list);
+        }
+    }
+
+    let mut visited: HashSet<&str> = HashSet::new();
+    visited.insert("start");
+
+    unique_paths_rec(&paths, "start", &visited, second_visit, false, "start")
+}
+
+fn unique_paths_rec(
+    paths: &HashMap<&str, Vec<&str>>,
+    current: &str,
+    visited: &HashSet<&str>,
+    second_visit: bool,
+    second_visit_taken: bool,
+    journey: &str,
+) -> usize {
+    if current == "end" {
+        return 1;
+    }
+
+    let mut sum = 0;
+    for &dest in paths.get(current).unwrap_or(&vec![]) {
+        if dest == "start" {
+            continue;
+        }
+
+        if dest.to_lowercase() != dest {
+            let mut journey = journey.to_owned();
+            journey.push(',');
+            journey.push_str(dest);
+            sum += unique_paths_rec(
+                paths,
+                dest,
+                visited,
+                second_visit,
+                second_visit_taken,
+                &journey,
+            );
+            continue;
+        }
+
+        if visited.contains(dest) && (!second_visit || second_visit_taken) {
+            continue;
+        }
+        let taken = second_visit_taken || visited.contains(dest);
+
+        let mut visited = visited.clone();
+        visited.insert(dest);
+
+        let mut journey = journey.to_owned();
+        journey.push(',');
+        journey.push_str(dest);
+        sum += unique_paths_rec(paths, dest, &visited, second_visit, taken, &journey);
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../example1.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+
+    fn parse(input: &str) -> Vec<(&str, &str)> {
+        Puzzle::parse(input).unwrap()
+    }
+
+    #[test]
+    fn single_visit() {
+        assert_eq!(unique_paths(&parse(EXAMPLE1), false), 10, "example 1");
+        assert_eq!(unique_paths(&parse(EXAMPLE2), false), 19, "example 2");
+        assert_eq!(unique_paths(&parse(EXAMPLE3), false), 226, "example 3");
+    }
+
+    #[test]
+    fn two_visits() {
+        assert_eq!(unique_paths(&parse(EXAMPLE1), true), 36, "example 1");
+        assert_eq!(unique_paths(&parse(EXAMPLE2), true), 103, "example 2");
+        assert_eq!(unique_paths(&parse(EXAMPLE3), true), 3509, "example 3");
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_parse::{lines, number, split_once, strip_prefix};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 13);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Manual<'a>;
+
+    fn parse(input: &str) -> Result<Manual<'_>, ParseError> {
+        let (points, folds) = split_once(input, "\n\n")?;
+        let first_fold = points.lines().count() + 2;
+
+        let points = lines(points, |line| {
+            let (x, y) = split_once(line, ",")?;
+            Ok((number(x)?, number(y)?))
+        })?;
+        let folds = lines(folds, parse_fold).map_err(|err| err.within(first_fold, folds))?;
+
+        Ok(Manual {
+            points: points.into_iter().collect(),
+            folds,
+        })
+    }
+
+    fn part_one(manual: &Manual<'_>) -> Answer {
+        folds(manual, 1).len().into()
+    }
+
+    fn part_two(manual: &Manual<'_>) -> Answer {
+        let points = folds(manual, usize::MAX);
+        aoc_ocr::read_points(points)
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into()
+    }
+}
+
+/// The dots on the transparent paper, and the folds to make in it.
+#[derive(Debug)]
+pub struct Manual<'a> {
+    points: HashSet<(i64, i64)>,
+    folds: Vec<(&'a str, i64)>,
+}
+
+/// A fold like `fold along y=7`.
+fn parse_fold(line: &str) -> Result<(&str, i64), ParseError> {
+    let (dir, n) = split_once(strip_prefix(line, "fold along ")?, "=")?;
+    if dir != "x" && dir != "y" {
+        return Err(ParseError::expected("x or y", dir));
+    }
+
+    Ok((dir, number(n)?))
+}
+
+/// The points left after making the first `count` folds.
+fn folds(manual: &Manual<'_>, count: usize) -> HashSet<(i64, i64)> {
+    let mut points = manual.points.clone();
+
+    for &(dir, n) in manual.folds.iter().take(count) {
+        let mut folded_points: HashSet<(i64, i64)> = HashSet::new();
+        for (x, y) in &points {
+            folded_points.insert(transform_point(*x, *y, dir, n));
+        }
+        points = folded_points;
+    }
+
+    points
+}
+
+fn transform_point(x: i64, y: i64, dir: &str, n: i64) -> (i64, i64) {
+    match dir {
+        "x" => {
+            if x < n {
+                (x, y)
+            } else {
+                (2 * n - x, y)
+            }
+        }
+        "y" => {
+            if y < n {
+                (x, y)
+            } else {
+                (x, 2 * n - y)
+            }
+        }
+        _ => unreachable!("folds are along x or y"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn folds_test() {
+        let manual = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(folds(&manual, 1).len(), 17);
+    }
+
+    #[test]
+    fn parse_test() {
+        let err = Puzzle::parse("6,10\n0,14\n\nfold along y=7\nfold along z=5\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 12: expected x or y, found \"z\""
+        );
+    }
+}
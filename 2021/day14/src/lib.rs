@@ -0,0 +1,121 @@
+use aoc_parse::{lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 14);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Polymer;
+
+    fn parse(input: &str) -> Result<Polymer, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(polymer: &Polymer) -> Answer {
+        count_range(polymer, 10).into()
+    }
+
+    fn part_two(polymer: &Polymer) -> Answer {
+        count_range(polymer, 40).into()
+    }
+}
+
+type Pair = (char, char);
+
+/// The count of each pair in the template, the pairs each pair becomes, and
+/// the template's final character.
+type Polymer = (HashMap<Pair, usize>, HashMap<Pair, Vec<Pair>>, char);
+
+fn count_range((counts, map, final_char): &Polymer, iterations: usize) -> usize {
+    let mut counts = counts.clone();
+
+    // Calculate how many of each pair we have in the final outcome.
+    for _ in 0..iterations {
+        let mut next_counts: HashMap<Pair, usize> = HashMap::new();
+        for (pair, count) in &counts {
+            for p in map.get(pair).unwrap() {
+                if let Some(c) = next_counts.get_mut(p) {
+                    *c += count;
+                } else {
+                    next_counts.insert(*p, *count);
+                }
+            }
+        }
+
+        counts = next_counts;
+    }
+
+    // Count the number of characters we have.
+    let mut char_counts: HashMap<char, usize> = HashMap::new();
+    char_counts.insert(*final_char, 1);
+    for (pair, count) in &counts {
+        if let Some(c) = char_counts.get_mut(&pair.0) {
+            *c += count;
+        } else {
+            char_counts.insert(pair.0, *count);
+        }
+    }
+
+    let mut most = 0;
+    let mut least = usize::MAX;
+    for (_, count) in char_counts {
+        if count > most {
+            most = count;
+        }
+        if count < least {
+            least = count;
+        }
+    }
+
+    most - least
+}
+
+fn parse_input(input: &str) -> Result<Polymer, ParseError> {
+    let (template, rules) = split_once(input, "\n\n")?;
+
+    let chars: Vec<char> = template.chars().collect();
+    if chars.is_empty() {
+        return Err(ParseError::new("the polymer template is empty").at(1, 1));
+    }
+    let mut pairs_count: HashMap<Pair, usize> = HashMap::new();
+    for i in 0..chars.len() - 1 {
+        let pair = (*chars.get(i).unwrap(), *chars.get(i + 1).unwrap());
+        if let Some(x) = pairs_count.get_mut(&pair) {
+            *x += 1;
+        } else {
+            pairs_count.insert(pair, 1);
+        }
+    }
+    let final_char = *chars.last().unwrap();
+
+    let rules = lines(rules, |line| {
+        let (pair, insert) = split_once(line, " -> ")?;
+        let pair_chars: Vec<char> = pair.chars().collect();
+        let insert_chars: Vec<char> = insert.chars().collect();
+        let (a, b, insert) = match (&pair_chars[..], &insert_chars[..]) {
+            (&[a, b], &[insert]) => (a, b, insert),
+            (&[_, _], _) => return Err(ParseError::expected("one element", insert)),
+            _ => return Err(ParseError::expected("a pair of elements", pair)),
+        };
+        Ok(((a, b), vec![(a, insert), (insert, b)]))
+    })
+    .map_err(|err| err.within(3, rules))?;
+
+    Ok((pairs_count, rules.into_iter().collect(), final_char))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let polymer = parse_input(EXAMPLE).unwrap();
+        assert_eq!(count_range(&polymer, 10), 1588, "10 iterations");
+        assert_eq!(count_range(&polymer, 40), 2188189693529, "40 iterations");
+    }
+}
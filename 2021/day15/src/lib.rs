@@ -0,0 +1,65 @@
+use aoc_grid::{Grid, Pos};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use aoc_search::dijkstra;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 15);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(cavern: &Grid<usize>) -> Answer {
+        minimal_path(cavern).into()
+    }
+
+    fn part_two(cavern: &Grid<usize>) -> Answer {
+        minimal_path(&expand_cavern(cavern)).into()
+    }
+}
+
+fn minimal_path(cavern: &Grid<usize>) -> usize {
+    let end = (cavern.width() - 1, cavern.height() - 1);
+    let successors = |&pos: &Pos| cavern.neighbours(pos).map(|n| (n, cavern[n]));
+
+    dijkstra((0, 0), successors, |&pos| pos == end)
+        .goal_cost()
+        .expect("no way through the cavern")
+}
+
+fn parse_input(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::try_parse(input, |c| {
+        c.to_digit(10)
+            .map(|risk| risk as usize)
+            .ok_or_else(|| ParseError::expected("a risk level", &c.to_string()))
+    })
+}
+
+fn expand_cavern(original: &Grid<usize>) -> Grid<usize> {
+    let (width, height) = (original.width(), original.height());
+
+    Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        let v = original[(x % width, y % height)];
+        (v + x / width + y / height - 1) % 9 + 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let cavern = parse_input(EXAMPLE).unwrap();
+        assert_eq!(minimal_path(&cavern), 40, "Part one");
+
+        let expanded_cavern = expand_cavern(&cavern);
+        assert_eq!(minimal_path(&expanded_cavern), 315, "Part two");
+    }
+}
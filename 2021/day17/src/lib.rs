@@ -0,0 +1,253 @@
+use aoc_parse::combinators::{complete, field, int, Parsed, Parser};
+use aoc_parse::line;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 17);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Target;
+
+    fn parse(input: &str) -> Result<Target, ParseError> {
+        line(input, complete(Target::parse))
+    }
+
+    fn part_one(target: &Target) -> Answer {
+        part_one(target).into()
+    }
+
+    fn part_two(target: &Target) -> Answer {
+        part_two(target).into()
+    }
+}
+
+/// The highest a probe can go and still end up in the target.
+fn part_one(target: &Target) -> i64 {
+    hits(target)
+        .iter()
+        .map(|&(_, vy)| apex(vy))
+        .max()
+        .unwrap_or(0)
+}
+
+fn part_two(target: &Target) -> usize {
+    hits(target).len()
+}
+
+/// Every starting velocity that has the probe in the target after some step:
+/// those whose steps level with the target fall in the window of steps it's
+/// over the target.
+fn hits(target: &Target) -> Vec<(i64, i64)> {
+    let windows: Vec<(i64, (i64, Option<i64>))> = target
+        .x_velocities()
+        .filter_map(|vx| Some((vx, x_window(vx, &target.x)?)))
+        .collect();
+
+    let mut hits = Vec::new();
+    for vy in target.y_velocities() {
+        let steps = y_steps(vy, &target.y);
+        for &(vx, (first, last)) in &windows {
+            if steps
+                .iter()
+                .any(|&step| step >= first && last.is_none_or(|last| step <= last))
+            {
+                hits.push((vx, vy));
+            }
+        }
+    }
+
+    hits
+}
+
+/// How high a probe thrown with y velocity `vy` goes.
+fn apex(vy: i64) -> i64 {
+    if vy > 0 {
+        vy * (vy + 1) / 2
+    } else {
+        0
+    }
+}
+
+/// The first and last steps after which a probe thrown with x velocity `vx`
+/// is over the target, with no last step if it stops over it.
+fn x_window(vx: i64, range: &RangeInclusive<i64>) -> Option<(i64, Option<i64>)> {
+    let mut window: Option<(i64, Option<i64>)> = None;
+    let (mut x, mut v) = (0, vx);
+
+    for step in 1.. {
+        x += v;
+        v -= v.signum();
+        if range.contains(&x) {
+            window = Some((window.map_or(step, |(first, _)| first), Some(step)));
+        }
+
+        if v == 0 {
+            break;
+        }
+    }
+
+    match window {
+        Some((first, _)) if range.contains(&x) => Some((first, None)),
+        window => window,
+    }
+}
+
+/// The steps after which a probe thrown with y velocity `vy` is level with
+/// the target.
+fn y_steps(vy: i64, range: &RangeInclusive<i64>) -> Vec<i64> {
+    let mut steps = Vec::new();
+    let (mut y, mut v) = (0, vy);
+
+    for step in 1.. {
+        y += v;
+        v -= 1;
+        if range.contains(&y) {
+            steps.push(step);
+        }
+
+        // Falling, and already below it
+        if v < 0 && y < *range.start() {
+            break;
+        }
+    }
+
+    steps
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Target {
+    x: RangeInclusive<i64>,
+    y: RangeInclusive<i64>,
+}
+
+impl Target {
+    /// Parses `target area: x=20..30, y=-10..-5`. The target can be either
+    /// side of the start, but not level with it, or every shot thrown
+    /// straight up would come back down into it.
+    fn parse(s: &str) -> Parsed<'_, Target> {
+        let (x, s) = ("target area: ", field("x", range)).read(s)?;
+        let (y, rest) = (", ", field("y", range)).read(s)?;
+        if y.contains(&0) {
+            return Err(ParseError::expected(
+                "a target above or below the start",
+                &s[2..s.len() - rest.len()],
+            ));
+        }
+
+        Ok((Target { x, y }, rest))
+    }
+
+    /// Anything faster than this is past the far side of the target after
+    /// the first step.
+    fn x_velocities(&self) -> RangeInclusive<i64> {
+        (*self.x.start()).min(0)..=(*self.x.end()).max(0)
+    }
+
+    /// Below the start, a probe thrown up at `vy` comes back down through
+    /// the start at `vy + 1`, so anything faster than the bottom of the
+    /// target is straight past it. Above, a probe is at the same heights on
+    /// the way down as on the way up, so it mustn't be past the target after
+    /// the first step, and it has to go at least as high as the bottom.
+    fn y_velocities(&self) -> RangeInclusive<i64> {
+        let (bottom, top) = (*self.y.start(), *self.y.end());
+        if top < 0 {
+            bottom..=-bottom - 1
+        } else {
+            (0..).find(|&vy| apex(vy) >= bottom).unwrap()..=top
+        }
+    }
+}
+
+/// A range like `-10..-5`, lowest first.
+fn range(s: &str) -> Parsed<'_, RangeInclusive<i64>> {
+    let (start, rest) = int(s)?;
+    let (end, rest) = ("..", int).read(rest)?;
+    if start > end {
+        return Err(ParseError::expected(
+            "the lower end first",
+            &s[..s.len() - rest.len()],
+        ));
+    }
+
+    Ok((start..=end, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn parse(input: &str) -> Target {
+        Puzzle::parse(input).unwrap()
+    }
+
+    /// Tries every velocity in a generous box, step by step.
+    fn simulate(target: &Target) -> Vec<(i64, i64)> {
+        let mut hits = Vec::new();
+        for vx in -60..=60_i64 {
+            for vy in -60..=60 {
+                let (mut x, mut y, mut dx, mut dy) = (0, 0, vx, vy);
+                for _ in 0..200 {
+                    x += dx;
+                    y += dy;
+                    dx -= dx.signum();
+                    dy -= 1;
+                    if target.x.contains(&x) && target.y.contains(&y) {
+                        hits.push((vx, vy));
+                        break;
+                    }
+                }
+            }
+        }
+
+        hits
+    }
+
+    #[test]
+    fn example() {
+        let target = parse(EXAMPLE);
+        assert_eq!(part_one(&target), 45);
+        assert_eq!(part_two(&target), 112);
+    }
+
+    #[test]
+    fn sides_test() {
+        for input in [
+            "target area: x=-30..-20, y=-10..-5",
+            "target area: x=20..30, y=5..10",
+            "target area: x=-30..-20, y=12..15",
+            "target area: x=-5..5, y=-10..-5",
+        ] {
+            let target = parse(input);
+            let mut hits = hits(&target);
+            hits.sort();
+            assert_eq!(hits, simulate(&target), "{}", input);
+        }
+
+        let mirrored = parse("target area: x=-30..-20, y=-10..-5");
+        assert_eq!(part_one(&mirrored), 45);
+        assert_eq!(part_two(&mirrored), 112);
+    }
+
+    #[test]
+    fn parse_test() {
+        let target = parse(EXAMPLE);
+        assert_eq!(target.x, 20..=30);
+        assert_eq!(target.y, -10..=-5);
+
+        let err = Puzzle::parse("target area: x=20..30, y=-5..5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 24: expected a target above or below the start, found \"y=-5..5\""
+        );
+
+        let err = Puzzle::parse("target area: x=30..20, y=-10..-5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 16: expected the lower end first, found \"30..20\""
+        );
+    }
+}
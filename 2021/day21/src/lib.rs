@@ -0,0 +1,138 @@
+use aoc_parse::combinators::{complete, int, labelled, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2021, 21);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (u64, u64);
+
+    fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&(x, y): &(u64, u64)) -> Answer {
+        deterministic(x, y).into()
+    }
+
+    fn part_two(&(x, y): &(u64, u64)) -> Answer {
+        dirac(x, y).into()
+    }
+}
+
+fn deterministic(x: u64, y: u64) -> u64 {
+    let mut scores = (0, 0);
+    let mut pos = (x - 1, y - 1); // 0-indexed to make the arithmetic easier.
+
+    let mut die = 0;
+    let mut rolls = 0;
+
+    let mut player = 0;
+
+    loop {
+        let mut roll = 0;
+        for _ in 0..3 {
+            die = 1 + (die % 100);
+            rolls += 1;
+            roll += die;
+        }
+
+        if player == 0 {
+            pos.0 = (pos.0 + roll) % 10;
+            scores.0 += 1 + pos.0;
+            if scores.0 >= 1000 {
+                return scores.1 * rolls;
+            }
+        } else {
+            pos.1 = (pos.1 + roll) % 10;
+            scores.1 += 1 + pos.1;
+            if scores.1 >= 1000 {
+                return scores.0 * rolls;
+            }
+        }
+
+        player = (player + 1) % 2;
+    }
+}
+
+/// How many of the 27 ways three rolls of the Dirac die can go give each
+/// total.
+const ROLLS: [(u64, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+/// The positions and scores of both players, and whose turn it is.
+type State = ([u64; 2], [u64; 2], usize);
+
+/// The number of universes that the player who wins most often wins in.
+fn dirac(x: u64, y: u64) -> u64 {
+    let [a, b] = wins(([x - 1, y - 1], [0, 0], 0), &mut HashMap::new());
+    a.max(b)
+}
+
+/// How many universes each player wins in from `state`. The same state
+/// turns up in a great many universes, so the counts are remembered in
+/// `seen`.
+fn wins(state: State, seen: &mut HashMap<State, [u64; 2]>) -> [u64; 2] {
+    if let Some(&wins) = seen.get(&state) {
+        return wins;
+    }
+
+    let (positions, scores, player) = state;
+    let mut total = [0, 0];
+    for &(roll, universes) in &ROLLS {
+        let (mut positions, mut scores) = (positions, scores);
+        positions[player] = (positions[player] + roll) % 10;
+        scores[player] += positions[player] + 1;
+
+        if scores[player] >= 21 {
+            total[player] += universes;
+        } else {
+            let [a, b] = wins((positions, scores, 1 - player), seen);
+            total[0] += universes * a;
+            total[1] += universes * b;
+        }
+    }
+
+    seen.insert(state, total);
+    total
+}
+
+fn parse_input(input: &str) -> Result<(u64, u64), ParseError> {
+    match lines(input, complete(start))?[..] {
+        [(1, x), (2, y)] => Ok((x, y)),
+        _ => Err(ParseError::new("expected players 1 and 2, in that order")),
+    }
+}
+
+/// Parses a line like `Player 1 starting position: 4`.
+fn start(s: &str) -> Parsed<'_, (u8, u64)> {
+    let (player, s) = ("Player ", int).read(s)?;
+    let (position, rest) = labelled(" starting position", int).read(s)?;
+    if !(1..=10).contains(&position) {
+        return Err(ParseError::expected("a position from 1 to 10", s));
+    }
+
+    Ok(((player, position), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(parse_input(EXAMPLE), Ok((4, 8)));
+        assert_eq!(deterministic(4, 8), 739785, "Deterministic");
+        assert_eq!(dirac(4, 8), 444356092776315, "Dirac");
+    }
+
+    #[test]
+    fn wins_test() {
+        let wins = wins(([3, 7], [0, 0], 0), &mut HashMap::new());
+        assert_eq!(wins, [444356092776315, 341960390180808]);
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_parse::{blocks, lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The calories carried by each elf.
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        blocks(input, |elf| Ok(lines(elf, number::<u64>)?.iter().sum()))
+    }
+
+    fn part_one(elves: &Vec<u64>) -> Answer {
+        top_three(elves)[0].into()
+    }
+
+    fn part_two(elves: &Vec<u64>) -> Answer {
+        top_three(elves).iter().sum::<u64>().into()
+    }
+}
+
+/// The calories carried by the three elves carrying the most, most first.
+fn top_three(elves: &[u64]) -> [u64; 3] {
+    let (mut a, mut b, mut c) = (0, 0, 0);
+    for &latest in elves {
+        if latest > c {
+            c = latest;
+        }
+        if latest > b {
+            c = b;
+            b = latest;
+        }
+        if latest > a {
+            b = a;
+            a = latest;
+        }
+    }
+
+    [a, b, c]
+}
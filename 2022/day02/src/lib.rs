@@ -0,0 +1,69 @@
+use aoc_parse::{lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The rounds of the strategy guide, like `A Y`.
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        lines(input, parse_round)
+    }
+
+    fn part_one(rounds: &Vec<&str>) -> Answer {
+        part_one(rounds).into()
+    }
+
+    fn part_two(rounds: &Vec<&str>) -> Answer {
+        part_two(rounds).into()
+    }
+}
+
+fn parse_round(line: &str) -> Result<&str, ParseError> {
+    let (theirs, ours) = split_once(line, " ")?;
+    if !matches!(theirs, "A" | "B" | "C") {
+        return Err(ParseError::expected("A, B or C", theirs));
+    }
+    if !matches!(ours, "X" | "Y" | "Z") {
+        return Err(ParseError::expected("X, Y or Z", ours));
+    }
+
+    Ok(line)
+}
+
+fn part_one(rounds: &[&str]) -> u64 {
+    rounds.iter().fold(0, |acc, &line| {
+        acc + match line {
+            "A X" => 3 + 1, // Rock - Rock (Draw)
+            "A Y" => 6 + 2, // Rock - Paper (Win)
+            "A Z" => 3,     // Rock - Scissors (Loss)
+            "B X" => 1,     // Paper - Rock (Loss)
+            "B Y" => 3 + 2, // Paper - Paper (Draw)
+            "B Z" => 6 + 3, // Paper - Scissors (Win)
+            "C X" => 6 + 1, // Scissors - Rock (Win)
+            "C Y" => 2,     // Scissors - Paper (Loss)
+            "C Z" => 3 + 3, // Scissors - Scissors (Draw)
+            _ => unreachable!("the rounds are checked when they're parsed"),
+        }
+    })
+}
+
+fn part_two(rounds: &[&str]) -> u64 {
+    rounds.iter().fold(0, |acc, &line| {
+        acc + match line {
+            "A X" => 3,     // Rock - Scissors (Loss)
+            "A Y" => 3 + 1, // Rock - Rock (Draw)
+            "A Z" => 6 + 2, // Rock - Paper (Win)
+            "B X" => 1,     // Paper - Rock (Loss)
+            "B Y" => 3 + 2, // Paper - Paper (Draw)
+            "B Z" => 6 + 3, // Paper - Scissors (Win)
+            "C X" => 2,     // Scissors - Paper (Loss)
+            "C Y" => 3 + 3, // Scissors - Scissors (Draw)
+            "C Z" => 6 + 1, // Scissors - Rock (Win)
+            _ => unreachable!("the rounds are checked when they're parsed"),
+        }
+    })
+}
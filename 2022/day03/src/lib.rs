@@ -0,0 +1,75 @@
+use aoc_parse::{each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The items in each rucksack, which are letters.
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        lines(input, |line| {
+            each_char(line, "an item", |c| c.is_ascii_alphabetic().then_some(c))?;
+            if !line.len().is_multiple_of(2) {
+                return Err(ParseError::new(format!(
+                    "expected an even number of items, but there are {}",
+                    line.len()
+                )));
+            }
+
+            Ok(line)
+        })
+    }
+
+    fn part_one(rucksacks: &Vec<&str>) -> Answer {
+        part_one(rucksacks).into()
+    }
+
+    fn part_two(rucksacks: &Vec<&str>) -> Answer {
+        part_two(rucksacks).into()
+    }
+}
+
+fn part_one(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|line| {
+            let n = line.len() / 2;
+            priority(intersection(vec![&line[..n], &line[n..]]))
+        })
+        .sum()
+}
+
+fn part_two(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .chunks_exact(3)
+        .map(|chunk| priority(intersection(chunk.to_vec())))
+        .sum()
+}
+
+fn intersection(lines: Vec<&str>) -> char {
+    *lines
+        .into_iter()
+        .map(|line| line.chars().collect::<HashSet<char>>())
+        .reduce(|acc, set| acc.intersection(&set).copied().collect())
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap()
+}
+
+fn priority(c: char) -> u32 {
+    let c = c as u32;
+    match c {
+        // Lower case
+        97..=122 => c - 96,
+
+        // Upper case
+        65..=90 => c - 38,
+
+        _ => panic!("unknown character code {}", c),
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::str::FromStr;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+        lines(input, str::parse)
+    }
+
+    fn part_one(input: &Vec<Pair>) -> Answer {
+        count(input, Range::fully_contains).into()
+    }
+
+    fn part_two(input: &Vec<Pair>) -> Answer {
+        count(input, Range::overlaps).into()
+    }
+}
+
+/// Counts the pairs where either range is related to the other by `f`.
+fn count(pairs: &[Pair], f: fn(&Range, &Range) -> bool) -> usize {
+    pairs
+        .iter()
+        .filter(|pair| f(&pair.0, &pair.1) || f(&pair.1, &pair.0))
+        .count()
+}
+
+pub struct Pair(Range, Range);
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = split_once(s, ",")?;
+        let x = x.parse::<Range>()?;
+        let y = y.parse::<Range>()?;
+        Ok(Pair(x, y))
+    }
+}
+
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn fully_contains(&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        (self.start >= other.start && self.start <= other.end)
+            || (self.end >= other.start && self.end <= other.end)
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = split_once(s, "-")?;
+        let x = number(x)?;
+        let y = number(y)?;
+        Ok(Range { start: x, end: y })
+    }
+}
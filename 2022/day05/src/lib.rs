@@ -0,0 +1,160 @@
+use aoc_parse::{lines, number, split_once, strip_prefix};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 5);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Crates, Vec<Move>);
+
+    fn parse(input: &str) -> Result<(Crates, Vec<Move>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((crates, moves): &(Crates, Vec<Move>)) -> Answer {
+        solve(crates, moves, false).into()
+    }
+
+    fn part_two((crates, moves): &(Crates, Vec<Move>)) -> Answer {
+        solve(crates, moves, true).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Crates, Vec<Move>), ParseError> {
+    let (crates, moves) = split_once(input, "\n\n")?;
+
+    // The moves start after the crates and the blank line
+    let first_move = crates.lines().count() + 2;
+    let moves = lines(moves, str::parse).map_err(|err| err.within(first_move, moves))?;
+
+    Ok((crates.parse()?, moves))
+}
+
+fn solve(crates: &Crates, moves: &[Move], batch: bool) -> String {
+    let mut crates = crates.clone();
+    moves.iter().for_each(|m| crates.move_crates(m, batch));
+
+    crates.top()
+}
+
+#[derive(Clone)]
+pub struct Crates {
+    stacks: HashMap<usize, Vec<char>>,
+}
+
+impl FromStr for Crates {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut assignment: Vec<&str> = s
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with("move"))
+            .collect();
+
+        // Map of position to stack number
+        let numbers = assignment
+            .pop()
+            .ok_or_else(|| ParseError::new("expected the stacks of crates"))?;
+        let columns: HashMap<usize, usize> = numbers
+            .chars()
+            .enumerate()
+            .filter_map(|(i, c)| c.to_digit(10).map(|n| (i, n as usize)))
+            .collect();
+
+        let mut stacks: HashMap<usize, Vec<char>> = HashMap::new();
+        for (y, line) in assignment.iter().enumerate().rev() {
+            for (i, c) in line
+                .chars()
+                .enumerate()
+                .filter(|(_, c)| *c != '[' && *c != ']' && *c != ' ')
+            {
+                let n = columns.get(&i).ok_or_else(|| {
+                    ParseError::new(format!("crate {:?} isn't above a stack number", c))
+                        .at(y + 1, i + 1)
+                })?;
+                stacks
+                    .entry(*n)
+                    .and_modify(|stack| stack.push(c))
+                    .or_insert(vec![c]);
+            }
+        }
+
+        Ok(Crates { stacks })
+    }
+}
+
+impl Crates {
+    fn move_crates(&mut self, m: &Move, batch: bool) {
+        let mut moving: Vec<char> = Vec::new();
+
+        let src_stack = self.stacks.get_mut(&m.src).unwrap();
+        for _ in 0..m.n {
+            moving.push(src_stack.pop().unwrap());
+        }
+
+        if !batch {
+            moving.reverse();
+        }
+
+        let dst_stack = self.stacks.get_mut(&m.dst).unwrap();
+        for _ in 0..m.n {
+            dst_stack.push(moving.pop().unwrap());
+        }
+    }
+
+    fn top(&self) -> String {
+        let mut ordered_crates: Vec<usize> = self.stacks.keys().copied().collect();
+        ordered_crates.sort();
+
+        ordered_crates
+            .iter()
+            .map(|k| self.stacks.get(k).unwrap().last().unwrap())
+            .collect()
+    }
+}
+
+pub struct Move {
+    n: usize,
+    src: usize,
+    dst: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (n, rest) = split_once(strip_prefix(s, "move ")?, " from ")?;
+        let (src, dst) = split_once(rest, " to ")?;
+
+        Ok(Move {
+            n: number(n)?,
+            src: number(src)?,
+            dst: number(dst)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn parse_crates() {
+        let crates: Crates = EXAMPLE.parse().unwrap();
+        assert_eq!(crates.stacks.get(&1).unwrap(), &vec!['Z', 'N']);
+        assert_eq!(crates.stacks.get(&2).unwrap(), &vec!['M', 'C', 'D']);
+        assert_eq!(crates.stacks.get(&3).unwrap(), &vec!['P']);
+    }
+
+    #[test]
+    fn example() {
+        let (crates, moves) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&crates, &moves, false), "CMZ");
+        assert_eq!(solve(&crates, &moves, true), "MCD");
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_parse::{each_char, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+        line(input, |s| {
+            each_char(s, "a lowercase letter", |c| {
+                c.is_ascii_lowercase().then_some(c)
+            })
+        })
+    }
+
+    fn part_one(chars: &Vec<char>) -> Answer {
+        solve(chars, 4).into()
+    }
+
+    fn part_two(chars: &Vec<char>) -> Answer {
+        solve(chars, 14).into()
+    }
+}
+
+fn solve(chars: &[char], n: usize) -> usize {
+    chars
+        .windows(n)
+        .enumerate()
+        .find(|(_, chars)| chars.iter().copied().collect::<HashSet<char>>().len() == n)
+        .map(|(i, _)| i + n)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(input: &str) -> Vec<char> {
+        Puzzle::parse(input).unwrap()
+    }
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(solve(&parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 4), 7);
+        assert_eq!(solve(&parse("bvwbjplbgvbhsrlpgdmjqwftvncz"), 4), 5);
+        assert_eq!(solve(&parse("nppdvjthqldpwncqszvftbrmjlhg"), 4), 6);
+        assert_eq!(solve(&parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 4), 10);
+        assert_eq!(solve(&parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 4), 11);
+    }
+
+    #[test]
+    fn part_two_examples() {
+        assert_eq!(solve(&parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 14), 19);
+        assert_eq!(solve(&parse("bvwbjplbgvbhsrlpgdmjqwftvncz"), 14), 23);
+        assert_eq!(solve(&parse("nppdvjthqldpwncqszvftbrmjlhg"), 14), 23);
+        assert_eq!(solve(&parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 14), 29);
+        assert_eq!(solve(&parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 14), 26);
+    }
+}
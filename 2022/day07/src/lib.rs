@@ -0,0 +1,97 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 7);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = HashMap<String, usize>;
+
+    fn parse(input: &str) -> Result<HashMap<String, usize>, ParseError> {
+        dir_sizes(input)
+    }
+
+    fn part_one(input: &HashMap<String, usize>) -> Answer {
+        part_one(input, 100000).into()
+    }
+
+    fn part_two(input: &HashMap<String, usize>) -> Answer {
+        part_two(input, 70000000, 30000000).into()
+    }
+}
+
+fn part_one(sizes: &HashMap<String, usize>, limit: usize) -> usize {
+    sizes.values().filter(|size| **size <= limit).sum()
+}
+
+fn part_two(sizes: &HashMap<String, usize>, max: usize, needed: usize) -> usize {
+    // Work out the current usage
+    let current = sizes.get("").unwrap();
+    let need_to_release = current + needed - max;
+
+    sizes
+        .values()
+        .filter(|size| **size >= need_to_release)
+        .fold(max, |acc, x| if *x < acc { *x } else { acc })
+}
+
+fn dir_sizes(s: &str) -> Result<HashMap<String, usize>, ParseError> {
+    let mut path: Vec<String> = Vec::new();
+    let mut dirs: HashMap<String, usize> = HashMap::new();
+
+    lines(s, |line| {
+        let line = line.trim();
+
+        if line == "$ cd /" {
+            path.clear();
+        } else if line == "$ cd .." {
+            path.pop();
+        } else if let Some(dir) = line.strip_prefix("$ cd ") {
+            path.push(dir.to_string());
+        } else if line.starts_with("$") || line.starts_with("dir") {
+            // Do nothing
+        } else {
+            // Assume anything else is a file
+            let parts = split_once(line, " ")?;
+            let size = number(parts.0)?;
+
+            // We add the size of this to the current dir, and all it's parents.
+            for i in 0..=path.len() {
+                let p = path[..i].join("/");
+                dirs.entry(p).and_modify(|e| *e += size).or_insert(size);
+            }
+        }
+
+        Ok(())
+    })?;
+
+    Ok(dirs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let sizes = dir_sizes(EXAMPLE).unwrap();
+        assert_eq!(part_one(&sizes, 100000), 95437);
+        assert_eq!(part_two(&sizes, 70000000, 30000000), 24933642);
+    }
+
+    #[test]
+    fn parse_input() {
+        let dirs = dir_sizes(EXAMPLE).unwrap();
+
+        let mut exp_dirs: HashMap<String, usize> = HashMap::new();
+        exp_dirs.insert("".to_string(), 48381165);
+        exp_dirs.insert("a".to_string(), 94853);
+        exp_dirs.insert("a/e".to_string(), 584);
+        exp_dirs.insert("d".to_string(), 24933642);
+        assert_eq!(dirs, exp_dirs);
+    }
+}
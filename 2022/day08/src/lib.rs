@@ -0,0 +1,146 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        part_one(grid).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        part_two(grid).into()
+    }
+}
+
+fn part_one(grid: &Grid) -> usize {
+    let mut all_visible: HashSet<(usize, usize)> = HashSet::new();
+
+    for y in 0..grid.height {
+        all_visible.extend(trees_visible_in_row(grid.row(y)).iter().map(|x| (*x, y)));
+    }
+    for x in 0..grid.width {
+        all_visible.extend(trees_visible_in_row(grid.column(x)).iter().map(|y| (x, *y)));
+    }
+
+    all_visible.len()
+}
+
+fn trees_visible_in_row(trees: Vec<i8>) -> HashSet<usize> {
+    // Set of all the positions of trees that are visible.
+    let mut visible: HashSet<usize> = HashSet::new();
+
+    // First, go forwards and check which ones are visible from the left/top
+    let mut highest = -1;
+    for (i, &height) in trees.iter().enumerate() {
+        if height > highest {
+            highest = height;
+            visible.insert(i);
+        }
+    }
+
+    // Now, go backwards and check which ones are visible from the right/bottom
+    let mut highest = -1;
+    for (i, &height) in trees.iter().enumerate().rev() {
+        if height > highest {
+            highest = height;
+            visible.insert(i);
+        }
+    }
+
+    visible
+}
+
+fn part_two(grid: &Grid) -> usize {
+    let mut max: usize = 0;
+    for x in 1..grid.width - 1 {
+        for y in 1..grid.height - 1 {
+            let row_score = trees_visible(grid.row(y), x);
+            let column_score = trees_visible(grid.column(x), y);
+            let score = row_score * column_score;
+            if score > max {
+                max = score;
+            }
+        }
+    }
+
+    max
+}
+
+fn trees_visible(trees: Vec<i8>, pos: usize) -> usize {
+    let max_height = trees.get(pos).unwrap();
+
+    let mut forward: usize = 0;
+    for tree in trees[pos + 1..].iter() {
+        forward += 1;
+        if tree >= max_height {
+            break;
+        }
+    }
+
+    let mut backward: usize = 0;
+    for tree in trees[..pos].iter().rev() {
+        backward += 1;
+        if tree >= max_height {
+            break;
+        }
+    }
+
+    forward * backward
+}
+
+pub struct Grid {
+    rows: Vec<Vec<i8>>,
+    height: usize,
+    width: usize,
+}
+
+impl Grid {
+    fn parse(s: &str) -> Result<Grid, ParseError> {
+        let trees = aoc_grid::Grid::try_parse(s, |c| {
+            c.to_digit(10)
+                .map(|d| d as i8)
+                .ok_or_else(|| ParseError::expected("a tree's height", &c.to_string()))
+        })?;
+
+        if trees.width() == 0 {
+            return Err(ParseError::new("expected a map of the trees"));
+        }
+
+        Ok(Grid {
+            rows: trees.rows().map(<[i8]>::to_vec).collect(),
+            height: trees.height(),
+            width: trees.width(),
+        })
+    }
+
+    fn row(&self, y: usize) -> Vec<i8> {
+        self.rows.get(y).unwrap().to_vec()
+    }
+
+    fn column(&self, x: usize) -> Vec<i8> {
+        self.rows.iter().map(|row| *row.get(x).unwrap()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let grid = Grid::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&grid), 21);
+        assert_eq!(part_two(&grid), 8);
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 9);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(char, usize)>;
+
+    fn parse(input: &str) -> Result<Vec<(char, usize)>, ParseError> {
+        lines(input, parse_move)
+    }
+
+    fn part_one(moves: &Vec<(char, usize)>) -> Answer {
+        unique_tail_positions(moves, 2).into()
+    }
+
+    fn part_two(moves: &Vec<(char, usize)>) -> Answer {
+        unique_tail_positions(moves, 10).into()
+    }
+}
+
+fn unique_tail_positions(moves: &[(char, usize)], knots: usize) -> usize {
+    let mut rope = Rope::new(knots);
+    for &(dir, n) in moves {
+        rope.move_n(dir, n);
+    }
+
+    rope.visited.len()
+}
+
+/// A move like `R 4`.
+fn parse_move(line: &str) -> Result<(char, usize), ParseError> {
+    let (dir, n) = split_once(line, " ")?;
+    let dir = match dir {
+        "L" => 'L',
+        "R" => 'R',
+        "U" => 'U',
+        "D" => 'D',
+        _ => return Err(ParseError::expected("L, R, U or D", dir)),
+    };
+
+    Ok((dir, number(n)?))
+}
+
+struct Rope {
+    n: usize,
+    knots: Vec<(i32, i32)>,
+    visited: HashSet<(i32, i32)>,
+}
+
+impl Rope {
+    fn new(n: usize) -> Rope {
+        let mut visited = HashSet::new();
+        visited.insert((0, 0));
+
+        let mut knots: Vec<(i32, i32)> = Vec::new();
+        for _ in 0..n {
+            knots.push((0, 0));
+        }
+
+        Rope { n, knots, visited }
+    }
+
+    fn move_n(&mut self, dir: char, n: usize) {
+        for _ in 0..n {
+            self.move_once(dir);
+        }
+    }
+
+    fn move_once(&mut self, dir: char) {
+        // Move the head first
+        let head = self.knots.get_mut(0).unwrap();
+        match dir {
+            'L' => head.0 -= 1,
+            'R' => head.0 += 1,
+            'U' => head.1 += 1,
+            'D' => head.1 -= 1,
+            _ => unreachable!("the moves are checked when they're parsed"),
+        }
+
+        for i in 1..self.n {
+            let prev = *self.knots.get(i - 1).unwrap();
+            let curr = self.knots.get_mut(i).unwrap();
+
+            let diff = (prev.0 - curr.0, prev.1 - curr.1);
+            if diff.0.abs() > 1 || diff.1.abs() > 1 {
+                curr.0 += unit(diff.0);
+                curr.1 += unit(diff.1);
+            }
+        }
+
+        let tail = self.knots.last().unwrap();
+        self.visited.insert(*tail);
+    }
+}
+
+fn unit(distance: i32) -> i32 {
+    match distance {
+        0 => 0,
+        x if x > 0 => 1,
+        _ => -1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn example() {
+        let moves = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(unique_tail_positions(&moves, 2), 13);
+        assert_eq!(unique_tail_positions(&moves, 10), 1);
+
+        let moves = Puzzle::parse(EXAMPLE2).unwrap();
+        assert_eq!(unique_tail_positions(&moves, 10), 36);
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_grid::Grid;
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 10);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Vec<isize>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(crt: &Vec<isize>) -> Answer {
+        part_one(crt).into()
+    }
+
+    fn part_two(crt: &Vec<isize>) -> Answer {
+        part_two(crt).into()
+    }
+}
+
+fn part_one(crt: &[isize]) -> isize {
+    crt.iter()
+        .enumerate()
+        .skip(19)
+        .step_by(40)
+        .map(|(i, n)| ((i + 1) as isize) * n) // i+1 because the cycle's are 1-indexed.
+        .sum()
+}
+
+/// Reads the letters on the screen.
+fn part_two(crt: &[isize]) -> String {
+    aoc_ocr::read_grid(&screen(crt)).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Which pixels are lit, where the sprite covers the pixel being drawn.
+fn screen(crt: &[isize]) -> Grid<bool> {
+    Grid::from_fn(40, 6, |(x, y)| (crt[y * 40 + x] - x as isize).abs() <= 1)
+}
+
+fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    let instructions = lines(input, |line| match line.strip_prefix("addx ") {
+        _ if line == "noop" => Ok(vec![0]),
+        Some(n) => Ok(vec![0, number(n)?]),
+        None => Err(ParseError::expected("\"noop\" or \"addx\"", line)),
+    })?;
+
+    let changes: Vec<isize> = instructions
+        .into_iter()
+        .flatten()
+        .scan(1, |state, n| {
+            *state += n;
+            Some(*state)
+        })
+        .collect();
+
+    let mut crt = vec![1];
+    crt.extend(changes);
+    Ok(crt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const RESULT: &str = include_str!("../part2_example_result.txt");
+
+    #[test]
+    fn test_parsing() {
+        assert_eq!(parse_input(EXAMPLE), Ok(vec![1, 1, 1, 4, 4, -1]));
+    }
+
+    #[test]
+    fn test_example() {
+        let crt = parse_input(EXAMPLE2).unwrap();
+        assert_eq!(part_one(&crt), 13140);
+
+        let screen = screen(&crt).map(|&lit| if lit { '#' } else { ' ' });
+        assert_eq!(screen.to_string().trim(), RESULT.trim());
+    }
+}
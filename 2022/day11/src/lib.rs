@@ -0,0 +1,219 @@
+use aoc_parse::blocks;
+use aoc_parse::combinators::{complete, either, int, labelled, list, map, Parsed, Parser};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::str::FromStr;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 11);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        blocks(input, str::parse)
+    }
+
+    fn part_one(input: &Vec<Monkey>) -> Answer {
+        part_one(input, 20).into()
+    }
+
+    fn part_two(input: &Vec<Monkey>) -> Answer {
+        part_two(input, 10000).into()
+    }
+}
+
+fn part_one(monkeys: &[Monkey], rounds: usize) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let m = monkeys.get_mut(i).unwrap();
+            m.examine().iter().for_each(|(id, worry)| {
+                monkeys[*id].items.push(*worry);
+            });
+        }
+    }
+
+    monkeys.sort_by_key(|m| std::cmp::Reverse(m.inspections));
+    monkeys[0].inspections * monkeys[1].inspections
+}
+
+fn part_two(monkeys: &[Monkey], rounds: usize) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    let divisor = monkeys
+        .iter()
+        .map(|m| m.divide_by)
+        .reduce(|acc, x| acc * x / gcd(acc, x))
+        .unwrap();
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let m = monkeys.get_mut(i).unwrap();
+            m.examine_mod(divisor).iter().for_each(|(id, worry)| {
+                monkeys[*id].items.push(*worry);
+            });
+        }
+    }
+
+    monkeys.sort_by_key(|m| std::cmp::Reverse(m.inspections));
+    monkeys[0].inspections * monkeys[1].inspections
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if a % b == 0 {
+        return b;
+    }
+    gcd(b, a % b)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Op {
+    Add(i64),
+    Mult(i64),
+    Sq,
+}
+
+impl Op {
+    /// Parses the operation's right hand side, like `* old` or `+ 6`.
+    fn parse(s: &str) -> Parsed<'_, Op> {
+        let square = map("* old", |_| Op::Sq);
+        let mult = map(("* ", int), Op::Mult);
+        let add = map(("+ ", int), Op::Add);
+        either(square, either(mult, add))(s)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Monkey {
+    id: usize,
+    items: Vec<i64>,
+    op: Op,
+    divide_by: i64,
+    dst_true: usize,
+    dst_false: usize,
+    inspections: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().map(str::trim).collect();
+        let [id, items, operation, test, if_true, if_false] = lines[..] else {
+            return Err(ParseError::new("expected six lines of notes on a monkey"));
+        };
+
+        let id = complete(|line| {
+            let (id, rest) = ("Monkey ", int).read(line)?;
+            let (_, rest) = ":".read(rest)?;
+            Ok((id, rest))
+        })(id)?;
+        let items = complete(labelled("Starting items", list(int)))(items)?;
+        let op = complete(labelled("Operation", ("new = old ", Op::parse)))(operation)?;
+        let divide_by = complete(labelled("Test", ("divisible by ", int)))(test)?;
+        let dst_true = complete(labelled("If true", ("throw to monkey ", int)))(if_true)?;
+        let dst_false = complete(labelled("If false", ("throw to monkey ", int)))(if_false)?;
+
+        Ok(Monkey {
+            id,
+            items,
+            op,
+            divide_by,
+            dst_true,
+            dst_false,
+            inspections: 0,
+        })
+    }
+}
+
+impl Monkey {
+    fn examine(&mut self) -> Vec<(usize, i64)> {
+        let items: Vec<(usize, i64)> = self
+            .items
+            .drain(0..)
+            .map(|item| {
+                let worry: i64 = match self.op {
+                    Op::Add(x) => (item + x) / 3,
+                    Op::Mult(x) => (item * x) / 3,
+                    Op::Sq => (item * item) / 3,
+                };
+
+                if worry % self.divide_by == 0 {
+                    (self.dst_true, worry)
+                } else {
+                    (self.dst_false, worry)
+                }
+            })
+            .collect();
+
+        self.inspections += items.len();
+        items
+    }
+
+    fn examine_mod(&mut self, n: i64) -> Vec<(usize, i64)> {
+        let items: Vec<(usize, i64)> = self
+            .items
+            .drain(0..)
+            .map(|item| {
+                let worry: i64 = match self.op {
+                    Op::Add(x) => (item + x) % n,
+                    Op::Mult(x) => (item * x) % n,
+                    Op::Sq => (item * item) % n,
+                };
+
+                if worry % self.divide_by == 0 {
+                    (self.dst_true, worry)
+                } else {
+                    (self.dst_false, worry)
+                }
+            })
+            .collect();
+
+        self.inspections += items.len();
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_parsing() {
+        let first = EXAMPLE.split_once("\n\n").unwrap().0;
+        let m: Monkey = first.parse().unwrap();
+        let exp = Monkey {
+            id: 0,
+            items: vec![79, 98],
+            op: Op::Mult(19),
+            divide_by: 23,
+            dst_true: 2,
+            dst_false: 3,
+            inspections: 0,
+        };
+        assert_eq!(exp, m);
+    }
+
+    #[test]
+    fn item_examination() {
+        let first = EXAMPLE.split_once("\n\n").unwrap().0;
+        let mut m: Monkey = first.parse().unwrap();
+        let items = m.examine();
+
+        let exp: Vec<(usize, i64)> = vec![(3, 500), (3, 620)];
+        assert_eq!(items, exp);
+        assert_eq!(2, m.inspections);
+        assert_eq!(0, m.items.len());
+    }
+
+    #[test]
+    fn example() {
+        let monkeys = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&monkeys, 20), 10605);
+        assert_eq!(part_two(&monkeys, 10000), 2713310158);
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_grid::{Grid, Pos};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use aoc_search::bfs;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 12);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part_one(map: &Map) -> Answer {
+        map.descend(|pos| pos == map.start).into()
+    }
+
+    fn part_two(map: &Map) -> Answer {
+        map.descend(|pos| map.heights[pos] == b'a').into()
+    }
+}
+
+pub struct Map {
+    start: Pos,
+    end: Pos,
+    heights: Grid<u8>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let grid = Grid::try_parse(input, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c as u8),
+            c => Err(ParseError::expected(
+                "a height from 'a' to 'z'",
+                &c.to_string(),
+            )),
+        })?;
+        let find = |c: u8| {
+            grid.find(|&h| h == c)
+                .ok_or_else(|| ParseError::new(format!("expected {:?} on the map", c as char)))
+        };
+        let (start, end) = (find(b'S')?, find(b'E')?);
+
+        let heights = grid.map(|&c| match c {
+            b'S' => b'a',
+            b'E' => b'z',
+            c => c,
+        });
+
+        Ok(Map {
+            start,
+            end,
+            heights,
+        })
+    }
+
+    /// The fewest steps from anywhere matching `goal` up to the end, found by
+    /// walking back down from the end. Going up, each step can climb at most
+    /// one, so going down it can drop at most one.
+    fn descend<G: Fn(Pos) -> bool>(&self, goal: G) -> usize {
+        let successors = |&pos: &Pos| {
+            let min = self.heights[pos] - 1;
+            self.heights
+                .neighbours(pos)
+                .filter(move |&n| self.heights[n] >= min)
+        };
+
+        bfs(self.end, successors, |&pos| goal(pos))
+            .goal_cost()
+            .expect("no route to the top")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let map = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part_one(&map), 31);
+        assert_eq!(Puzzle::part_two(&map), 29);
+    }
+}
@@ -0,0 +1,194 @@
+use aoc_parse::combinators::{bracketed, complete, either, int, map, Parsed};
+use aoc_parse::{blocks, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp::Ordering;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 13);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(Packet, Packet)>;
+
+    fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(pairs: &Vec<(Packet, Packet)>) -> Answer {
+        part_one(pairs).into()
+    }
+
+    fn part_two(pairs: &Vec<(Packet, Packet)>) -> Answer {
+        part_two(pairs).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    blocks(input, |block| {
+        match lines(block, complete(Packet::parse))?.as_slice() {
+            [left, right] => Ok((left.clone(), right.clone())),
+            _ => Err(ParseError::new("expected a pair of packets")),
+        }
+    })
+}
+
+fn part_one(pairs: &[(Packet, Packet)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (left, right))| {
+            let ord = left.cmp(right);
+            if ord == Ordering::Greater {
+                return None;
+            }
+            Some(i + 1)
+        })
+        .sum()
+}
+
+fn part_two(pairs: &[(Packet, Packet)]) -> usize {
+    let mut packets: Vec<Packet> = pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect();
+
+    let key0 = Packet::List(vec![Packet::List(vec![Packet::Num(2)])]);
+    let key1 = Packet::List(vec![Packet::List(vec![Packet::Num(6)])]);
+
+    packets.push(key0.clone());
+    packets.push(key1.clone());
+    packets.sort();
+
+    packets.iter().enumerate().fold(1, |acc, (i, p)| {
+        if *p == key0 || *p == key1 {
+            acc * (i + 1)
+        } else {
+            acc
+        }
+    })
+}
+
+#[derive(Debug, Eq, Clone)]
+pub enum Packet {
+    Num(i64),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    /// Parses a number or a list of packets, like `[1,[2,[]]]`.
+    fn parse(s: &str) -> Parsed<'_, Packet> {
+        either(
+            map(int, Packet::Num),
+            map(bracketed(Packet::parse), Packet::List),
+        )(s)
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Packet::*;
+
+        match (self, other) {
+            (Num(x), Num(y)) => x.cmp(y),
+            (Num(_), List(ys)) => {
+                if ys.is_empty() {
+                    return Ordering::Greater;
+                }
+
+                let ord = self.cmp(ys.first().unwrap());
+                if ord == Ordering::Equal {
+                    // If they are equal, we need to work out whether the right will
+                    // run out first
+                    if ys.len() == 1 {
+                        return Ordering::Equal;
+                    }
+                    return Ordering::Less;
+                }
+
+                ord
+            }
+
+            (List(xs), Num(_)) => {
+                if xs.is_empty() {
+                    return Ordering::Less;
+                }
+
+                let ord = xs.first().unwrap().cmp(other);
+                if ord == Ordering::Equal {
+                    // If they are equal, the
+                    // run out first
+                    if xs.len() == 1 {
+                        return Ordering::Equal;
+                    }
+                    return Ordering::Greater;
+                }
+
+                ord
+            }
+
+            (List(xs), List(ys)) => {
+                for (i, x) in xs.iter().enumerate() {
+                    let y = ys.get(i);
+                    if y.is_none() {
+                        // Right side ran out first
+                        return Ordering::Greater;
+                    }
+
+                    let ord = x.cmp(y.unwrap());
+                    if ord != Ordering::Equal {
+                        return ord;
+                    }
+                }
+
+                // Left side has run out of items, check whether right is out too.
+                if xs.len() == ys.len() {
+                    return Ordering::Equal;
+                }
+
+                // Otherwise left side ran out first.
+                Ordering::Less
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn parsing() {
+        let line = EXAMPLE.lines().next().unwrap();
+
+        let p = complete(Packet::parse)(line).unwrap();
+        let exp: Packet = Packet::List(vec![
+            Packet::Num(1),
+            Packet::Num(1),
+            Packet::Num(3),
+            Packet::Num(1),
+            Packet::Num(1),
+        ]);
+        assert_eq!(exp, p);
+    }
+
+    #[test]
+    fn example() {
+        let pairs = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&pairs), 13);
+        assert_eq!(part_two(&pairs), 140);
+    }
+}
@@ -0,0 +1,190 @@
+use aoc_parse::combinators::{complete, int, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 14);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        part_one(grid).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        part_two(grid).into()
+    }
+}
+
+fn part_one(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+
+    let mut count = 0;
+    loop {
+        let p = grid.pour();
+        if p.y == grid.lowest {
+            return count;
+        }
+
+        count += 1;
+        grid.filled.insert(p);
+    }
+}
+
+fn part_two(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    grid.lowest += 1;
+
+    let mut count = 0;
+    loop {
+        count += 1;
+
+        let p = grid.pour();
+        if p == (Point { x: 500, y: 0 }) {
+            return count;
+        }
+
+        grid.filled.insert(p);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Grid {
+    filled: HashSet<Point>,
+    lowest: isize,
+}
+
+impl Grid {
+    /// Reads the paths of the rock walls, one per line like
+    /// `498,4 -> 498,6 -> 496,6`.
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        let paths = lines(input, complete(path))?;
+        let filled: HashSet<Point> = paths.iter().flat_map(|path| wall(path)).collect();
+
+        let lowest = filled
+            .iter()
+            .map(|p| p.y)
+            .max()
+            .ok_or_else(|| ParseError::new("there are no walls"))?;
+
+        Ok(Grid { filled, lowest })
+    }
+
+    fn pour(&mut self) -> Point {
+        let mut sand_pos = Point { x: 500, y: 0 };
+
+        'outer: loop {
+            if sand_pos.y == self.lowest {
+                return sand_pos;
+            }
+
+            let dirs = vec![0, -1, 1]; // straight down, left then right
+            for d in dirs {
+                let next_pos = Point {
+                    x: sand_pos.x + d,
+                    y: sand_pos.y + 1,
+                };
+
+                // This position is already filled, so we can't pour here.
+                if self.filled.contains(&next_pos) {
+                    continue;
+                }
+
+                // If we can pour here, do so.
+                sand_pos = next_pos;
+                continue 'outer;
+            }
+
+            // If we get all the way through and we couldn't advance, we mark the position as
+            // filled
+            return sand_pos;
+        }
+    }
+}
+
+fn path(s: &str) -> Parsed<'_, Vec<Point>> {
+    let (first, mut rest) = point(s)?;
+    let mut points = vec![first];
+    while rest.starts_with(" -> ") {
+        let (point, after) = (" -> ", point).read(rest)?;
+        points.push(point);
+        rest = after;
+    }
+
+    Ok((points, rest))
+}
+
+fn point(s: &str) -> Parsed<'_, Point> {
+    let (x, s) = int(s)?;
+    let (y, rest) = (",", int).read(s)?;
+    Ok((Point { x, y }, rest))
+}
+
+/// The rock along a path.
+fn wall(vertices: &[Point]) -> HashSet<Point> {
+    vertices
+        .windows(2)
+        .flat_map(|window| {
+            let (min_x, max_x) = min_max(window[0].x, window[1].x);
+            let (min_y, max_y) = min_max(window[0].y, window[1].y);
+
+            if min_x == max_x {
+                (min_y..=max_y)
+                    .map(|y| Point { x: min_x, y })
+                    .collect::<Vec<Point>>()
+            } else {
+                (min_x..=max_x)
+                    .map(|x| Point { x, y: min_y })
+                    .collect::<Vec<Point>>()
+            }
+        })
+        .collect()
+}
+
+fn min_max(a: isize, b: isize) -> (isize, isize) {
+    if a > b {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let grid = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&grid), 24);
+        assert_eq!(part_two(&grid), 93);
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(grid.filled.len(), 20);
+        assert_eq!(grid.lowest, 9);
+
+        let err = Puzzle::parse("498,4 -> 498,6\n503,4 -> 502 -> 494,9\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 13: expected \",\", found \" -> 494,9\""
+        );
+    }
+}
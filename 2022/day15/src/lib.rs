@@ -0,0 +1,136 @@
+use aoc_parse::combinators::{complete, field, int, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 15);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+        lines(input, complete(Sensor::parse))
+    }
+
+    fn part_one(input: &Vec<Sensor>) -> Answer {
+        part_one(input, 2000000).into()
+    }
+
+    fn part_two(input: &Vec<Sensor>) -> Answer {
+        part_two(input, 0, 4000000).into()
+    }
+}
+
+fn part_one(sensors: &[Sensor], row: i64) -> usize {
+    let beacons: HashSet<i64> = sensors
+        .iter()
+        .filter(|s| s.beacon_y == row)
+        .map(|s| s.beacon_x)
+        .collect();
+
+    let not_present: HashSet<i64> = sensors
+        .iter()
+        .filter_map(|s| s.scanned_on_row(row))
+        .flat_map(|range| range.0..=range.1)
+        .collect();
+
+    not_present.difference(&beacons).count()
+}
+
+fn part_two(sensors: &[Sensor], min: i64, max: i64) -> i64 {
+    let mut sensors = sensors.to_vec();
+    sensors.sort_by(|a, b| {
+        let ord = a.x.cmp(&b.x);
+        if ord == Ordering::Equal {
+            a.y.cmp(&b.y)
+        } else {
+            ord
+        }
+    });
+
+    for y in min..=max {
+        let mut ranges: Vec<(i64, i64)> = sensors
+            .iter()
+            .filter_map(|line| line.scanned_on_row(y))
+            .collect();
+
+        // Sort them so that we have them in order left to right.
+        ranges.sort_by_key(|a| a.0);
+
+        // Iterate through the ranges,
+        let mut end = min - 1;
+        for r in ranges {
+            if r.0 > end && r.0 > min {
+                // We've found the gap.
+                return (end + 1) * 4000000 + y;
+            }
+
+            if r.1 > end {
+                end = r.1;
+            }
+        }
+    }
+
+    panic!("no row found!");
+}
+
+#[derive(Clone)]
+pub struct Sensor {
+    x: i64,
+    y: i64,
+    beacon_x: i64,
+    beacon_y: i64,
+    radius: i64,
+}
+
+impl Sensor {
+    /// Parses a line like
+    /// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`.
+    fn parse(input: &str) -> Parsed<'_, Sensor> {
+        let ((x, y), s) = ("Sensor at ", position).read(input)?;
+        let ((beacon_x, beacon_y), s) = (": closest beacon is at ", position).read(s)?;
+
+        let radius: i64 = (x - beacon_x).abs() + (y - beacon_y).abs();
+        let sensor = Sensor {
+            x,
+            y,
+            beacon_x,
+            beacon_y,
+            radius,
+        };
+        Ok((sensor, s))
+    }
+
+    fn scanned_on_row(&self, y: i64) -> Option<(i64, i64)> {
+        let d_y: i64 = (self.y - y).abs();
+        if d_y > self.radius {
+            return None;
+        }
+
+        let d_x = self.radius - d_y;
+        Some((self.x - d_x, self.x + d_x))
+    }
+}
+
+fn position(input: &str) -> Parsed<'_, (i64, i64)> {
+    let (x, s) = field("x", int).read(input)?;
+    let (y, s) = (", ", field("y", int)).read(s)?;
+    Ok(((x, y), s))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let sensors = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&sensors, 10), 26);
+        assert_eq!(part_two(&sensors, 0, 20), 56000011);
+    }
+}
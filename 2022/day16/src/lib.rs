@@ -0,0 +1,234 @@
+use aoc_parse::{lines, number, split_once, strip_prefix};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use aoc_search::bfs;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Debug, Write};
+use std::str::FromStr;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 16);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = HashMap<Location, Room>;
+
+    fn parse(input: &str) -> Result<HashMap<Location, Room>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(rooms: &HashMap<Location, Room>) -> Answer {
+        part_one(rooms).into()
+    }
+
+    fn part_two(rooms: &HashMap<Location, Room>) -> Answer {
+        part_two(rooms).into()
+    }
+}
+
+fn part_one(rooms: &HashMap<Location, Room>) -> usize {
+    let best = Network::new(rooms).best_releases(30);
+    best.into_values().max().unwrap_or(0)
+}
+
+/// Teaching the elephant takes four minutes, and after that the two of us
+/// open valves separately, so the best plan is the best pair of plans that
+/// don't share any valves.
+fn part_two(rooms: &HashMap<Location, Room>) -> usize {
+    let best: Vec<(u64, usize)> = Network::new(rooms).best_releases(26).into_iter().collect();
+
+    let mut most = 0;
+    for (i, &(mine, released)) in best.iter().enumerate() {
+        for &(elephants, also_released) in &best[i + 1..] {
+            if mine & elephants == 0 {
+                most = most.max(released + also_released);
+            }
+        }
+    }
+
+    most
+}
+
+fn parse_input(input: &str) -> Result<HashMap<Location, Room>, ParseError> {
+    let rooms: Vec<Room> = lines(input, str::parse)?;
+    let rooms: HashMap<Location, Room> = rooms
+        .into_iter()
+        .map(|room| (room.loc.clone(), room))
+        .collect();
+
+    if !rooms.contains_key(&START) {
+        return Err(ParseError::new("expected a valve AA to start from"));
+    }
+    for room in rooms.values() {
+        if let Some(to) = room.tunnels.iter().find(|to| !rooms.contains_key(to)) {
+            return Err(ParseError::new(format!(
+                "expected a valve {:?} for the tunnel from {:?}",
+                to, room.loc
+            )));
+        }
+    }
+
+    Ok(rooms)
+}
+
+const A: u32 = 'A' as u32;
+
+/// Where we start out, at valve AA.
+const START: Location = Location(0);
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Location(u32);
+
+impl FromStr for Location {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 2 || !s.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(ParseError::expected("a valve like 'XX'", s));
+        }
+
+        Ok(Location(
+            s.chars().fold(0, |acc, c| acc * 26 + (c as u32 - A)),
+        ))
+    }
+}
+
+impl Debug for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char(char::from_u32((self.0 / 26) + A).unwrap())?;
+        f.write_char(char::from_u32((self.0 % 26) + A).unwrap())
+    }
+}
+
+#[derive(Debug)]
+pub struct Room {
+    loc: Location,
+    flow_rate: usize,
+    tunnels: Vec<Location>,
+}
+
+impl FromStr for Room {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .strip_prefix("Valve ")
+            .ok_or_else(|| ParseError::expected("'Valve XX'", s))?;
+        let (loc, rest) = split_once(rest, " has flow rate=")?;
+        let (flow_rate, rest) = split_once(rest, "; ")?;
+
+        // A room with a single tunnel says so in the singular
+        let tunnels = strip_prefix(rest, "tunnels lead to valves ")
+            .or_else(|_| strip_prefix(rest, "tunnel leads to valve "))?;
+
+        Ok(Room {
+            loc: loc.parse()?,
+            flow_rate: number(flow_rate)?,
+            tunnels: tunnels
+                .split(", ")
+                .map(str::parse)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Only the valves with some flow are worth walking to, so the tunnels are
+/// boiled down to how many minutes it takes to get from one of those to
+/// another.
+struct Network {
+    flow_rates: Vec<usize>,
+    /// Minutes from one valve to another, with the start as the last valve.
+    distances: Vec<Vec<usize>>,
+}
+
+impl Network {
+    fn new(rooms: &HashMap<Location, Room>) -> Network {
+        let mut valves: Vec<&Room> = rooms.values().filter(|room| room.flow_rate > 0).collect();
+        valves.sort_by_key(|room| room.loc.0);
+
+        let mut locations: Vec<&Location> = valves.iter().map(|room| &room.loc).collect();
+        locations.push(&START);
+
+        let distances = locations
+            .iter()
+            .map(|&from| {
+                let paths = bfs(from.clone(), |loc| rooms[loc].tunnels.clone(), |_| false);
+                locations
+                    .iter()
+                    .map(|&to| paths.cost(to).unwrap_or(usize::MAX))
+                    .collect()
+            })
+            .collect();
+
+        Network {
+            flow_rates: valves.iter().map(|room| room.flow_rate).collect(),
+            distances,
+        }
+    }
+
+    /// The most pressure that opening each set of valves can release within
+    /// `time` minutes, keyed by a bitmask of the valves that were opened.
+    fn best_releases(&self, time: usize) -> HashMap<u64, usize> {
+        let mut best = HashMap::new();
+        self.open_valves(self.flow_rates.len(), time, 0, 0, &mut best);
+        best
+    }
+
+    fn open_valves(
+        &self,
+        at: usize,
+        time: usize,
+        opened: u64,
+        released: usize,
+        best: &mut HashMap<u64, usize>,
+    ) {
+        let most = best.entry(opened).or_insert(0);
+        *most = released.max(*most);
+
+        for (next, &flow_rate) in self.flow_rates.iter().enumerate() {
+            // It takes a minute to open the valve once we get there
+            let cost = self.distances[at][next].saturating_add(1);
+            if opened & (1 << next) != 0 || cost >= time {
+                continue;
+            }
+
+            let time = time - cost;
+            let opened = opened | (1 << next);
+            self.open_valves(next, time, opened, released + flow_rate * time, best);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let rooms = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&rooms), 1651);
+        assert_eq!(part_two(&rooms), 1707);
+    }
+
+    #[test]
+    fn parse_error() {
+        let input = EXAMPLE.replace("Valve GG", "Vale GG");
+        let err = parse_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, column 1: expected 'Valve XX', found \"Vale GG has flow rate=0; tunnels lead to valves FF, HH\""
+        );
+    }
+
+    #[test]
+    fn missing_valve() {
+        let input = EXAMPLE.replace("tunnel leads to valve GG", "tunnel leads to valve ZZ");
+        let err = parse_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a valve ZZ for the tunnel from HH"
+        );
+    }
+}
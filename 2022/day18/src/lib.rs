@@ -0,0 +1,115 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use itertools::Itertools;
+use std::str::FromStr;
+
+pub const DAY: Day = Day::new::<Puzzle>(2022, 18);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Cube>;
+
+    fn parse(input: &str) -> Result<Vec<Cube>, ParseError> {
+        lines(input, str::parse)
+    }
+
+    fn part_one(input: &Vec<Cube>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Vec<Cube>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(cubes: &[Cube]) -> usize {
+    let mut count: usize = 0;
+
+    let mut cubes_sorted_for_x: Vec<Cube> = cubes.to_vec();
+    cubes_sorted_for_x.sort_by_key(|cube| (cube.y, cube.z, cube.x));
+    for (_, group) in &cubes_sorted_for_x
+        .into_iter()
+        .chunk_by(|cube| (cube.y, cube.z))
+    {
+        let xs: Vec<u8> = group.into_iter().map(|cube| cube.x).collect();
+        count += count_consecutive(xs);
+    }
+
+    let mut cubes_sorted_for_y: Vec<Cube> = cubes.to_vec();
+    cubes_sorted_for_y.sort_by_key(|cube| (cube.x, cube.z, cube.y));
+    for (_, group) in &cubes_sorted_for_y
+        .into_iter()
+        .chunk_by(|cube| (cube.x, cube.z))
+    {
+        let ys: Vec<u8> = group.into_iter().map(|cube| cube.y).collect();
+        count += count_consecutive(ys);
+    }
+
+    let mut cubes_sorted_for_z: Vec<Cube> = cubes.to_vec();
+    cubes_sorted_for_z.sort_by_key(|cube| (cube.x, cube.y, cube.z));
+    for (_, group) in &cubes_sorted_for_z
+        .into_iter()
+        .chunk_by(|cube| (cube.x, cube.y))
+    {
+        let zs: Vec<u8> = group.into_iter().map(|cube| cube.z).collect();
+        count += count_consecutive(zs);
+    }
+
+    6 * cubes.len() - 2 * count
+}
+
+fn part_two(_cubes: &[Cube]) -> usize {
+    0
+}
+
+fn count_consecutive(vals: Vec<u8>) -> usize {
+    let mut curr: Option<u8> = None;
+    let mut count: usize = 0;
+
+    for v in vals {
+        if let Some(x) = curr {
+            if v == x + 1 {
+                count += 1;
+            }
+        }
+
+        curr = Some(v)
+    }
+
+    count
+}
+
+#[derive(Clone)]
+pub struct Cube {
+    x: u8,
+    y: u8,
+    z: u8,
+}
+
+impl FromStr for Cube {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<u8> = s.split(',').map(number).collect::<Result<_, _>>()?;
+
+        match parts[..] {
+            [x, y, z] => Ok(Cube { x, y, z }),
+            _ => Err(ParseError::expected("three coordinates", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let cubes: Vec<Cube> = lines(EXAMPLE, str::parse).unwrap();
+        assert_eq!(part_one(&cubes), 64);
+        assert_eq!(part_two(&cubes), 0);
+    }
+}
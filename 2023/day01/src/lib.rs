@@ -0,0 +1,77 @@
+use aoc_parse::{each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        lines(input, |line| {
+            each_char(line, "a letter or a digit", |c| {
+                (c.is_ascii_lowercase() || c.is_ascii_digit()).then_some(c)
+            })?;
+            Ok(line)
+        })
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Answer {
+        calibration_sum(lines).into()
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Answer {
+        calibration_sum_words(lines).into()
+    }
+}
+
+fn calibration_sum(lines: &[&str]) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            let digits: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
+            10 * digits.first().unwrap() + digits.last().unwrap()
+        })
+        .sum()
+}
+
+fn calibration_sum_words(lines: &[&str]) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            // Replace the words, but we need to be careful of possible overlaps so we leave some
+            // additional letters in play.
+            let line = line
+                .replace("one", "o1e") // the o can be the end of two, and the e can start eight
+                .replace("two", "t2o") // the t can end eight and the o can start one
+                .replace("three", "t3e") // the t can end eight and the e can start eight
+                .replace("four", "4") // nothing finishes with f or starts with r
+                .replace("five", "5e") // nothing finishes with f byt e starts eight
+                .replace("six", "6") // nothing finishes with s or starts with x
+                .replace("seven", "7n") // nothing finishes with s, but nine starts with n
+                .replace("eight", "e8t") // nine ends with e and two/three start with t
+                .replace("nine", "n9e"); // seven ends in n, and eight starts with e
+
+            let digits: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
+            10 * digits.first().unwrap() + digits.last().unwrap()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(calibration_sum(&Puzzle::parse(EXAMPLE).unwrap()), 142);
+        assert_eq!(
+            calibration_sum_words(&Puzzle::parse(EXAMPLE2).unwrap()),
+            281
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_parse::combinators::{complete, int, list, word, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        lines(input, complete(Game::parse))
+    }
+
+    fn part_one(games: &Vec<Game>) -> Answer {
+        solve_possible(games).into()
+    }
+
+    fn part_two(games: &Vec<Game>) -> Answer {
+        solve_power(games).into()
+    }
+}
+
+/// How many cubes of each colour are in the bag in part one.
+const LIMITS: [usize; 3] = [12, 13, 14];
+
+fn solve_possible(games: &[Game]) -> usize {
+    games.iter().filter_map(Game::possible).sum()
+}
+
+fn solve_power(games: &[Game]) -> usize {
+    games.iter().map(Game::power).sum()
+}
+
+pub struct Game {
+    id: usize,
+    /// The most cubes that were ever shown of each colour, as red, green
+    /// and blue.
+    max: [usize; 3],
+}
+
+impl Game {
+    /// Parses a game like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+    fn parse(line: &str) -> Parsed<'_, Game> {
+        let (id, s) = ("Game ", int).read(line)?;
+        let (_, mut s) = ":".read(s)?;
+
+        let mut max = [0; 3];
+        loop {
+            let (cubes, rest) = list(cubes).read(s)?;
+            for (n, colour) in cubes {
+                max[colour] = max[colour].max(n);
+            }
+
+            match ";".read(rest) {
+                Ok((_, rest)) => s = rest,
+                Err(_) => return Ok((Game { id, max }, rest)),
+            }
+        }
+    }
+
+    fn possible(&self) -> Option<usize> {
+        let possible = self.max.iter().zip(LIMITS).all(|(&n, max)| n <= max);
+        possible.then_some(self.id)
+    }
+
+    fn power(&self) -> usize {
+        self.max.iter().product()
+    }
+}
+
+/// Some cubes of one colour, like `3 blue`, with the colour as an index.
+fn cubes(s: &str) -> Parsed<'_, (usize, usize)> {
+    let (n, s) = int(s)?;
+    let (colour, s) = (" ", word).read(s)?;
+    match colour {
+        "red" => Ok(((n, 0), s)),
+        "green" => Ok(((n, 1), s)),
+        "blue" => Ok(((n, 2), s)),
+        _ => Err(ParseError::expected("red, green or blue", colour)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let games = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve_possible(&games), 8);
+        assert_eq!(solve_power(&games), 2286);
+    }
+}
@@ -0,0 +1,185 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one((parts, symbols): &Schematic) -> Answer {
+        part_number_sum(parts, symbols).into()
+    }
+
+    fn part_two((parts, symbols): &Schematic) -> Answer {
+        gear_sum(parts, symbols).into()
+    }
+}
+
+/// The part numbers and the symbols on each line of the engine schematic.
+type Schematic = (Vec<Vec<Part>>, Vec<Vec<Symbol>>);
+
+fn part_number_sum(parts: &[Vec<Part>], symbols: &[Vec<Symbol>]) -> usize {
+    parts
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line_parts)| {
+            line_parts.iter().filter_map(move |p| {
+                if y > 0
+                    && symbols
+                        .get(y - 1)
+                        .unwrap()
+                        .iter()
+                        .any(|s| s.x + 1 >= p.x0 && s.x <= p.x1 + 1)
+                {
+                    return Some(p.num);
+                }
+
+                if symbols
+                    .get(y)
+                    .unwrap()
+                    .iter()
+                    .any(|s| (p.x0 > 0 && s.x == p.x0 - 1) || s.x == p.x1 + 1)
+                {
+                    return Some(p.num);
+                }
+
+                if let Some(next_row_symbols) = symbols.get(y + 1) {
+                    if next_row_symbols
+                        .iter()
+                        .any(|s| s.x + 1 >= p.x0 && s.x <= p.x1 + 1)
+                    {
+                        return Some(p.num);
+                    }
+                }
+
+                None
+            })
+        })
+        .sum()
+}
+
+fn gear_sum(parts: &[Vec<Part>], symbols: &[Vec<Symbol>]) -> usize {
+    symbols
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line_symbols)| {
+            line_symbols
+                .iter()
+                .filter(|s| s.c == '*')
+                .filter_map(move |s| {
+                    let mut adjacent: Vec<usize> = Vec::new();
+                    let x0 = if s.x > 0 { s.x - 1 } else { 0 };
+
+                    // Row before
+                    if y > 0 {
+                        for p in parts.get(y - 1).unwrap() {
+                            if p.x0 <= s.x + 1 && p.x1 >= x0 {
+                                adjacent.push(p.num);
+                            }
+                        }
+                    }
+
+                    // Left and right
+                    for p in parts.get(y).unwrap() {
+                        if p.x1 == x0 || p.x0 == s.x + 1 {
+                            adjacent.push(p.num);
+                        }
+                    }
+
+                    // Row below
+                    if let Some(next_row_parts) = parts.get(y + 1) {
+                        for p in next_row_parts {
+                            if p.x0 <= s.x + 1 && p.x1 >= x0 {
+                                adjacent.push(p.num);
+                            }
+                        }
+                    }
+
+                    if adjacent.len() == 2 {
+                        return Some(adjacent.iter().product::<usize>());
+                    }
+                    None
+                })
+        })
+        .sum()
+}
+
+pub struct Part {
+    num: usize,
+    x0: usize,
+    x1: usize,
+}
+
+pub struct Symbol {
+    c: char,
+    x: usize,
+}
+
+fn parse_input(input: &str) -> Schematic {
+    let mut all_parts: Vec<Vec<Part>> = Vec::new();
+    let mut all_symbols: Vec<Vec<Symbol>> = Vec::new();
+
+    for line in input.lines() {
+        let mut parts: Vec<Part> = Vec::new();
+        let mut symbols: Vec<Symbol> = Vec::new();
+        let mut num = 0;
+        let mut start = 0;
+
+        for (x, c) in line.chars().enumerate() {
+            if c.is_ascii_digit() {
+                if num == 0 {
+                    start = x;
+                }
+                num = 10 * num + c.to_digit(10).unwrap();
+                continue;
+            }
+
+            if num > 0 {
+                parts.push(Part {
+                    num: num as usize,
+                    x0: start,
+                    x1: x - 1,
+                });
+                num = 0;
+            }
+
+            if c == '.' {
+                continue;
+            }
+
+            symbols.push(Symbol { x, c });
+        }
+
+        if num > 0 {
+            parts.push(Part {
+                num: num as usize,
+                x0: start,
+                x1: line.len() - 1,
+            });
+        }
+
+        all_parts.push(parts);
+        all_symbols.push(symbols);
+    }
+
+    (all_parts, all_symbols)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (parts, symbols) = parse_input(EXAMPLE);
+        assert_eq!(part_number_sum(&parts, &symbols), 4361);
+        assert_eq!(gear_sum(&parts, &symbols), 467835);
+    }
+}
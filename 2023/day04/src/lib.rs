@@ -0,0 +1,96 @@
+use aoc_parse::{lines, number, split_once, strip_prefix};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        lines(input, Card::parse)
+    }
+
+    fn part_one(cards: &Vec<Card>) -> Answer {
+        part_one(cards).into()
+    }
+
+    fn part_two(cards: &Vec<Card>) -> Answer {
+        part_two(cards).into()
+    }
+}
+
+fn part_one(cards: &[Card]) -> usize {
+    cards.iter().map(|card| card.score()).sum()
+}
+
+fn part_two(cards: &[Card]) -> usize {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    let mut total = 0;
+
+    for card in cards {
+        let score = card.matches();
+        let copies = 1 + counts.get(&card.num).unwrap_or(&0);
+        total += copies;
+
+        for i in card.num + 1..=card.num + score {
+            if let Some(x) = counts.get_mut(&i) {
+                *x += copies;
+            } else {
+                counts.insert(i, copies);
+            }
+        }
+    }
+
+    total
+}
+
+pub struct Card {
+    num: usize,
+    winners: HashSet<u32>,
+    candidates: HashSet<u32>,
+}
+
+impl Card {
+    /// Reads a card like `Card 1: 41 48 83 | 83 86  6 31`.
+    fn parse(s: &str) -> Result<Card, ParseError> {
+        let (game, nums) = split_once(s, ":")?;
+        let (winners, candidates) = split_once(nums, "|")?;
+        let numbers = |s: &str| s.split_whitespace().map(number).collect::<Result<_, _>>();
+
+        Ok(Card {
+            num: number(strip_prefix(game, "Card")?.trim_start())?,
+            winners: numbers(winners)?,
+            candidates: numbers(candidates)?,
+        })
+    }
+
+    fn score(&self) -> usize {
+        let m = self.matches();
+        if m == 0 {
+            0
+        } else {
+            1 << (m - 1)
+        }
+    }
+
+    fn matches(&self) -> usize {
+        self.winners.intersection(&self.candidates).count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let cards = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&cards), 13);
+        assert_eq!(part_two(&cards), 30);
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_parse::combinators::{complete, int, labelled, spaced};
+use aoc_parse::{line, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The time each race lasts, and the distance to beat in it.
+    type Input<'a> = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+        let (times, distances) = split_once(input, "\n")?;
+        let times = line(times, complete(labelled("Time", spaced(int))))?;
+        let distances = line(distances, complete(labelled("Distance", spaced(int))))
+            .map_err(|err| err.within(2, distances))?;
+
+        if times.len() != distances.len() {
+            return Err(ParseError::new(format!(
+                "expected a distance for each of the {} races, but there are {}",
+                times.len(),
+                distances.len()
+            )));
+        }
+
+        Ok((times, distances))
+    }
+
+    fn part_one((times, distances): &(Vec<u64>, Vec<u64>)) -> Answer {
+        solve_pt1(times, distances).into()
+    }
+
+    fn part_two((times, distances): &(Vec<u64>, Vec<u64>)) -> Answer {
+        solve_pt2(times, distances).into()
+    }
+}
+
+fn solve_pt1(times: &[u64], distances: &[u64]) -> u64 {
+    times
+        .iter()
+        .zip(distances)
+        .map(|(&t, &d)| num_ways(t, d))
+        .product()
+}
+
+/// There's really only one race, with the spaces between its numbers left
+/// out.
+fn solve_pt2(times: &[u64], distances: &[u64]) -> u64 {
+    num_ways(join_digits(times), join_digits(distances))
+}
+
+fn join_digits(nums: &[u64]) -> u64 {
+    nums.iter()
+        .fold(0, |acc, &n| acc * 10u64.pow(n.to_string().len() as u32) + n)
+}
+
+fn num_ways(t: u64, d: u64) -> u64 {
+    // If we hold the boat for x seconds, then it's speed is x and
+    // the time it travels is (t-x), so we're solving the equation
+    // x(t-x) > d.
+    // Rearranging, we get: n^2 - tx + d < 0
+    // This is a standard quadratic so solution is:
+    //   t ± √t^2 - 4d
+    //   -------------
+    //        2
+    let det = t * t - 4 * d;
+    let sqrt = (det as f64).sqrt();
+
+    let low: u64 = unsafe { ((t as f64 - sqrt) / 2.0).ceil().to_int_unchecked() };
+    let high: u64 = unsafe { ((t as f64 + sqrt) / 2.0).floor().to_int_unchecked() };
+
+    // In the case of an exact solution, we only equal the target not beat it when x
+    // is high or low, so we can't include those end two points.
+    if low * (t - low) == d {
+        return high - low - 1;
+    }
+
+    // Otherwise we subtract to find the difference - +1 to make it inclusive of low.
+    high - low + 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (times, distances) = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve_pt1(&times, &distances), 288);
+        assert_eq!(solve_pt2(&times, &distances), 71503);
+    }
+}
@@ -0,0 +1,122 @@
+use aoc_parse::{line, lines, split_once, strip_prefix, strip_suffix};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Network;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(network: &Network) -> Answer {
+        solve(network, "AAA").into()
+    }
+
+    fn part_two(network: &Network) -> Answer {
+        solve_many(network).into()
+    }
+}
+
+/// The left/right instructions, and the nodes each node leads to.
+type Network = (Vec<Instruction>, HashMap<String, (String, String)>);
+
+#[derive(Debug)]
+pub enum Instruction {
+    LEFT,
+    RIGHT,
+}
+
+fn solve((instructions, maps): &Network, start: &str) -> usize {
+    use Instruction::*;
+
+    let mut pos = start.to_owned();
+    for (i, instruction) in instructions.iter().cycle().enumerate() {
+        if pos.ends_with("Z") {
+            return i;
+        }
+
+        let map = maps.get(&pos).unwrap();
+        match instruction {
+            LEFT => pos = map.0.clone(),
+            RIGHT => pos = map.1.clone(),
+        }
+    }
+
+    0
+}
+
+fn solve_many(network: &Network) -> usize {
+    let (_, maps) = network;
+
+    // This is not a great general solution, it's only due to the fact that the cycles are
+    // very simple and just repeat every n instructions that we can get away with just finding
+    // the lowest common multiple of all cycle lengths.
+    // If there had been offsets, or cycles of varying lengths this would be a lot harder.
+    maps.keys()
+        .filter_map(|key| {
+            if key.ends_with("A") {
+                Some(key.to_owned())
+            } else {
+                None
+            }
+        })
+        .map(|key| solve(network, &key))
+        .reduce(|acc, x| (acc * x) / gcd(acc, x))
+        .unwrap()
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    gcd(b, a % b)
+}
+
+fn parse_input(s: &str) -> Result<Network, ParseError> {
+    let (instructions, maps) = split_once(s, "\n\n")?;
+
+    let instructions = line(instructions, |line| {
+        line.chars()
+            .enumerate()
+            .map(|(x, c)| match c {
+                'L' => Ok(Instruction::LEFT),
+                'R' => Ok(Instruction::RIGHT),
+                _ => Err(ParseError::expected("'L' or 'R'", &c.to_string()).at(1, x + 1)),
+            })
+            .collect()
+    })?;
+
+    let maps = lines(maps, |line| {
+        let (src, dst) = split_once(line, " = ")?;
+        let dst = strip_suffix(strip_prefix(dst, "(")?, ")")?;
+        let (left, right) = split_once(dst, ", ")?;
+        Ok((
+            src.trim().to_owned(),
+            (left.trim().to_owned(), right.trim().to_owned()),
+        ))
+    })
+    .map_err(|err| err.within(3, maps))?;
+
+    Ok((instructions, maps.into_iter().collect()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(&parse_input(EXAMPLE).unwrap(), "AAA"), 2);
+        assert_eq!(solve(&parse_input(EXAMPLE2).unwrap(), "AAA"), 6);
+        assert_eq!(solve_many(&parse_input(EXAMPLE3).unwrap()), 6);
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 9);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        lines(input, |line| line.split(' ').map(number).collect())
+    }
+
+    fn part_one(histories: &Vec<Vec<i64>>) -> Answer {
+        solve(histories).1.into()
+    }
+
+    fn part_two(histories: &Vec<Vec<i64>>) -> Answer {
+        solve(histories).0.into()
+    }
+}
+
+fn solve(histories: &[Vec<i64>]) -> (i64, i64) {
+    histories
+        .iter()
+        .map(|history| diff(history.clone()))
+        .fold((0, 0), |acc, x| (acc.0 + x.0, acc.1 + x.1))
+}
+
+fn diff(input: Vec<i64>) -> (i64, i64) {
+    if input.iter().all(|n| n == &0) {
+        return (0, 0);
+    }
+
+    let mut deltas: Vec<i64> = Vec::new();
+    for i in 0..input.len() - 1 {
+        deltas.push(input.get(i + 1).unwrap() - input.get(i).unwrap());
+    }
+
+    let (prev, next) = diff(deltas);
+    (input.first().unwrap() - prev, input.last().unwrap() + next)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (prev, next) = solve(&Puzzle::parse(EXAMPLE).unwrap());
+        assert_eq!(next, 114);
+        assert_eq!(prev, 2);
+    }
+}
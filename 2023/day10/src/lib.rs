@@ -0,0 +1,212 @@
+use aoc_grid::{Grid, Pos};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 10);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Pipes;
+
+    fn parse(input: &str) -> Result<Pipes, ParseError> {
+        Pipes::new(input)
+    }
+
+    fn part_one(pipes: &Pipes) -> Answer {
+        pipes.furthest_distance().into()
+    }
+
+    fn part_two(pipes: &Pipes) -> Answer {
+        pipes.internal_area().into()
+    }
+}
+
+type Loc = Pos;
+
+/// The pipes that make up the loop. Everything else is ground.
+pub struct Pipes {
+    loop_pipes: Grid<Pipe>,
+}
+
+impl Pipes {
+    fn new(input: &str) -> Result<Pipes, ParseError> {
+        let (pipes, start) = parse_input(input)?;
+
+        let mut loc = start;
+        let mut prev = (0, 0);
+
+        let mut loop_pipes = Grid::new(pipes.width(), pipes.height(), Pipe::Ground);
+
+        loop {
+            let pipe = pipes[loc];
+            let (a, b) = pipe.adjacent(loc);
+            if prev == a {
+                prev = loc;
+                loc = b;
+            } else {
+                prev = loc;
+                loc = a;
+            }
+
+            loop_pipes[prev] = pipe;
+            if loc == start {
+                break;
+            }
+        }
+
+        Ok(Pipes { loop_pipes })
+    }
+
+    fn furthest_distance(&self) -> usize {
+        self.loop_pipes
+            .values()
+            .filter(|&&p| p != Pipe::Ground)
+            .count()
+            / 2
+    }
+
+    fn internal_area(&self) -> usize {
+        use Pipe::*;
+
+        let mut inside = 0;
+        for row in self.loop_pipes.rows() {
+            let mut boundaries_crossed = 0;
+            let mut n = 0;
+            let mut s = 0;
+
+            for p in row {
+                // We're working from left to right, and calculating how
+                // many boundaries each would need to cross to get to the
+                // outside.
+                // A | is a boundary all by itself.
+                // The others need both a north and south component, so we
+                // keep track of how many north and south there has been.
+                match p {
+                    V => boundaries_crossed += 1,
+                    NE | NW => n += 1,
+                    SE | SW => s += 1,
+                    Ground => {
+                        let boundaries = boundaries_crossed + if n > s { s } else { n };
+                        if boundaries % 2 == 1 {
+                            inside += 1;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        inside
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Pipe {
+    H,
+    V,
+    NE,
+    NW,
+    SE,
+    SW,
+    Start,
+    Ground,
+}
+
+impl Pipe {
+    fn adjacent(&self, loc: Loc) -> (Loc, Loc) {
+        use Pipe::*;
+        match self {
+            H => ((loc.0 - 1, loc.1), (loc.0 + 1, loc.1)),
+            V => ((loc.0, loc.1 - 1), (loc.0, loc.1 + 1)),
+            NE => ((loc.0 + 1, loc.1), (loc.0, loc.1 - 1)),
+            NW => ((loc.0 - 1, loc.1), (loc.0, loc.1 - 1)),
+            SE => ((loc.0 + 1, loc.1), (loc.0, loc.1 + 1)),
+            SW => ((loc.0 - 1, loc.1), (loc.0, loc.1 + 1)),
+            Start => panic!("Start not used"),
+            Ground => panic!("Ground not used"),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Grid<Pipe>, Loc), ParseError> {
+    use Pipe::*;
+
+    let mut pipes = Grid::try_parse(input, |c| match c {
+        '|' => Ok(V),
+        '-' => Ok(H),
+        'L' => Ok(NE),
+        'J' => Ok(NW),
+        '7' => Ok(SW),
+        'F' => Ok(SE),
+        'S' => Ok(Start),
+        '.' => Ok(Ground),
+        _ => Err(ParseError::expected("a pipe or '.'", &c.to_string())),
+    })?;
+
+    let start = pipes
+        .find(|&p| p == Start)
+        .ok_or_else(|| ParseError::new("there's no start position 'S'"))?;
+
+    // We need to work out what the start should have been, so we examine the four
+    // adjacent pipes and work out which are pointing towards the start.
+    let adjacent = |delta| pipes.offset(start, delta).map_or(Ground, |loc| pipes[loc]);
+
+    let north = adjacent((0, -1));
+    let north = north == V || north == SE || north == SW;
+
+    let east = adjacent((1, 0));
+    let east = east == H || east == NW || east == SW;
+
+    let south = adjacent((0, 1));
+    let south = south == V || south == NE || south == NW;
+
+    let west = adjacent((-1, 0));
+    let west = west == H || west == NE || west == SE;
+
+    let start_pipe = if north && east {
+        NE
+    } else if north && south {
+        V
+    } else if north && west {
+        NW
+    } else if east && south {
+        SE
+    } else if east && west {
+        H
+    } else if south && west {
+        SW
+    } else {
+        return Err(
+            ParseError::new("can't identify which way the start pipe goes")
+                .at(start.1 + 1, start.0 + 1),
+        );
+    };
+    pipes[start] = start_pipe;
+
+    Ok((pipes, start))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PART1_EXAMPLE1: &str = include_str!("../pt1_example1.txt");
+    const PART1_EXAMPLE2: &str = include_str!("../pt1_example2.txt");
+
+    #[test]
+    fn part_one() {
+        assert_eq!(Pipes::new(PART1_EXAMPLE1).unwrap().furthest_distance(), 4);
+        assert_eq!(Pipes::new(PART1_EXAMPLE2).unwrap().furthest_distance(), 8);
+    }
+
+    const PART2_EXAMPLE1: &str = include_str!("../pt2_example1.txt");
+    const PART2_EXAMPLE2: &str = include_str!("../pt2_example2.txt");
+    const PART2_EXAMPLE3: &str = include_str!("../pt2_example3.txt");
+
+    #[test]
+    fn part_two() {
+        assert_eq!(Pipes::new(PART2_EXAMPLE1).unwrap().internal_area(), 4);
+        assert_eq!(Pipes::new(PART2_EXAMPLE2).unwrap().internal_area(), 8);
+        assert_eq!(Pipes::new(PART2_EXAMPLE3).unwrap().internal_area(), 10);
+    }
+}
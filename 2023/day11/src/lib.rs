@@ -0,0 +1,83 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 11);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(galaxies: &Vec<(usize, usize)>) -> Answer {
+        min_path_length(galaxies, 2).into()
+    }
+
+    fn part_two(galaxies: &Vec<(usize, usize)>) -> Answer {
+        min_path_length(galaxies, 1000000).into()
+    }
+}
+
+fn min_path_length(galaxies: &[(usize, usize)], expansion: u64) -> u64 {
+    let populated_columns: HashSet<usize> = galaxies.iter().map(|(x, _)| *x).collect();
+    let populated_rows: HashSet<usize> = galaxies.iter().map(|(_, y)| *y).collect();
+
+    let mut total_dist = 0;
+
+    for i in 0..galaxies.len() - 1 {
+        for j in i + 1..galaxies.len() {
+            let a = galaxies.get(i).unwrap();
+            let b = galaxies.get(j).unwrap();
+
+            let columns_traversed = if a.0 > b.0 { (b.0, a.0) } else { (a.0, b.0) };
+            for c in columns_traversed.0..columns_traversed.1 {
+                if populated_columns.contains(&c) {
+                    total_dist += 1;
+                } else {
+                    total_dist += expansion;
+                }
+            }
+
+            let rows_traversed = if a.1 > b.1 { (b.1, a.1) } else { (a.1, b.1) };
+            for r in rows_traversed.0..rows_traversed.1 {
+                if populated_rows.contains(&r) {
+                    total_dist += 1;
+                } else {
+                    total_dist += expansion;
+                }
+            }
+        }
+    }
+
+    total_dist
+}
+
+fn parse_input(input: &str) -> Vec<(usize, usize)> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter_map(move |(x, c)| if c == '#' { Some((x, y)) } else { None })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let galaxies = parse_input(EXAMPLE);
+        assert_eq!(min_path_length(&galaxies, 2), 374);
+        assert_eq!(min_path_length(&galaxies, 10), 1030);
+        assert_eq!(min_path_length(&galaxies, 100), 8410);
+    }
+}
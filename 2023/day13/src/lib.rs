@@ -0,0 +1,140 @@
+use aoc_parse::{blocks, each_char, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 13);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The rows of each pattern of ash and rocks.
+    type Input<'a> = Vec<Vec<&'a str>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+        blocks(input, |pattern| lines(pattern, parse_row))
+    }
+
+    fn part_one(patterns: &Vec<Vec<&str>>) -> Answer {
+        solve(patterns, 0).into()
+    }
+
+    fn part_two(patterns: &Vec<Vec<&str>>) -> Answer {
+        solve(patterns, 1).into()
+    }
+}
+
+fn parse_row(row: &str) -> Result<&str, ParseError> {
+    each_char(row, "'#' or '.'", |c| matches!(c, '#' | '.').then_some(c))?;
+    Ok(row)
+}
+
+fn solve(patterns: &[Vec<&str>], diff_allowed: usize) -> usize {
+    patterns
+        .iter()
+        .enumerate()
+        .filter_map(|(i, pattern)| {
+            let ans = solve_pattern(pattern, diff_allowed);
+            if ans.is_none() {
+                eprintln!("Pattern {} has no solution", i + 1);
+                eprintln!("{}", pattern.join("\n"));
+                eprintln!("--\n{}\n", transpose(pattern).join("\n"));
+            }
+            ans
+        })
+        .sum()
+}
+
+fn solve_pattern(pattern: &[&str], diff_allowed: usize) -> Option<usize> {
+    if let Some(rows) = find_mirror_line(pattern, diff_allowed) {
+        return Some(100 * rows);
+    }
+
+    let pattern = transpose(pattern);
+    let pattern: Vec<&str> = pattern.iter().map(String::as_str).collect();
+    if let Some(columns) = find_mirror_line(&pattern, diff_allowed) {
+        return Some(columns);
+    }
+
+    None
+}
+
+fn find_mirror_line(lines: &[&str], diff_allowed: usize) -> Option<usize> {
+    'outer: for i in 0..lines.len() - 1 {
+        let mut total_diff = 0;
+
+        let mut d = 0;
+        loop {
+            if d > i || d + i + 1 >= lines.len() {
+                if total_diff == diff_allowed {
+                    return Some(i + 1);
+                }
+                continue 'outer;
+            }
+
+            total_diff += diff(lines.get(i - d).unwrap(), lines.get(i + d + 1).unwrap());
+            if total_diff > diff_allowed {
+                continue 'outer;
+            }
+
+            d += 1;
+        }
+    }
+
+    None
+}
+
+fn transpose(pattern: &[&str]) -> Vec<String> {
+    let mut output: Vec<String> = Vec::new();
+
+    for line in pattern {
+        for (i, c) in line.chars().enumerate() {
+            if let Some(v) = output.get_mut(i) {
+                v.push(c);
+            } else {
+                output.push(String::from(c));
+            }
+        }
+    }
+
+    output
+}
+
+fn diff(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).filter(|(a, b)| a != b).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_pt1() {
+        let patterns = Puzzle::parse(EXAMPLE).unwrap();
+
+        assert_eq!(solve_pattern(&patterns[0], 0), Some(5));
+        assert_eq!(solve_pattern(&patterns[1], 0), Some(400));
+    }
+
+    #[test]
+    fn example_pt2() {
+        let patterns = Puzzle::parse(EXAMPLE).unwrap();
+
+        assert_eq!(solve_pattern(&patterns[0], 1), Some(300));
+        assert_eq!(solve_pattern(&patterns[1], 1), Some(100));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let original = ["123", "456", "789"];
+        let exp = ["147", "258", "369"];
+        assert_eq!(transpose(&original), exp);
+    }
+
+    #[test]
+    fn test_diff() {
+        let a = "123456789";
+        let b = "123!5678!";
+        assert_eq!(diff(a, b), 2);
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_parse::combinators::{complete, either, int, map, word, Parsed};
+use aoc_parse::line;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 15);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(&'a str, Step<'a>)>;
+
+    fn parse(input: &str) -> Result<Vec<(&str, Step<'_>)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(steps: &Vec<(&str, Step<'_>)>) -> Answer {
+        solve_pt1(steps).into()
+    }
+
+    fn part_two(steps: &Vec<(&str, Step<'_>)>) -> Answer {
+        solve_pt2(steps).into()
+    }
+}
+
+/// A step of the initialization sequence, like `rn=1` or `cm-`.
+pub enum Step<'a> {
+    Insert(&'a str, usize),
+    Remove(&'a str),
+}
+
+impl Step<'_> {
+    fn parse(s: &str) -> Parsed<'_, Step<'_>> {
+        let (label, s) = word(s)?;
+        let insert = map(("=", int), |focal_length| Step::Insert(label, focal_length));
+        let remove = map("-", |_| Step::Remove(label));
+        either(insert, remove)(s)
+    }
+}
+
+/// The steps, each with the text it was written as, which is what's
+/// hashed in part one.
+fn parse_input(input: &str) -> Result<Vec<(&str, Step<'_>)>, ParseError> {
+    line(input, |line| {
+        line.split(',')
+            .map(|step| Ok((step, complete(Step::parse)(step)?)))
+            .collect()
+    })
+}
+
+fn solve_pt1(steps: &[(&str, Step<'_>)]) -> usize {
+    steps.iter().map(|(step, _)| hash(step)).sum()
+}
+
+fn solve_pt2(steps: &[(&str, Step<'_>)]) -> usize {
+    let mut boxes: Vec<Vec<(String, usize)>> = Vec::with_capacity(256);
+    for _ in 0..256 {
+        boxes.push(Vec::new());
+    }
+
+    for (_, step) in steps {
+        let label = match step {
+            Step::Insert(label, _) | Step::Remove(label) => *label,
+        };
+        let box_num = hash(label);
+
+        let b = boxes.get_mut(box_num).unwrap();
+        if let Step::Insert(_, num) = *step {
+            let mut found = false;
+            for lens in b.iter_mut() {
+                if lens.0 == label {
+                    lens.1 = num;
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                b.push((label.to_string(), num));
+            }
+
+            continue;
+        }
+
+        if let Some(i) = b
+            .iter()
+            .enumerate()
+            .find(|(_, lens)| lens.0 == label)
+            .map(|(i, _)| i)
+        {
+            b.remove(i);
+        }
+    }
+
+    let mut total = 0;
+    for (i, b) in boxes.iter().enumerate() {
+        for (j, lens) in b.iter().enumerate() {
+            total += (i + 1) * (j + 1) * lens.1;
+        }
+    }
+
+    total
+}
+
+fn hash(s: &str) -> usize {
+    s.chars().fold(0, |acc, x| ((acc + x as usize) * 17) % 256)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        let steps = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_pt1(&steps), 1320);
+        assert_eq!(solve_pt2(&steps), 145);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("cm-"), 253);
+        assert_eq!(hash("qp=3"), 97);
+        assert_eq!(hash("cm=2"), 47);
+        assert_eq!(hash("qp-"), 14);
+        assert_eq!(hash("pc=4"), 180);
+        assert_eq!(hash("ot=9"), 9);
+        assert_eq!(hash("ab=5"), 197);
+        assert_eq!(hash("pc-"), 48);
+        assert_eq!(hash("pc=6"), 214);
+        assert_eq!(hash("ot=7"), 231);
+    }
+}
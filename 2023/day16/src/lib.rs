@@ -0,0 +1,186 @@
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 16);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        count_energised(grid, 0, 0, Dir::E).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        max_energised(grid).into()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Dir {
+    N,
+    E,
+    S,
+    W,
+}
+
+fn max_energised(grid: &Grid) -> usize {
+    use Dir::*;
+
+    let mut highest = 0;
+    for x in 0..grid.width {
+        highest = highest.max(count_energised(grid, x, 0, S));
+        highest = highest.max(count_energised(grid, x, grid.height - 1, N));
+    }
+    for y in 0..grid.height {
+        highest = highest.max(count_energised(grid, 0, y, E));
+        highest = highest.max(count_energised(grid, grid.width - 1, y, W));
+    }
+    highest
+}
+
+fn count_energised(grid: &Grid, start_x: usize, start_y: usize, start_dir: Dir) -> usize {
+    use Dir::*;
+
+    let mut energised: HashSet<(usize, usize, Dir)> = HashSet::new();
+    let mut current: HashSet<(usize, usize, Dir)> = HashSet::new();
+
+    for dir in grid.next_dir(start_x, start_y, start_dir) {
+        current.insert((start_x, start_y, dir));
+    }
+
+    loop {
+        if current.is_empty() {
+            break;
+        }
+
+        let mut next_steps: HashSet<(usize, usize, Dir)> = HashSet::new();
+
+        for (x, y, dir) in current {
+            if energised.contains(&(x, y, dir)) {
+                continue;
+            }
+            energised.insert((x, y, dir));
+
+            // Check that we can move first
+            if x == 0 && dir == W {
+                continue;
+            }
+            if x >= grid.width - 1 && dir == E {
+                continue;
+            }
+            if y == 0 && dir == N {
+                continue;
+            }
+            if y >= grid.height - 1 && dir == S {
+                continue;
+            }
+
+            // Then move
+            let (x, y) = match dir {
+                N => (x, y - 1),
+                E => (x + 1, y),
+                S => (x, y + 1),
+                W => (x - 1, y),
+            };
+            for dir in grid.next_dir(x, y, dir) {
+                next_steps.insert((x, y, dir));
+            }
+        }
+
+        current = next_steps;
+    }
+
+    energised
+        .into_iter()
+        .map(|(x, y, _)| (x, y))
+        .collect::<HashSet<(usize, usize)>>()
+        .len()
+}
+
+pub struct Grid {
+    height: usize,
+    width: usize,
+    mirrors: HashMap<(usize, usize), char>,
+}
+
+impl Grid {
+    fn next_dir(&self, x: usize, y: usize, dir: Dir) -> Vec<Dir> {
+        use Dir::*;
+
+        if let Some(mirror) = self.mirrors.get(&(x, y)) {
+            match mirror {
+                '|' => {
+                    if dir == W || dir == E {
+                        return vec![N, S];
+                    } else {
+                        return vec![dir];
+                    };
+                }
+                '-' => {
+                    if dir == N || dir == S {
+                        return vec![E, W];
+                    } else {
+                        return vec![dir];
+                    };
+                }
+                '/' => {
+                    match dir {
+                        N => return vec![E],
+                        E => return vec![N],
+                        S => return vec![W],
+                        W => return vec![S],
+                    };
+                }
+                '\\' => {
+                    match dir {
+                        N => return vec![W],
+                        E => return vec![S],
+                        S => return vec![E],
+                        W => return vec![N],
+                    };
+                }
+                _ => panic!("Unknown mirror '{}'", mirror),
+            }
+        }
+
+        vec![dir]
+    }
+}
+
+fn parse_input(s: &str) -> Result<Grid, ParseError> {
+    let rows = lines(s, |line| {
+        line.chars()
+            .enumerate()
+            .filter(|&(_, c)| c != '.')
+            .map(|(x, c)| match c {
+                '/' | '\\' | '|' | '-' => Ok((x, c)),
+                _ => Err(
+                    ParseError::expected("a mirror, a splitter or '.'", &c.to_string())
+                        .at(1, x + 1),
+                ),
+            })
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    let height = rows.len();
+    let width = s.lines().next().map_or(0, |line| line.chars().count());
+
+    let mirrors = rows
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.into_iter().map(move |(x, c)| ((x, y), c)))
+        .collect();
+
+    Ok(Grid {
+        height,
+        width,
+        mirrors,
+    })
+}
@@ -0,0 +1,100 @@
+use aoc_grid::{Grid, Pos, ORTHOGONAL};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use aoc_search::dijkstra;
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 17);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The heat lost entering each city block.
+    type Input<'a> = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+        Grid::try_parse(input, |c| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| ParseError::expected("a heat loss", &c.to_string()))
+        })
+    }
+
+    fn part_one(grid: &Grid<usize>) -> Answer {
+        solve(grid, false).into()
+    }
+
+    fn part_two(grid: &Grid<usize>) -> Answer {
+        solve(grid, true).into()
+    }
+}
+
+/// Where the crucible is, and whether it got there moving north-south (`1`)
+/// or east-west (`0`). It has to turn after every run, so that's all that
+/// matters about how it arrived. It starts out free to go either way.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Node {
+    pos: Pos,
+    axis: Option<usize>,
+}
+
+/// Every run of `min..=max` blocks the crucible can make after turning, with
+/// the heat lost along the way.
+fn crucible_moves(grid: &Grid<usize>, node: &Node, min: usize, max: usize) -> Vec<(Node, usize)> {
+    let mut moves = Vec::new();
+
+    for (dir, &delta) in ORTHOGONAL.iter().enumerate() {
+        let axis = dir % 2;
+        if node.axis == Some(axis) {
+            continue;
+        }
+
+        let mut pos = node.pos;
+        let mut heat_loss = 0;
+        for steps in 1..=max {
+            pos = match grid.offset(pos, delta) {
+                Some(pos) => pos,
+                None => break,
+            };
+            heat_loss += grid[pos];
+
+            if steps >= min {
+                let axis = Some(axis);
+                moves.push((Node { pos, axis }, heat_loss));
+            }
+        }
+    }
+
+    moves
+}
+
+fn solve(grid: &Grid<usize>, ultra: bool) -> usize {
+    let end = (grid.width() - 1, grid.height() - 1);
+    let (min, max) = if ultra { (4, 10) } else { (1, 3) };
+
+    let start = Node {
+        pos: (0, 0),
+        axis: None,
+    };
+    dijkstra(
+        start,
+        |node| crucible_moves(grid, node, min, max),
+        |node| node.pos == end,
+    )
+    .goal_cost()
+    .expect("the crucible can't reach the factory")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn example() {
+        let grid = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&grid, false), 102);
+        assert_eq!(solve(&grid, true), 94);
+        assert_eq!(solve(&Puzzle::parse(EXAMPLE2).unwrap(), true), 71);
+    }
+}
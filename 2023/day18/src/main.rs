@@ -1,10 +1,10 @@
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_str!("../input.txt");
-
 fn main() {
-    let plan = Plan::parse(INPUT);
+    let input = aoc_runner::input(2023, 18);
+
+    let plan = Plan::parse(&input);
     println!("Part one: {}", plan.interior_area());
 }
 
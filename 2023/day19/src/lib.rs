@@ -0,0 +1,462 @@
+use aoc_parse::combinators::{complete, either, field, int, word, Parsed, Parser};
+use aoc_parse::{lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 19);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((workflows, parts): &(HashMap<String, Workflow>, Vec<Part>)) -> Answer {
+        solve(workflows, parts).into()
+    }
+
+    fn part_two((workflows, _): &(HashMap<String, Workflow>, Vec<Part>)) -> Answer {
+        solve_slices(workflows).into()
+    }
+}
+
+fn solve(workflows: &HashMap<String, Workflow>, parts: &[Part]) -> usize {
+    parts
+        .iter()
+        .filter(|part| part.is_accepted(workflows))
+        .map(|part| part.sum())
+        .sum()
+}
+
+fn solve_slices(workflows: &HashMap<String, Workflow>) -> usize {
+    use Destination::*;
+
+    let slice = PartSlice {
+        x_min: 1,
+        x_max: 4000,
+        m_min: 1,
+        m_max: 4000,
+        a_min: 1,
+        a_max: 4000,
+        s_min: 1,
+        s_max: 4000,
+    };
+
+    let mut total = 0;
+
+    let mut current: Vec<(Destination, PartSlice)> =
+        vec![(Destination::Workflow(String::from("in")), slice)];
+
+    loop {
+        if let Some((dest, slice)) = current.pop() {
+            match dest {
+                Accepted => total += slice.combinations(),
+                Rejected => {}
+                Workflow(w) => {
+                    let workflow = workflows.get(&w).unwrap();
+                    current.extend_from_slice(&workflow.apply_slice(&slice));
+                }
+            }
+        } else {
+            return total;
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+    let (workflows, parts) = split_once(input, "\n\n")?;
+
+    let workflows = lines(workflows, |line| {
+        let workflow = complete(Workflow::parse)(line)?;
+        Ok((workflow.name.clone(), workflow))
+    })?;
+
+    // The parts start after the workflows and the blank line
+    let first_part = workflows.len() + 2;
+    let parts = lines(parts, complete(Part::parse)).map_err(|err| err.within(first_part, parts))?;
+
+    Ok((workflows.into_iter().collect(), parts))
+}
+
+#[derive(Clone, Debug)]
+enum Destination {
+    Accepted,
+    Rejected,
+    Workflow(String),
+}
+
+impl Destination {
+    fn parse(s: &str) -> Self {
+        use Destination::*;
+
+        match s {
+            "A" => Accepted,
+            "R" => Rejected,
+            _ => Workflow(s.to_string()),
+        }
+    }
+}
+
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        use Category::*;
+
+        match s {
+            "x" => Ok(X),
+            "m" => Ok(M),
+            "a" => Ok(A),
+            "s" => Ok(S),
+            _ => Err(ParseError::expected("one of x, m, a or s", s)),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Operator {
+    LessThan,
+    GreaterThan,
+}
+
+impl Operator {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        use Operator::*;
+
+        match s {
+            "<" => Ok(LessThan),
+            ">" => Ok(GreaterThan),
+            _ => Err(ParseError::expected("'<' or '>'", s)),
+        }
+    }
+}
+
+struct Comparison {
+    category: Category,
+    op: Operator,
+    n: usize,
+    dest: Destination,
+}
+
+impl Comparison {
+    /// Parses a rule like `a<2006:qkq`.
+    fn parse(input: &str) -> Parsed<'_, Self> {
+        let (category, s) = word(input)?;
+        let (op, s) = either("<", ">").read(s)?;
+        let (n, s) = int(s)?;
+        let (dest, s) = (":", word).read(s)?;
+
+        let comparison = Comparison {
+            category: Category::parse(category)?,
+            op: Operator::parse(op)?,
+            n,
+            dest: Destination::parse(dest),
+        };
+        Ok((comparison, s))
+    }
+
+    fn apply(&self, part: &Part) -> Option<Destination> {
+        use Category::*;
+        use Operator::*;
+
+        let val = match self.category {
+            X => part.x,
+            M => part.m,
+            A => part.a,
+            S => part.s,
+        };
+
+        match self.op {
+            LessThan => {
+                if val < self.n {
+                    Some(self.dest.clone())
+                } else {
+                    None
+                }
+            }
+            GreaterThan => {
+                if val > self.n {
+                    Some(self.dest.clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn split_slice(
+        &self,
+        slice: &PartSlice,
+    ) -> (Option<PartSlice>, Option<(Destination, PartSlice)>) {
+        use Category::*;
+        use Operator::*;
+
+        let mut a = slice.clone();
+        let mut any_remaining = true;
+
+        let mut b = slice.clone();
+        let mut split_succeeds = true;
+
+        match (&self.category, &self.op) {
+            (X, LessThan) => {
+                if slice.x_max < self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.x_min >= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.x_min = self.n;
+                    b.x_max = self.n - 1;
+                }
+            }
+            (X, GreaterThan) => {
+                if slice.x_min > self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.x_max <= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.x_max = self.n;
+                    b.x_min = self.n + 1;
+                }
+            }
+            (M, LessThan) => {
+                if slice.m_max < self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.m_min >= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.m_min = self.n;
+                    b.m_max = self.n - 1;
+                }
+            }
+            (M, GreaterThan) => {
+                if slice.m_min > self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.m_max <= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.m_max = self.n;
+                    b.m_min = self.n + 1;
+                }
+            }
+            (A, LessThan) => {
+                if slice.a_max < self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.a_min >= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.a_min = self.n;
+                    b.a_max = self.n - 1;
+                }
+            }
+            (A, GreaterThan) => {
+                if slice.a_min > self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.a_max <= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.a_max = self.n;
+                    b.a_min = self.n + 1;
+                }
+            }
+            (S, LessThan) => {
+                if slice.s_max < self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.s_min >= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.s_min = self.n;
+                    b.s_max = self.n - 1;
+                }
+            }
+            (S, GreaterThan) => {
+                if slice.s_min > self.n {
+                    // Everything goes through
+                    any_remaining = false;
+                } else if slice.s_max <= self.n {
+                    // Nothing goes through
+                    split_succeeds = false;
+                } else {
+                    // Otherwise, we get a split.
+                    a.s_max = self.n;
+                    b.s_min = self.n + 1;
+                }
+            }
+        };
+
+        let a = if any_remaining { Some(a) } else { None };
+        let b = if split_succeeds {
+            Some((self.dest.clone(), b))
+        } else {
+            None
+        };
+        (a, b)
+    }
+}
+
+pub struct Workflow {
+    name: String,
+    comparisons: Vec<Comparison>,
+    dest: Destination,
+}
+
+impl Workflow {
+    /// Parses a workflow like `px{a<2006:qkq,m>2090:A,rfg}`, where the
+    /// last destination is where parts go if none of the rules match.
+    fn parse(input: &str) -> Parsed<'_, Self> {
+        let (name, mut s) = word(input)?;
+        s = "{".read(s)?.1;
+
+        let mut comparisons = Vec::new();
+        while let Ok((comparison, rest)) = Comparison::parse(s) {
+            comparisons.push(comparison);
+            s = ",".read(rest)?.1;
+        }
+
+        let (dest, s) = word(s)?;
+        let (_, s) = "}".read(s)?;
+
+        let workflow = Workflow {
+            name: name.to_string(),
+            comparisons,
+            dest: Destination::parse(dest),
+        };
+        Ok((workflow, s))
+    }
+
+    fn apply(&self, part: &Part) -> Destination {
+        for comparison in &self.comparisons {
+            if let Some(dest) = comparison.apply(part) {
+                return dest;
+            }
+        }
+        self.dest.clone()
+    }
+
+    fn apply_slice(&self, slice: &PartSlice) -> Vec<(Destination, PartSlice)> {
+        let mut slices = Vec::new();
+
+        let mut remaining = slice.clone();
+        for comparison in &self.comparisons {
+            let (r, split) = comparison.split_slice(&remaining);
+
+            if let Some(split) = split {
+                slices.push(split);
+            }
+
+            match r {
+                Some(r) => remaining = r,
+                None => return slices,
+            }
+        }
+
+        // If we still have some left over, that will go to the final destination.
+        slices.push((self.dest.clone(), remaining));
+
+        slices
+    }
+}
+
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    /// Parses a part like `{x=787,m=2655,a=1222,s=2876}`.
+    fn parse(input: &str) -> Parsed<'_, Self> {
+        let (x, rest) = ("{", field("x", int)).read(input)?;
+        let (m, rest) = (",", field("m", int)).read(rest)?;
+        let (a, rest) = (",", field("a", int)).read(rest)?;
+        let (s, rest) = (",", field("s", int)).read(rest)?;
+        let (_, rest) = "}".read(rest)?;
+
+        Ok((Part { x, m, a, s }, rest))
+    }
+
+    fn is_accepted(&self, workflows: &HashMap<String, Workflow>) -> bool {
+        use Destination::*;
+
+        let mut current = String::from("in");
+
+        loop {
+            let workflow = workflows.get(&current).unwrap();
+            let next = workflow.apply(self);
+
+            match next {
+                Accepted => return true,
+                Rejected => return false,
+                Workflow(x) => current = x,
+            }
+        }
+    }
+
+    fn sum(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PartSlice {
+    x_min: usize,
+    x_max: usize,
+    m_min: usize,
+    m_max: usize,
+    a_min: usize,
+    a_max: usize,
+    s_min: usize,
+    s_max: usize,
+}
+
+impl PartSlice {
+    fn combinations(&self) -> usize {
+        (self.x_max - self.x_min + 1)
+            * (self.m_max - self.m_min + 1)
+            * (self.a_max - self.a_min + 1)
+            * (self.s_max - self.s_min + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (workflows, parts) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&workflows, &parts), 19114, "Part one");
+        assert_eq!(solve_slices(&workflows), 167409079868000, "Part one");
+    }
+}
@@ -0,0 +1,336 @@
+use aoc_parse::combinators::{complete, list, word, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, VecDeque};
+
+pub const DAY: Day = Day::new::<Puzzle>(2023, 20);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The network, and the conjunction that feeds `rx`.
+    type Input<'a> = (Network<'a>, usize);
+
+    fn parse(input: &str) -> Result<(Network<'_>, usize), ParseError> {
+        let network = Network::parse(input)?;
+        let hub = network.rx_hub()?;
+        Ok((network, hub))
+    }
+
+    fn part_one((network, _): &(Network, usize)) -> Answer {
+        part_one(network).into()
+    }
+
+    fn part_two((network, hub): &(Network, usize)) -> Answer {
+        part_two(network, *hub).into()
+    }
+}
+
+/// Multiplies the low pulses sent by the high ones over a thousand presses.
+fn part_one(network: &Network) -> usize {
+    let mut circuit = Circuit::new(network);
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..1000 {
+        circuit.press(|_, pulse, _| match pulse {
+            Pulse::Low => low += 1,
+            Pulse::High => high += 1,
+        });
+    }
+
+    low * high
+}
+
+/// How many presses it takes for `rx` to be sent a low pulse.
+///
+/// `rx` is fed by one conjunction, the `hub`, which only sends a low pulse
+/// once the last pulse from each of its inputs was high. The inputs each
+/// send a high pulse every so many presses, so this waits for the first from
+/// each and takes the lowest common multiple of when they came.
+fn part_two(network: &Network, hub: usize) -> usize {
+    let mut periods: HashMap<usize, usize> = HashMap::new();
+    let feeds = network.inputs(hub);
+    let mut circuit = Circuit::new(network);
+    let mut presses = 0;
+    while periods.len() < feeds.len() {
+        presses += 1;
+        circuit.press(|from, pulse, to| {
+            if to == hub && pulse == Pulse::High {
+                periods.entry(from).or_insert(presses);
+            }
+        });
+    }
+
+    periods
+        .into_values()
+        .reduce(|acc, x| (acc * x) / gcd(acc, x))
+        .unwrap()
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    gcd(b, a % b)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// Sends the broadcaster a low pulse when it's pressed.
+    Button,
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// A module that's only ever sent pulses, like `output` or `rx`.
+    Output,
+}
+
+/// The modules and how they're wired up, without any of their state. The
+/// button is module 0, wired to the broadcaster.
+#[derive(Debug)]
+pub struct Network<'a> {
+    names: Vec<&'a str>,
+    kinds: Vec<Kind>,
+    outputs: Vec<Vec<usize>>,
+}
+
+impl<'a> Network<'a> {
+    fn parse(input: &'a str) -> Result<Network<'a>, ParseError> {
+        let modules = lines(input, complete(module))?;
+
+        let mut names = vec!["button"];
+        let mut kinds = vec![Kind::Button];
+        for &(kind, name, _) in &modules {
+            names.push(name);
+            kinds.push(kind);
+        }
+        for (_, _, outputs) in &modules {
+            for &output in outputs {
+                if !names.contains(&output) {
+                    names.push(output);
+                    kinds.push(Kind::Output);
+                }
+            }
+        }
+
+        let index: HashMap<&str, usize> = names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let broadcaster = *index
+            .get("broadcaster")
+            .ok_or_else(|| ParseError::new("there's no broadcaster"))?;
+
+        let mut outputs = vec![vec![broadcaster]];
+        outputs.extend(
+            modules
+                .iter()
+                .map(|(_, _, outputs)| outputs.iter().map(|output| index[output]).collect()),
+        );
+        outputs.resize(names.len(), Vec::new());
+
+        Ok(Network {
+            names,
+            kinds,
+            outputs,
+        })
+    }
+
+    /// The conjunction that feeds `rx`, which part two depends on there
+    /// being exactly one of. The examples for part one don't have an `rx`.
+    fn rx_hub(&self) -> Result<usize, ParseError> {
+        let rx = self
+            .find("rx")
+            .ok_or_else(|| ParseError::new("expected an rx module"))?;
+        match self.inputs(rx)[..] {
+            [hub] if self.kinds[hub] == Kind::Conjunction && !self.inputs(hub).is_empty() => {
+                Ok(hub)
+            }
+            _ => Err(ParseError::new(
+                "expected a single conjunction feeding rx, with inputs of its own",
+            )),
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|&n| n == name)
+    }
+
+    /// The modules that send pulses to `module`.
+    fn inputs(&self, module: usize) -> Vec<usize> {
+        (0..self.outputs.len())
+            .filter(|&i| self.outputs[i].contains(&module))
+            .collect()
+    }
+}
+
+/// Parses a module like `%a -> inv, con`.
+fn module(s: &str) -> Parsed<'_, (Kind, &str, Vec<&str>)> {
+    let (kind, rest) = match s.split_at_checked(1) {
+        Some(("%", rest)) => (Kind::FlipFlop, rest),
+        Some(("&", rest)) => (Kind::Conjunction, rest),
+        _ if s.starts_with("broadcaster") => (Kind::Broadcaster, s),
+        _ => return Err(ParseError::expected("'%', '&' or \"broadcaster\"", s)),
+    };
+
+    let (name, rest) = word(rest)?;
+    let (outputs, rest) = (" -> ", list(word)).read(rest)?;
+    Ok(((kind, name, outputs), rest))
+}
+
+/// What a module does with the pulses it's sent.
+trait Module {
+    /// Takes a pulse from module `from`, and gives the pulse to send on to
+    /// every output, if any.
+    fn process(&mut self, from: usize, pulse: Pulse) -> Option<Pulse>;
+}
+
+struct Broadcaster;
+
+impl Module for Broadcaster {
+    fn process(&mut self, _: usize, pulse: Pulse) -> Option<Pulse> {
+        Some(pulse)
+    }
+}
+
+/// Ignores high pulses, and turns on or off with each low one, saying which.
+struct FlipFlop {
+    on: bool,
+}
+
+impl Module for FlipFlop {
+    fn process(&mut self, _: usize, pulse: Pulse) -> Option<Pulse> {
+        if pulse == Pulse::High {
+            return None;
+        }
+
+        self.on = !self.on;
+        Some(if self.on { Pulse::High } else { Pulse::Low })
+    }
+}
+
+/// Remembers the last pulse from each input, and sends a low pulse only
+/// while all of them were high.
+struct Conjunction {
+    memory: HashMap<usize, Pulse>,
+}
+
+impl Conjunction {
+    fn new(inputs: Vec<usize>) -> Conjunction {
+        Conjunction {
+            memory: inputs.into_iter().map(|i| (i, Pulse::Low)).collect(),
+        }
+    }
+}
+
+impl Module for Conjunction {
+    fn process(&mut self, from: usize, pulse: Pulse) -> Option<Pulse> {
+        self.memory.insert(from, pulse);
+        if self.memory.values().all(|&p| p == Pulse::High) {
+            Some(Pulse::Low)
+        } else {
+            Some(Pulse::High)
+        }
+    }
+}
+
+struct Output;
+
+impl Module for Output {
+    fn process(&mut self, _: usize, _: Pulse) -> Option<Pulse> {
+        None
+    }
+}
+
+/// A network with the state of all its modules, between presses of the
+/// button.
+struct Circuit<'a, 'n> {
+    network: &'n Network<'a>,
+    modules: Vec<Box<dyn Module>>,
+}
+
+impl<'a, 'n> Circuit<'a, 'n> {
+    fn new(network: &'n Network<'a>) -> Circuit<'a, 'n> {
+        let modules = (0..network.names.len())
+            .map(|i| -> Box<dyn Module> {
+                match network.kinds[i] {
+                    Kind::Broadcaster => Box::new(Broadcaster),
+                    Kind::FlipFlop => Box::new(FlipFlop { on: false }),
+                    Kind::Conjunction => Box::new(Conjunction::new(network.inputs(i))),
+                    Kind::Button | Kind::Output => Box::new(Output),
+                }
+            })
+            .collect();
+
+        Circuit { network, modules }
+    }
+
+    /// Presses the button, and delivers pulses in the order they were sent
+    /// until there are none left, showing each to `watch` as the module it
+    /// came from, the pulse and the module it went to.
+    fn press(&mut self, mut watch: impl FnMut(usize, Pulse, usize)) {
+        let mut queue: VecDeque<(usize, Pulse, usize)> = self.network.outputs[0]
+            .iter()
+            .map(|&to| (0, Pulse::Low, to))
+            .collect();
+
+        while let Some((from, pulse, to)) = queue.pop_front() {
+            watch(from, pulse, to);
+            if let Some(pulse) = self.modules[to].process(from, pulse) {
+                queue.extend(
+                    self.network.outputs[to]
+                        .iter()
+                        .map(|&next| (to, pulse, next)),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(part_one(&Network::parse(EXAMPLE).unwrap()), 32000000);
+        assert_eq!(part_one(&Network::parse(EXAMPLE2).unwrap()), 11687500);
+    }
+
+    #[test]
+    fn part_two_test() {
+        // Counters of one and two flip-flops, whose inverters go high every
+        // second and every fourth press
+        let network = "broadcaster -> a, b1\n%a -> ia\n&ia -> hub\n\
+                       %b1 -> b2\n%b2 -> ib\n&ib -> hub\n&hub -> rx\n";
+        let (network, hub) = Puzzle::parse(network).unwrap();
+        assert_eq!(part_two(&network, hub), 4);
+
+        let err = Puzzle::parse(EXAMPLE2).unwrap_err();
+        assert_eq!(err.to_string(), "expected an rx module");
+
+        let err = Puzzle::parse("broadcaster -> a, b\n%a -> rx\n%b -> rx\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a single conjunction feeding rx, with inputs of its own"
+        );
+    }
+
+    #[test]
+    fn parse_test() {
+        let network = Network::parse(EXAMPLE2).unwrap();
+        assert_eq!(network.inputs(network.find("con").unwrap()), [2, 4]);
+        assert_eq!(network.kinds[network.find("output").unwrap()], Kind::Output);
+
+        let err = Puzzle::parse("broadcaster -> a\n!a -> b\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected '%', '&' or \"broadcaster\", found \"!a -> b\""
+        );
+    }
+}
@@ -1,12 +1,3 @@
 fn main() {
-    println!("Hello, world!");
+    aoc_runner::run(&aoc_2023_day20::DAY);
 }
-
-
-enum Pulse {
-    High(String),
-    Low(String),
-}
-
-trait Module {
-    fn process(&mut self, pulse: Pulse) -> 
@@ -1,13 +1,13 @@
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!("../input.txt");
-
 fn main() {
-    println!("Part one: {}", solve(INPUT, 64));
+    let input = aoc_runner::input(2023, 21);
+
+    println!("Part one: {}", solve(&input, 64));
 
     let mut steps = 65;
     for _ in 0..10 {
-        println!("For {} steps: {}", steps, solve(INPUT, steps));
+        println!("For {} steps: {}", steps, solve(&input, steps));
         steps += 131;
     }
 }
@@ -0,0 +1,70 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(lists: &(Vec<u64>, Vec<u64>)) -> Answer {
+        part_one(lists).into()
+    }
+
+    fn part_two(lists: &(Vec<u64>, Vec<u64>)) -> Answer {
+        part_two(lists).into()
+    }
+}
+
+fn part_one((left, right): &(Vec<u64>, Vec<u64>)) -> u64 {
+    let (mut left, mut right) = (left.clone(), right.clone());
+    left.sort();
+    right.sort();
+
+    left.iter()
+        .zip(right.iter())
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum()
+}
+
+fn part_two((left, right): &(Vec<u64>, Vec<u64>)) -> u64 {
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for &x in right {
+        if let Some(v) = counts.get_mut(&x) {
+            *v += 1;
+        } else {
+            counts.insert(x, 1);
+        }
+    }
+
+    left.iter().map(|x| x * counts.get(x).unwrap_or(&0)).sum()
+}
+
+fn parse_input(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let pairs: Vec<(u64, u64)> = lines(input, |line| {
+        let (left, right) = split_once(line, " ")?;
+        Ok((number(left.trim())?, number(right.trim())?))
+    })?;
+
+    Ok(pairs.into_iter().unzip())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let lists = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&lists), 11);
+        assert_eq!(part_two(&lists), 31);
+    }
+}
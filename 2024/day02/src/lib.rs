@@ -0,0 +1,86 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use itertools::Itertools;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The levels in each report.
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        lines(input, |line| line.split_whitespace().map(number).collect())
+    }
+
+    fn part_one(reports: &Vec<Vec<i64>>) -> Answer {
+        part_one(reports).into()
+    }
+
+    fn part_two(reports: &Vec<Vec<i64>>) -> Answer {
+        part_two(reports).into()
+    }
+}
+
+fn part_one(reports: &[Vec<i64>]) -> usize {
+    reports.iter()
+        .filter(|nums| is_safe(nums))
+    .count()
+}
+
+
+fn part_two(reports: &[Vec<i64>]) -> usize {
+    reports.iter()
+        .filter_map(|nums| {
+            if is_safe(nums) {
+                return Some(());
+            }
+
+            for i in 0..nums.len() {
+                let mut reduced_nums = nums.clone();
+                reduced_nums.remove(i);
+                if is_safe(&reduced_nums) {
+                    return Some(());
+                }
+            }
+
+            None
+        })
+    .count()
+}
+
+fn is_safe(nums: &[i64]) -> bool {
+    let mut up = 0;
+    let mut down = 0;
+    for (a, b) in nums.iter().tuple_windows() {
+        let diff = a.abs_diff(*b);
+        if diff == 0 || diff > 3 {
+            return false;
+        }
+
+        if a > b {
+            down += 1;
+        } else if a < b {
+            up += 1;
+        }
+    }
+
+    up == 0 || down == 0
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let reports = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&reports), 2);
+        assert_eq!(part_two(&reports), 4);
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_parse::combinators::{take_while, Parsed, Parser};
+use aoc_parse::number;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The instructions that survived, in order. The rest of the memory is
+    /// corrupted, so there's nothing in it that can fail to parse.
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        let mut instructions = Vec::new();
+        for (i, _) in input.char_indices() {
+            let s = &input[i..];
+            if s.starts_with("do()") {
+                instructions.push(Instruction::Do);
+            } else if s.starts_with("don't()") {
+                instructions.push(Instruction::Dont);
+            } else if let Ok(((a, b), _)) = mul(s) {
+                instructions.push(Instruction::Mul(a, b));
+            }
+        }
+        Ok(instructions)
+    }
+
+    fn part_one(instructions: &Vec<Instruction>) -> Answer {
+        part_one(instructions).into()
+    }
+
+    fn part_two(instructions: &Vec<Instruction>) -> Answer {
+        part_two(instructions).into()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+fn part_one(instructions: &[Instruction]) -> i64 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(a, b) => a * b,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn part_two(instructions: &[Instruction]) -> i64 {
+    let mut enabled = true;
+    let mut total = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(a, b) if enabled => total += a * b,
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+    total
+}
+
+/// An instruction like `mul(44,46)`, anything else being corrupted memory.
+fn mul(s: &str) -> Parsed<'_, (i64, i64)> {
+    let (a, s) = ("mul(", operand).read(s)?;
+    let (b, s) = (",", operand).read(s)?;
+    let (_, s) = ")".read(s)?;
+    Ok(((a, b), s))
+}
+
+/// A number of one to three digits, with no sign.
+fn operand(s: &str) -> Parsed<'_, i64> {
+    let (digits, rest) = take_while("digits", |c| c.is_ascii_digit()).read(s)?;
+    match digits.len() {
+        1..=3 => Ok((number(digits)?, rest)),
+        _ => Err(ParseError::expected("up to three digits", digits)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(part_one(&Puzzle::parse(EXAMPLE).unwrap()), 161);
+        assert_eq!(part_two(&Puzzle::parse(EXAMPLE2).unwrap()), 48);
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The word search, as rows of letters.
+    type Input<'a> = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        let letters = aoc_grid::Grid::try_parse(input, |c| match c {
+            'A'..='Z' => Ok(c),
+            _ => Err(ParseError::expected("a capital letter", &c.to_string())),
+        })?;
+
+        if letters.width() == 0 {
+            return Err(ParseError::new("expected a word search"));
+        }
+
+        Ok(letters.rows().map(<[char]>::to_vec).collect())
+    }
+
+    fn part_one(grid: &Vec<Vec<char>>) -> Answer {
+        part_one(grid).into()
+    }
+
+    fn part_two(grid: &Vec<Vec<char>>) -> Answer {
+        part_two(grid).into()
+    }
+}
+
+fn part_one(grid: &[Vec<char>]) -> usize {
+    let rows: usize = grid.iter()
+        .map(|row| count_occurences(&row.iter().collect::<String>()))
+        .sum();
+
+    let width = grid.first().unwrap().len();
+    let columns: usize = (0..width)
+        .map(|i| {
+            let column: String = grid
+                .iter()
+                .map(|row| row.get(i).unwrap())
+                .collect();
+            column
+        })
+        .map(|column| count_occurences(&column))
+        .sum();
+
+    let width: i64 = width.try_into().unwrap();
+    let mut diagonals: usize = 0;
+    for dir in [-1, 1] {
+        for start in -width..2*width+1 {
+            let diagonal: String = grid
+                .iter()
+                .enumerate()
+                .filter_map(|(y, row)| {
+                    let x: i64 = start + (TryInto::<i64>::try_into(y).unwrap() * dir);
+                    if x < 0 {
+                        return None;
+                    }
+
+                    let x: usize = x.try_into().unwrap();
+                    row.get(x)
+                })
+                .collect();
+
+            diagonals += count_occurences(&diagonal);
+        }
+    }
+
+    rows + columns + diagonals
+}
+
+
+fn part_two(grid: &[Vec<char>]) -> usize {
+    let height = grid.len();
+    let width = grid.first().unwrap().len();
+
+    let mut count = 0;
+    for y in 1..height-1 {
+        for x in 1..width-1 {
+            if *grid.get(y).unwrap().get(x).unwrap() != 'A' {
+                continue;
+            }
+
+            let top_left = *grid.get(y-1).unwrap().get(x-1).unwrap();
+            let bottom_right = *grid.get(y+1).unwrap().get(x+1).unwrap();
+            if !(top_left == 'M' && bottom_right == 'S' || top_left == 'S' && bottom_right == 'M') {
+                continue;
+            }
+
+            let top_right = *grid.get(y-1).unwrap().get(x+1).unwrap();
+            let bottom_left = *grid.get(y+1).unwrap().get(x-1).unwrap();
+            if !(top_right == 'M' && bottom_left == 'S' || top_right == 'S' && bottom_left == 'M') {
+                continue;
+            }
+
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn count_occurences(input: &str) -> usize {
+    let forward = input.matches("XMAS").count();
+    let backwards = input.matches("SAMX").count();
+    forward + backwards
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let grid = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&grid), 18);
+        assert_eq!(part_two(&grid), 9);
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 5);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Manual;
+
+    fn parse(input: &str) -> Result<Manual, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(manual: &Manual) -> Answer {
+        part_one(manual).into()
+    }
+
+    fn part_two(manual: &Manual) -> Answer {
+        part_two(manual).into()
+    }
+}
+
+/// The page ordering rules, and the pages in each update.
+type Manual = (Vec<(u64, u64)>, Vec<Vec<u64>>);
+
+fn part_one((orderings, updates): &Manual) -> u64 {
+    updates
+        .iter()
+        .filter(|update| is_in_order(update, orderings))
+        .map(|update| *update.get(update.len() / 2).unwrap())
+        .sum()
+}
+
+fn part_two((orderings, updates): &Manual) -> u64 {
+    updates
+        .iter()
+        .filter(|update| !is_in_order(update, orderings))
+        .map(|update| sort_update(update, orderings))
+        .map(|update| *update.get(update.len() / 2).unwrap())
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Manual, ParseError> {
+    let (orderings, updates) = split_once(input, "\n\n")?;
+
+    let orderings: Vec<(u64, u64)> = lines(orderings, |line| {
+        let (a, b) = split_once(line, "|")?;
+        Ok((number(a)?, number(b)?))
+    })?;
+
+    // The updates start after the orderings and the blank line
+    let first_update = orderings.len() + 2;
+    let updates = lines(updates, |line| line.split(",").map(number).collect())
+        .map_err(|err| err.within(first_update, updates))?;
+
+    Ok((orderings, updates))
+}
+
+fn is_in_order(update: &[u64], orderings: &[(u64, u64)]) -> bool {
+    for ordering in orderings {
+        let first = find(update, ordering.0);
+        if first.is_none() {
+            continue;
+        }
+
+        let second = find(update, ordering.1);
+        if second.is_none() {
+            continue;
+        }
+
+        if first.unwrap() > second.unwrap() {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn sort_update(update: &[u64], orderings: &[(u64, u64)]) -> Vec<u64> {
+    let mut before: HashMap<u64, HashSet<u64>> = HashMap::new();
+    for ordering in orderings {
+        if find(update, ordering.0).is_none() || find(update, ordering.1).is_none() {
+            continue;
+        }
+
+        if let Some(s) = before.get_mut(&ordering.0) {
+            s.insert(ordering.1);
+        } else {
+            let mut s = HashSet::new();
+            s.insert(ordering.1);
+            before.insert(ordering.0, s);
+        }
+    }
+
+    // It looks like the order is completely explicit and there's no need to imply
+    // anything. i.e. if we have a < b < c then we will be told that a < b, b < c
+    // and a < c, there's no need to work that out.
+    // For the more general solution where we do have to work the implicit orderings
+    // out, we could iterate through the map, extending it by everything after each
+    let mut update = update.to_vec();
+    update.sort_by(|a, b| {
+        let a = before.get(a).map(|s| s.len()).unwrap_or(0);
+        let b = before.get(b).map(|s| s.len()).unwrap_or(0);
+        a.cmp(&b)
+    });
+
+    update
+}
+
+fn find(update: &[u64], key: u64) -> Option<usize> {
+    update
+        .iter()
+        .enumerate()
+        .find(|(_, x)| **x == key)
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let manual = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&manual), 143);
+        assert_eq!(part_two(&manual), 123);
+    }
+}
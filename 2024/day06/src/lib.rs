@@ -0,0 +1,209 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Grid::new(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        part_one(grid).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        part_two(grid).into()
+    }
+}
+
+fn part_one(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    grid.trace_route();
+    grid.unique_locations().len()
+}
+
+fn part_two(original_grid: &Grid) -> usize {
+    let mut grid = original_grid.clone();
+    grid.trace_route();
+    let unique_locations = grid.unique_locations();
+
+    let mut count = 0;
+
+    for pos in unique_locations {
+        if pos == original_grid.guard_pos {
+            continue;
+        }
+
+        let mut grid_clone = original_grid.clone();
+        grid_clone.obstacles.insert(pos);
+        if grid_clone.trace_route() {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+enum Dir {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir {
+    fn turn_right(&self) -> Dir {
+        match self {
+            Dir::N => Dir::E,
+            Dir::E => Dir::S,
+            Dir::S => Dir::W,
+            Dir::W => Dir::N,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    max_x: usize,
+    max_y: usize,
+    obstacles: HashSet<Pos>,
+
+    guard_pos: Pos,
+    guard_dir: Dir,
+
+    visited: HashSet<(Pos, Dir)>,
+}
+
+impl Grid {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let map = aoc_grid::Grid::try_parse(input, |c| match c {
+            '#' | '^' | '.' => Ok(c),
+            _ => Err(ParseError::expected("'#', '^' or '.'", &c.to_string())),
+        })?;
+
+        let (x, y) = map
+            .find(|&c| c == '^')
+            .ok_or_else(|| ParseError::new("expected '^' for the guard"))?;
+        let guard_pos = Pos { x, y };
+
+        let obstacles = map
+            .iter()
+            .filter(|&(_, &c)| c == '#')
+            .map(|((x, y), _)| Pos { x, y })
+            .collect();
+        let max_x = map.width();
+        let max_y = map.height() - 1;
+
+        let mut visited = HashSet::new();
+        visited.insert((guard_pos, Dir::N));
+
+        Ok(Grid {
+            max_x,
+            max_y,
+            obstacles,
+            guard_pos,
+            guard_dir: Dir::N,
+            visited,
+        })
+    }
+
+    fn trace_route(&mut self) -> bool {
+        loop {
+            if let Some(already_visited) = self.move_guard() {
+                if already_visited {
+                    return true;
+                }
+                continue;
+            }
+
+            return false;
+        }
+    }
+
+    // Returns None if the move takes us outside of the grid.
+    // Otherwise returns whether or not we've been to this position before.
+    fn move_guard(&mut self) -> Option<bool> {
+        if let Some(next_pos) = self.next_pos(self.guard_pos, self.guard_dir) {
+            if self.obstacles.contains(&next_pos) {
+                self.guard_dir = self.guard_dir.turn_right();
+            } else {
+                self.guard_pos = next_pos;
+            }
+
+            return Some(!self.visited.insert((self.guard_pos, self.guard_dir)));
+        }
+
+        None
+    }
+
+    fn next_pos(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        match dir {
+            Dir::N => {
+                if pos.y == 0 {
+                    return None;
+                }
+                Some(Pos {
+                    x: pos.x,
+                    y: pos.y - 1,
+                })
+            }
+            Dir::E => {
+                if pos.x == self.max_x {
+                    return None;
+                }
+                Some(Pos {
+                    x: pos.x + 1,
+                    y: pos.y,
+                })
+            }
+            Dir::S => {
+                if pos.y == self.max_y {
+                    return None;
+                }
+                Some(Pos {
+                    x: pos.x,
+                    y: pos.y + 1,
+                })
+            }
+            Dir::W => {
+                if pos.x == 0 {
+                    return None;
+                }
+                Some(Pos {
+                    x: pos.x - 1,
+                    y: pos.y,
+                })
+            }
+        }
+    }
+
+    fn unique_locations(&mut self) -> HashSet<Pos> {
+        self.visited.iter().map(|(pos, _)| *pos).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let grid = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&grid), 41);
+        assert_eq!(part_two(&grid), 6);
+    }
+}
@@ -0,0 +1,96 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 7);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// Each equation's test value, and the numbers that should make it.
+    type Input<'a> = Vec<(i64, Vec<i64>)>;
+
+    fn parse(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+        lines(input, parse_equation)
+    }
+
+    fn part_one(equations: &Vec<(i64, Vec<i64>)>) -> Answer {
+        part_one(equations).into()
+    }
+
+    fn part_two(equations: &Vec<(i64, Vec<i64>)>) -> Answer {
+        part_two(equations).into()
+    }
+}
+
+/// Reads an equation like `190: 10 19`.
+fn parse_equation(line: &str) -> Result<(i64, Vec<i64>), ParseError> {
+    let (target, nums) = split_once(line, ":")?;
+    let target = number(target)?;
+    let nums: Vec<i64> = nums.split_whitespace().map(number).collect::<Result<_, _>>()?;
+    if nums.is_empty() {
+        return Err(ParseError::expected("at least one number", line));
+    }
+    Ok((target, nums))
+}
+
+fn part_one(equations: &[(i64, Vec<i64>)]) -> i64 {
+    solve(equations, false)
+}
+
+
+fn part_two(equations: &[(i64, Vec<i64>)]) -> i64 {
+    solve(equations, true)
+}
+
+fn solve(equations: &[(i64, Vec<i64>)], concat: bool) -> i64 {
+    equations.iter()
+        .filter_map(|&(target, ref nums)| if can_make_target(target, nums.to_vec(), concat) > 0 { Some(target) } else { None })
+            .sum()
+
+}
+
+fn can_make_target(target: i64, nums: Vec<i64>, concat: bool) -> usize {
+    let mut nums = nums.clone();
+    nums.reverse();
+
+    let current = nums.pop().unwrap();
+    can_make_target_rec(target, current, nums, concat)
+}
+
+fn can_make_target_rec(target: i64, current: i64, mut nums: Vec<i64>, concat: bool) -> usize {
+    if current > target {
+        return 0;
+    }
+    if nums.is_empty() {
+        if target == current {
+            return 1;
+        }
+        return 0;
+    }
+
+    let n = nums.pop().unwrap();
+    let mut result = can_make_target_rec(target, current+n, nums.clone(), concat) + can_make_target_rec(target, current*n, nums.clone(), concat);
+    if concat {
+        let pow = n.ilog10() + 1;
+        let current = current * 10i64.pow(pow) + n;
+        result += can_make_target_rec(target, current, nums.clone(), concat);
+    }
+
+    result
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let equations = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&equations), 3749);
+        assert_eq!(part_two(&equations), 11387);
+    }
+}
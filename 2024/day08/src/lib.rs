@@ -0,0 +1,131 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap,HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(map: &Map) -> Answer {
+        part_one(map).into()
+    }
+
+    fn part_two(map: &Map) -> Answer {
+        part_two(map).into()
+    }
+}
+
+/// The map's height and width, and where each frequency's antennas are.
+type Map = (i64, i64, HashMap<char, Vec<(i64, i64)>>);
+
+fn part_one(&(height, width, ref antennas): &Map) -> usize {
+    let mut locations: HashSet<(i64,i64)> = HashSet::new();
+
+    for v in antennas.values() {
+        for i in 0..v.len()-1 {
+            for j in i+1..v.len() {
+                let a = v.get(i).unwrap();
+                let b = v.get(j).unwrap();
+                
+                // b + (a -> b)
+                let x = 2*b.0 - a.0;
+                let y = 2*b.1 - a.1;
+                if x >= 0 && x < width && y >= 0 && y < height {
+                    locations.insert((x, y));
+                }
+                
+                // a + (b -> a)
+                let x = 2*a.0 - b.0;
+                let y = 2*a.1 - b.1;
+                if x >= 0 && x < width && y >= 0 && y < height {
+                    locations.insert((x, y));
+                }
+            }
+        }
+    }
+
+    locations.len()
+}
+
+
+fn part_two(&(height, width, ref antennas): &Map) -> usize {
+    let mut locations: HashSet<(i64,i64)> = HashSet::new();
+
+    for v in antennas.values() {
+        for i in 0..v.len()-1 {
+            for j in i+1..v.len() {
+                let a = v.get(i).unwrap();
+                let b = v.get(j).unwrap();
+
+                let x_diff = b.0 - a.0;
+                let y_diff = b.1 - a.1;
+
+                let mut x = a.0;
+                let mut y = a.1;
+                while x >= 0 && x < width && y >= 0 && y < height {
+                    locations.insert((x, y));
+                    x += x_diff;
+                    y += y_diff;
+                }
+
+                let mut x = a.0;
+                let mut y = a.1;
+                while x >= 0 && x < width && y >= 0 && y < height {
+                    locations.insert((x, y));
+                    x -= x_diff;
+                    y -= y_diff;
+                }
+            }
+        }
+    }
+
+    locations.len()
+}
+
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let mut antennas: HashMap<char, Vec<(i64,i64)>> = HashMap::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c == '.' {
+                continue;
+            }
+            let x: i64 = x.try_into().unwrap();
+            let y: i64 = y.try_into().unwrap();
+
+            if let Some(pos) = antennas.get_mut(&c) {
+                pos.push((x,y));
+            } else {
+                let pos = vec![(x,y)];
+                antennas.insert(c, pos);
+            }
+        }
+    }
+
+    let lines: Vec<&str> = input.lines().collect();
+    let height = lines.len().try_into().unwrap();
+    let first = lines.first().ok_or_else(|| ParseError::expected("a map", ""))?;
+    let width = first.len().try_into().unwrap();
+
+    Ok((height, width, antennas))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let map = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&map), 14);
+        assert_eq!(part_two(&map), 34);
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_parse::{digits, line};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 9);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The disk map's digits, alternating between files and free space.
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        let map = line(input, digits)?;
+        Ok(map.into_iter().map(|d| d as usize).collect())
+    }
+
+    fn part_one(map: &Vec<usize>) -> Answer {
+        solve(map, true).into()
+    }
+
+    fn part_two(map: &Vec<usize>) -> Answer {
+        solve(map, false).into()
+    }
+}
+
+fn solve(map: &[usize], single_blocks: bool) -> usize {
+    let mut disk = Disk::new(map, single_blocks);
+    disk.solve()
+}
+
+struct Disk {
+    blocks: Vec<Block>,
+    gaps: Vec<Gap>,
+}
+
+impl Disk {
+    fn new(map: &[usize], single_blocks: bool) -> Self {
+        let mut id = 0;
+        let mut pos = 0;
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut gaps: Vec<Gap> = Vec::new();
+
+        for (i, &n) in map.iter().enumerate() {
+            if n == 0 {
+                continue;
+            }
+            if i % 2 == 0 {
+                if single_blocks {
+                    for p in pos..pos+n {
+                        blocks.push(Block{pos: p, id, len: 1});
+                    }
+                } else {
+                    blocks.push(Block{pos, id, len: n});
+                }
+
+                id += 1;
+            } else {
+                gaps.push(Gap{pos, len: n});
+            }
+
+            pos += n;
+        }
+
+        Disk{blocks, gaps}
+    }
+
+    fn solve(&mut self) -> usize {
+        for i in (0..self.blocks.len()).rev() {
+            let block = self.blocks.get_mut(i).unwrap();
+            for gap in self.gaps.iter_mut() {
+                if gap.pos > block.pos {
+                    continue;
+                }
+
+                if gap.len >= block.len {
+                    block.pos = gap.pos;
+                    gap.pos += block.len;
+                    gap.len -= block.len;
+
+                    break;
+                }
+            }
+        }
+
+        self.blocks.iter().map(|block| block.checksum()).sum()
+    }
+}
+
+#[derive(Debug,Clone)]
+struct Block {
+    pos: usize,
+    id: usize,
+    len: usize,
+}
+
+impl Block {
+    fn checksum(&self) -> usize {
+        (self.pos..self.pos+self.len).map(|i| i*self.id).sum()
+    }
+}
+
+#[derive(Debug)]
+struct Gap {
+    pos: usize,
+    len: usize,
+}
+
+
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let map = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&map, true), 1928);
+        assert_eq!(solve(&map, false), 2858);
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_grid::Grid;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 10);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The height at each position on the map.
+    type Input<'a> = HashMap<(i64, i64), u32>;
+
+    fn parse(input: &str) -> Result<HashMap<(i64, i64), u32>, ParseError> {
+        let heights = Grid::try_parse(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::expected("a height", &c.to_string()))
+        })?;
+
+        Ok(heights
+            .iter()
+            .map(|((x, y), &height)| ((x as i64, y as i64), height))
+            .collect())
+    }
+
+    fn part_one(grid: &HashMap<(i64, i64), u32>) -> Answer {
+        Mapper::new(grid).trailhead_score_sum().into()
+    }
+
+    fn part_two(grid: &HashMap<(i64, i64), u32>) -> Answer {
+        Mapper::new(grid).trailhead_rating_sum().into()
+    }
+}
+
+struct Mapper {
+    grid: HashMap<(i64, i64), u32>,
+    memoized: HashMap<(i64, i64), usize>,
+}
+
+impl Mapper {
+    fn new(grid: &HashMap<(i64, i64), u32>) -> Mapper {
+        Mapper {
+            grid: grid.clone(),
+            memoized: HashMap::new(),
+        }
+    }
+
+    fn trailhead_score_sum(&mut self) -> usize {
+        self.grid
+            .iter()
+            .filter_map(|(&pos, &n)| {
+                if n == 0 {
+                    Some(self.trailhead_score(pos))
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    fn trailhead_score(&self, pos: (i64, i64)) -> usize {
+        let mut positions = HashSet::new();
+        positions.insert(pos);
+
+        for height in 1u32..=9 {
+            let mut next_positions = HashSet::new();
+            for p in positions {
+                for neighbour in [
+                    (p.0 - 1, p.1),
+                    (p.0 + 1, p.1),
+                    (p.0, p.1 - 1),
+                    (p.0, p.1 + 1),
+                ] {
+                    if let Some(n) = self.grid.get(&neighbour) {
+                        if *n == height {
+                            next_positions.insert(neighbour);
+                        }
+                    }
+                }
+            }
+
+            positions = next_positions;
+        }
+
+        positions.len()
+    }
+
+    fn trailhead_rating_sum(&mut self) -> usize {
+        let mut sum = 0;
+        for (&pos, &d) in self.grid.clone().iter() {
+            if d == 0 {
+                let score = self.trailhead_rating(pos, d);
+                sum += score;
+            }
+        }
+        sum
+    }
+
+    fn trailhead_rating(&mut self, pos: (i64, i64), n: u32) -> usize {
+        if let Some(count) = self.memoized.get(&pos) {
+            return *count;
+        }
+
+        let count = self._trailhead_rating(pos, n);
+        self.memoized.insert(pos, count);
+        count
+    }
+
+    fn _trailhead_rating(&mut self, pos: (i64, i64), n: u32) -> usize {
+        if n == 9 {
+            return 1;
+        }
+
+        let mut count = 0;
+        for neighbour in [
+            (pos.0 - 1, pos.1),
+            (pos.0 + 1, pos.1),
+            (pos.0, pos.1 - 1),
+            (pos.0, pos.1 + 1),
+        ] {
+            if let Some(height) = self.grid.get(&neighbour) {
+                if *height == n + 1 {
+                    count += self.trailhead_rating(neighbour, n + 1);
+                }
+            }
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let mut mapper = Mapper::new(&Puzzle::parse(EXAMPLE).unwrap());
+        assert_eq!(mapper.trailhead_score_sum(), 36);
+        assert_eq!(mapper.trailhead_rating_sum(), 81);
+    }
+}
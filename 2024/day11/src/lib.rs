@@ -0,0 +1,90 @@
+use aoc_parse::{line, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 11);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(stones: &Vec<usize>) -> Answer {
+        solve(stones, 25).into()
+    }
+
+    fn part_two(stones: &Vec<usize>) -> Answer {
+        solve(stones, 75).into()
+    }
+}
+
+fn solve(stones: &[usize], blinks: usize) -> usize {
+    let mut splitter = StoneSplitter::new();
+
+    stones
+        .iter()
+        .map(|stone| splitter.split(*stone, blinks))
+        .sum()
+}
+
+struct StoneSplitter {
+    memoized: HashMap<(usize, usize), usize>,
+}
+
+impl StoneSplitter {
+    fn new() -> Self {
+        StoneSplitter {
+            memoized: HashMap::new(),
+        }
+    }
+
+    fn split(&mut self, stone: usize, blinks: usize) -> usize {
+        let key = (stone, blinks);
+        if let Some(count) = self.memoized.get(&key) {
+            return *count;
+        }
+
+        let count = self._split(stone, blinks);
+        self.memoized.insert(key, count);
+        count
+    }
+
+    fn _split(&mut self, stone: usize, blinks: usize) -> usize {
+        if blinks == 0 {
+            return 1;
+        }
+        if stone == 0 {
+            return self.split(1, blinks - 1);
+        }
+
+        let digits = stone.ilog10() + 1;
+        if digits.is_multiple_of(2) {
+            let div = 10usize.pow(digits / 2);
+            return self.split(stone / div, blinks - 1) + self.split(stone % div, blinks - 1);
+        }
+
+        self.split(stone * 2024, blinks - 1)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    line(input, |line| line.split_whitespace().map(number).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let stones = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&stones, 6), 22);
+        assert_eq!(solve(&stones, 25), 55312);
+    }
+}
@@ -0,0 +1,173 @@
+use aoc_grid::Grid;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 12);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The plant growing in each garden plot.
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::try_parse(input, |c| match c {
+            'A'..='Z' => Ok(c),
+            _ => Err(ParseError::expected("a plant", &c.to_string())),
+        })
+    }
+
+    fn part_one(garden: &Grid<char>) -> Answer {
+        part_one(garden).into()
+    }
+
+    fn part_two(garden: &Grid<char>) -> Answer {
+        part_two(garden).into()
+    }
+}
+
+fn part_one(garden: &Grid<char>) -> usize {
+    get_regions(garden).iter().map(|region| region.len() * perimeter(region)).sum()
+}
+
+fn part_two(garden: &Grid<char>) -> usize {
+    get_regions(garden).iter().map(|region| region.len() * sides(region)).sum()
+}
+
+fn get_regions(garden: &Grid<char>) -> Vec<HashSet<(i64, i64)>> {
+    let mut positions: HashMap<(i64, i64), char> = garden
+        .iter()
+        .map(|((x, y), &c)| ((x as i64, y as i64), c))
+        .collect();
+
+    let height = garden.height() as i64;
+    let width = garden.width() as i64;
+
+    let mut regions: Vec<HashSet<(i64, i64)>> = Vec::new();
+
+    for x in 0..width {
+        for y in 0..height {
+            if !positions.contains_key(&(x,y)) {
+                continue;
+            }
+
+            let current_char = positions.remove(&(x,y)).unwrap();
+            let mut region: HashSet<(i64, i64)> = HashSet::new();
+            region.insert((x,y));
+            let mut last_added: HashSet<(i64, i64)> = HashSet::new();
+            last_added.insert((x,y));
+
+            while !last_added.is_empty() {
+                let mut new_positions: HashSet<(i64,i64)> = HashSet::new();
+                for pos in last_added {
+
+                    for p in [(pos.0-1,pos.1), (pos.0+1, pos.1), (pos.0, pos.1-1), (pos.0, pos.1+1)] {
+                        if let Some(c) = positions.get(&p) {
+                            if *c == current_char {
+                                region.insert(p);
+                                new_positions.insert(p);
+                                positions.remove(&p);
+                            }
+                        }
+                    }
+                }
+
+                last_added = new_positions;
+            }
+
+            regions.push(region);
+        }
+    }
+
+    regions
+}
+
+fn perimeter(region: &HashSet<(i64, i64)>) -> usize {
+    region.iter().map(|(x, y)| {
+        let neighbours: Vec<(i64,i64)> = vec![(x-1,*y), (x+1, *y), (*x,y-1), (*x,y+1)];
+        neighbours.iter().filter(|pos| !region.contains(pos)).count()
+    }).sum()
+}
+
+fn sides(region: &HashSet<(i64, i64)>) -> usize {
+    let mut left: Vec<(i64, i64)> = Vec::new();
+    let mut right: Vec<(i64, i64)> = Vec::new();
+    let mut top: Vec<(i64, i64)> = Vec::new();
+    let mut bottom: Vec<(i64, i64)> = Vec::new();
+
+    for &(x, y) in region {
+        if !region.contains(&(x-1, y)) {
+            left.push((x,y));
+        }
+        if !region.contains(&(x+1,y)) {
+            right.push((x,y));
+        }
+
+        if !region.contains(&(x,y-1)) {
+            top.push((x, y));
+        }
+        if !region.contains(&(x,y+1)) {
+            bottom.push((x,y+1));
+        }
+    }
+
+
+    left.sort();
+    right.sort();
+    top.sort_by_key(|(x,y)| (*y, *x));
+    bottom.sort_by_key(|(x,y)| (*y, *x));
+
+    let mut left_sides = 1;
+    for i in 1..left.len() {
+        let p0 = left.get(i-1).unwrap();
+        let p1 = left.get(i).unwrap();
+
+        if p0.0 != p1.0 || p1.1 - p0.1 != 1 {
+            left_sides += 1;
+        }
+    }
+    let mut right_sides = 1;
+    for i in 1..right.len() {
+        let p0 = right.get(i-1).unwrap();
+        let p1 = right.get(i).unwrap();
+
+        if p0.0 != p1.0 || p1.1 - p0.1 != 1 {
+            right_sides += 1;
+        }
+    }
+
+    let mut top_sides = 1;
+    for i in 1..top.len() {
+        let p0 = top.get(i-1).unwrap();
+        let p1 = top.get(i).unwrap();
+
+        if p0.1 != p1.1 || p1.0 - p0.0 != 1 {
+            top_sides += 1;
+        }
+    }
+    let mut bottom_sides = 1;
+    for i in 1..bottom.len() {
+        let p0 = bottom.get(i-1).unwrap();
+        let p1 = bottom.get(i).unwrap();
+
+        if p0.1 != p1.1 || p1.0 - p0.0 != 1 {
+            bottom_sides += 1;
+        }
+    }
+
+    left_sides + right_sides + top_sides + bottom_sides
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let garden = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&garden), 1930);
+        assert_eq!(part_two(&garden), 1206);
+    }
+}
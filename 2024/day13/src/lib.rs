@@ -0,0 +1,121 @@
+use aoc_parse::blocks;
+use aoc_parse::combinators::{complete, field, int, labelled, Parsed, Parser};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 13);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+        blocks(input, |block| complete(Machine::parse)(block.trim_end()))
+    }
+
+    fn part_one(machines: &Vec<Machine>) -> Answer {
+        part_one(machines).into()
+    }
+
+    fn part_two(machines: &Vec<Machine>) -> Answer {
+        part_two(machines).into()
+    }
+}
+
+fn part_one(machines: &[Machine]) -> i64 {
+    machines.iter().filter_map(Machine::solve).sum()
+}
+
+fn part_two(machines: &[Machine]) -> i64 {
+    machines
+        .iter()
+        .filter_map(|machine| {
+            let mut machine = machine.clone();
+            machine.adjust_target(10000000000000);
+            machine.solve()
+        })
+        .sum()
+}
+
+#[derive(Clone, Debug)]
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    target: (i64, i64),
+}
+
+impl Machine {
+    /// Parses a machine like:
+    ///
+    /// ```text
+    /// Button A: X+94, Y+34
+    /// Button B: X+22, Y+67
+    /// Prize: X=8400, Y=5400
+    /// ```
+    fn parse(input: &str) -> Parsed<'_, Self> {
+        let (a, s) = labelled("Button A", button).read(input)?;
+        let (b, s) = ("\n", labelled("Button B", button)).read(s)?;
+        let (target, s) = ("\n", labelled("Prize", prize)).read(s)?;
+        Ok((Self { a, b, target }, s))
+    }
+
+    fn adjust_target(&mut self, v: i64) {
+        self.target.0 += v;
+        self.target.1 += v;
+    }
+
+    fn solve(&self) -> Option<i64> {
+        let div = self.a.0 * self.b.1 - self.a.1 * self.b.0;
+        if div != 0 {
+            // The two vectors are not co-linear, so there is at most one solution.
+            let num = self.target.0 * self.b.1 - self.target.1 * self.b.0;
+
+            if num % div != 0 {
+                return None;
+            }
+            let a = num / div;
+
+            let num = self.target.0 - a * self.a.0;
+            if num % self.b.0 != 0 {
+                return None;
+            }
+            let b = num / self.b.0;
+
+            if a >= 0 && b >= 0 {
+                return Some(b + 3 * a);
+            }
+            return None;
+        }
+
+        // If they are co-linear, we need to do things bit differently. Thankfully it doesn't look
+        // like there are any cases like this, so we'll ignore.
+        eprintln!("{:?} is co-linear", self);
+        None
+    }
+}
+
+fn button(s: &str) -> Parsed<'_, (i64, i64)> {
+    let (x, s) = ("X", int).read(s)?;
+    let (y, s) = (", Y", int).read(s)?;
+    Ok(((x, y), s))
+}
+
+fn prize(s: &str) -> Parsed<'_, (i64, i64)> {
+    let (x, s) = field("X", int).read(s)?;
+    let (y, s) = (", ", field("Y", int)).read(s)?;
+    Ok(((x, y), s))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let machines = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&machines), 480);
+        assert_eq!(part_two(&machines), 875318608908);
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_parse::combinators::{complete, field, int, Parsed, Parser};
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 14);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+        lines(input, complete(robot))
+    }
+
+    fn part_one(robots: &Vec<Robot>) -> Answer {
+        part_one(robots).into()
+    }
+
+    fn part_two(robots: &Vec<Robot>) -> Answer {
+        part_two(robots).into()
+    }
+}
+
+/// A robot's starting position and its velocity, as `(p_x, p_y, v_x, v_y)`.
+type Robot = (i64, i64, i64, i64);
+
+/// Reads a robot like `p=0,4 v=3,-3`.
+fn robot(s: &str) -> Parsed<'_, Robot> {
+    let (p_x, s) = field("p", int).read(s)?;
+    let (p_y, s) = (",", int).read(s)?;
+    let (v_x, s) = (" ", field("v", int)).read(s)?;
+    let (v_y, s) = (",", int).read(s)?;
+    Ok(((p_x, p_y, v_x, v_y), s))
+}
+
+fn part_one(robots: &[Robot]) -> usize {
+    solve(robots, 100, 101, 103)
+}
+
+fn part_two(robots: &[Robot]) -> i64 {
+    let mut scores: Vec<(i64, usize)> = Vec::new();
+    for i in 1..10_000 {
+        scores.push((i, solve(robots, i, 101, 103)));
+    }
+    scores.sort_by_key(|k| k.1);
+
+    let s = scores.first().unwrap();
+
+    visualise(robots, s.0, 101, 103);
+
+    s.0
+}
+
+fn solve(robots: &[Robot], time: i64, width: i64, height: i64) -> usize {
+    let (mut q0, mut q1, mut q2, mut q3) = (0, 0, 0, 0);
+    for robot in robots {
+        match quadrant(robot, time, width, height) {
+            Some(0) => {
+                q0 += 1;
+            }
+            Some(1) => {
+                q1 += 1;
+            }
+            Some(2) => {
+                q2 += 1;
+            }
+            Some(3) => {
+                q3 += 1;
+            }
+            None => {}
+            Some(x) => {
+                eprintln!("Unexpected quadrant {:?}: {}", robot, x);
+            }
+        }
+    }
+
+    q0 * q1 * q2 * q3
+}
+
+fn quadrant(robot: &Robot, time: i64, width: i64, height: i64) -> Option<i64> {
+    let (x, y) = position(robot, time, width, height);
+
+    if x == width / 2 {
+        return None;
+    }
+    if y == height / 2 {
+        return None;
+    }
+
+    Some((2 * x / width) + 2 * (2 * y / height))
+}
+
+fn position(robot: &Robot, time: i64, width: i64, height: i64) -> (i64, i64) {
+    let &(p_x, p_y, v_x, v_y) = robot;
+
+    let x = (width + (p_x + v_x * time) % width) % width;
+    let y = (height + (p_y + v_y * time) % height) % height;
+    (x, y)
+}
+
+fn visualise(robots: &[Robot], time: i64, width: i64, height: i64) {
+    let mut positions: HashSet<(i64, i64)> = HashSet::new();
+
+    for robot in robots {
+        let pos = position(robot, time, width, height);
+        positions.insert(pos);
+    }
+
+    for y in 0..height {
+        let line: String = (0..width)
+            .map(|x| {
+                if positions.contains(&(x, y)) {
+                    '#'
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        eprintln!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let robots = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&robots, 100, 11, 7), 12);
+    }
+}
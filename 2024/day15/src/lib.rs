@@ -0,0 +1,400 @@
+use aoc_grid::{Grid, Pos};
+use aoc_parse::{each_char, lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 15);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Plan;
+
+    fn parse(input: &str) -> Result<Plan, ParseError> {
+        Plan::parse(input)
+    }
+
+    fn part_one(plan: &Plan) -> Answer {
+        solve(plan, false, false).into()
+    }
+
+    fn part_two(plan: &Plan) -> Answer {
+        solve(plan, true, false).into()
+    }
+}
+
+fn solve(plan: &Plan, double: bool, visualise: bool) -> usize {
+    let mut warehouse = Warehouse::new(plan, double);
+    warehouse.run(visualise)
+}
+
+/// The warehouse as it's first drawn, and the moves the robot will try.
+pub struct Plan {
+    grid: Grid<char>,
+    robot: Pos,
+    moves: Vec<Direction>,
+}
+
+impl Plan {
+    fn parse(input: &str) -> Result<Plan, ParseError> {
+        let (grid, moves) = split_once(input, "\n\n")?;
+
+        let grid = Grid::try_parse(grid, |c| match c {
+            '#' | '.' | 'O' | '@' => Ok(c),
+            _ => Err(ParseError::expected("'#', '.', 'O' or '@'", &c.to_string())),
+        })?;
+        let robot = grid
+            .find(|&c| c == '@')
+            .ok_or_else(|| ParseError::new("expected '@' for the robot"))?;
+
+        let moves = lines(moves, |line| {
+            each_char(line, "'^', 'v', '<' or '>'", |c| match c {
+                '^' => Some(Direction::Up),
+                'v' => Some(Direction::Down),
+                '<' => Some(Direction::Left),
+                '>' => Some(Direction::Right),
+                _ => None,
+            })
+        })
+        .map_err(|err| err.within(grid.height() + 2, moves))?;
+
+        Ok(Plan {
+            grid,
+            robot,
+            moves: moves.concat(),
+        })
+    }
+}
+
+struct Warehouse {
+    walls: Grid<bool>,
+    /// The left hand side of each box.
+    boxes: Grid<bool>,
+    robot: (i64, i64),
+    instructions: Vec<Direction>,
+    double: bool,
+}
+
+impl Warehouse {
+    fn new(plan: &Plan, double: bool) -> Self {
+        let grid = &plan.grid;
+        let scale = if double { 2 } else { 1 };
+
+        // Everything is stretched sideways in the doubled warehouse, apart
+        // from the boxes which stay at the left of their new space
+        let walls = Grid::from_fn(grid.width() * scale, grid.height(), |(x, y)| {
+            grid[(x / scale, y)] == '#'
+        });
+        let boxes = Grid::from_fn(grid.width() * scale, grid.height(), |(x, y)| {
+            x % scale == 0 && grid[(x / scale, y)] == 'O'
+        });
+
+        let (x, y) = plan.robot;
+        let robot = ((x * scale) as i64, y as i64);
+
+        let instructions: Vec<Direction> = plan.moves.iter().rev().copied().collect();
+
+        Warehouse {
+            walls,
+            boxes,
+            robot,
+            instructions,
+            double,
+        }
+    }
+
+    fn run(&mut self, visualise: bool) -> usize {
+        let mut count = 0;
+
+        if self.double {
+            loop {
+                if visualise {
+                    self.visualise(count);
+                    count += 1;
+                }
+                if !self.iterate_double() {
+                    return self.score();
+                }
+                continue;
+            }
+        }
+
+        loop {
+            if visualise {
+                self.visualise(count);
+                count += 1;
+            }
+            if !self.iterate() {
+                return self.score();
+            }
+            continue;
+        }
+    }
+
+    fn iterate(&mut self) -> bool {
+        let dir = self.instructions.pop();
+        if dir.is_none() {
+            return false;
+        }
+        let dir = dir.unwrap();
+
+        let next_robot = (self.robot.0 + dir.x(), self.robot.1 + dir.y());
+        if self.is_wall(next_robot) {
+            // We hit a wall, nothing to do.
+            return true;
+        }
+
+        if !self.is_box(next_robot) {
+            // No box, so just move the robot.
+            self.robot = next_robot;
+            return true;
+        }
+
+        // We know we've hit a box, so we need to keep going until we find an empty space.
+        let mut pos = next_robot;
+        loop {
+            pos = (pos.0 + dir.x(), pos.1 + dir.y());
+            if self.is_wall(pos) {
+                // We've hit a wall, so we can't shunt the boxes along.
+                return true;
+            }
+
+            if !self.is_box(pos) {
+                // We've hit a gap, so we can move all the boxes along.
+                // In reality, this can
+                self.set_box(pos, true);
+                self.set_box(next_robot, false);
+                self.robot = next_robot;
+                return true;
+            }
+
+            // We've hit another box, so we need to just keep going until we hit a wall or
+            // a free space.
+        }
+    }
+
+    fn iterate_double(&mut self) -> bool {
+        use Direction::{Down, Left, Right, Up};
+
+        let dir = self.instructions.pop();
+        if dir.is_none() {
+            return false;
+        }
+        let dir = dir.unwrap();
+
+        let next_robot = (self.robot.0 + dir.x(), self.robot.1 + dir.y());
+        let can_move = match dir {
+            Left => self.move_left(&next_robot),
+            Right => self.move_right(&next_robot),
+            Up => self.move_vertically(&next_robot, -1),
+            Down => self.move_vertically(&next_robot, 1),
+        };
+        if can_move {
+            self.robot = next_robot;
+        }
+
+        true
+    }
+
+    fn move_left(&mut self, robot_pos: &(i64, i64)) -> bool {
+        let mut pos = *robot_pos;
+        loop {
+            if self.is_wall(pos) {
+                // We've hit a wall, nothing more to do.
+                return false;
+            }
+
+            if self.is_box((pos.0 - 1, pos.1)) {
+                // We've hit a box, so we need to check the next spot two to the left.
+                pos = (pos.0 - 2, pos.1);
+                continue;
+            }
+
+            // We're not at a wall or empty spot so we can shunt all the boxes along.
+            for x in (pos.0..robot_pos.0).step_by(2) {
+                self.set_box((x + 1, robot_pos.1), false);
+                self.set_box((x, robot_pos.1), true);
+            }
+            return true;
+        }
+    }
+
+    fn move_right(&mut self, robot_pos: &(i64, i64)) -> bool {
+        let mut pos = *robot_pos;
+        loop {
+            if self.is_wall(pos) {
+                // We've hit a wall, nothing more to do.
+                return false;
+            }
+
+            if self.is_box((pos.0, pos.1)) {
+                // We've hit a box, so we need to check the next spot two to the right;
+                pos = (pos.0 + 2, pos.1);
+                continue;
+            }
+
+            // We're not at a wall or empty spot so we can shunt all the boxes along.
+            for x in (robot_pos.0..pos.0).step_by(2) {
+                self.set_box((x, robot_pos.1), false);
+                self.set_box((x + 1, robot_pos.1), true);
+            }
+            return true;
+        }
+    }
+
+    fn move_vertically(&mut self, robot_pos: &(i64, i64), step: i64) -> bool {
+        let mut x_pos: HashSet<i64> = HashSet::new();
+        x_pos.insert(robot_pos.0);
+
+        let mut blocks_to_move: HashSet<(i64, i64)> = HashSet::new();
+
+        let mut y = robot_pos.1;
+
+        loop {
+            // Have we hit a wall with any block?
+            for x in &x_pos {
+                if self.is_wall((*x, y)) {
+                    return false;
+                }
+            }
+
+            let mut next_x_pos: HashSet<i64> = HashSet::new();
+
+            // Are there any blocks above/below us?
+            for x in &x_pos {
+                if self.is_box((x - 1, y)) {
+                    next_x_pos.insert(x - 1);
+                    next_x_pos.insert(*x);
+                    blocks_to_move.insert((x - 1, y));
+                }
+                if self.is_box((*x, y)) {
+                    next_x_pos.insert(*x);
+                    next_x_pos.insert(x + 1);
+                    blocks_to_move.insert((*x, y));
+                }
+            }
+
+            // If we have nothing above/below the blocks we need to move, shift them.
+            if next_x_pos.is_empty() {
+                for block in &blocks_to_move {
+                    self.set_box(*block, false);
+                }
+                for block in &blocks_to_move {
+                    self.set_box((block.0, block.1 + step), true);
+                }
+
+                return true;
+            }
+
+            y += step;
+            x_pos = next_x_pos;
+        }
+    }
+
+    fn cell(grid: &Grid<bool>, (x, y): (i64, i64)) -> bool {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => grid.get((x, y)).copied().unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    fn is_wall(&self, pos: (i64, i64)) -> bool {
+        Self::cell(&self.walls, pos)
+    }
+
+    fn is_box(&self, pos: (i64, i64)) -> bool {
+        Self::cell(&self.boxes, pos)
+    }
+
+    fn set_box(&mut self, (x, y): (i64, i64), present: bool) {
+        self.boxes[(x as usize, y as usize)] = present;
+    }
+
+    fn score(&self) -> usize {
+        self.boxes
+            .iter()
+            .filter(|(_, &b)| b)
+            .map(|((x, y), _)| 100 * y + x)
+            .sum()
+    }
+
+    fn visualise(&self, count: usize) {
+        println!("\nIteration {}\n", count);
+
+        let mut view = self.walls.map(|&wall| if wall { '#' } else { ' ' });
+        for ((x, y), _) in self.boxes.iter().filter(|(_, &b)| b) {
+            if self.double {
+                view[(x, y)] = '[';
+                view[(x + 1, y)] = ']';
+            } else {
+                view[(x, y)] = 'O';
+            }
+        }
+
+        view[(self.robot.0 as usize, self.robot.1 as usize)] = match self.instructions.last() {
+            Some(Direction::Up) => '^',
+            Some(Direction::Down) => 'v',
+            Some(Direction::Left) => '<',
+            Some(Direction::Right) => '>',
+            None => '@',
+        };
+
+        println!("{}", view);
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn x(&self) -> i64 {
+        match self {
+            Direction::Left => -1,
+            Direction::Right => 1,
+            _ => 0,
+        }
+    }
+
+    fn y(&self) -> i64 {
+        match self {
+            Direction::Up => -1,
+            Direction::Down => 1,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../example1.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+
+    #[test]
+    fn example() {
+        let plan1 = Puzzle::parse(EXAMPLE1).unwrap();
+        let plan2 = Puzzle::parse(EXAMPLE2).unwrap();
+        let plan3 = Puzzle::parse(EXAMPLE3).unwrap();
+
+        assert_eq!(solve(&plan1, false, false), 2028);
+        assert_eq!(solve(&plan2, false, false), 10092);
+        assert_eq!(solve(&plan3, true, true), 618);
+        assert_eq!(solve(&plan2, true, true), 9021);
+    }
+
+    #[test]
+    fn bad_move() {
+        let err = Puzzle::parse("#####\n#@O.#\n#####\n\n<>\n^x\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 2: expected '^', 'v', '<' or '>', found \"x\""
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_grid::{Grid, Pos, ORTHOGONAL};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use aoc_search::{dijkstra, Paths};
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 16);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Maze;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        Maze::new(input)
+    }
+
+    fn part_one(maze: &Maze) -> Answer {
+        part_one(maze).into()
+    }
+
+    fn part_two(maze: &Maze) -> Answer {
+        part_two(maze).into()
+    }
+}
+
+fn part_one(maze: &Maze) -> usize {
+    maze.find_routes().goal_cost().unwrap()
+}
+
+fn part_two(maze: &Maze) -> usize {
+    maze.tiles_on_best_route()
+}
+
+/// The index into `ORTHOGONAL` of the way the reindeer starts out facing.
+const EAST: usize = 1;
+
+/// A position and the index into `ORTHOGONAL` of the way the reindeer faces.
+type Reindeer = (Pos, usize);
+
+pub struct Maze {
+    walls: Grid<bool>,
+
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+impl Maze {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::try_parse(input, |c| match c {
+            '#' | '.' | 'S' | 'E' => Ok(c),
+            _ => Err(ParseError::expected("'#', '.', 'S' or 'E'", &c.to_string())),
+        })?;
+        let find = |target| {
+            grid.find(|&c| c == target)
+                .ok_or_else(|| ParseError::new(format!("expected '{target}' in the maze")))
+        };
+
+        Ok(Maze {
+            walls: grid.map(|&c| c == '#'),
+            start_pos: find('S')?,
+            end_pos: find('E')?,
+        })
+    }
+
+    /// Stepping forward costs one point and turning costs a thousand.
+    fn moves(&self, &(pos, dir): &Reindeer) -> Vec<(Reindeer, usize)> {
+        let mut moves = vec![((pos, (dir + 3) % 4), 1000), ((pos, (dir + 1) % 4), 1000)];
+
+        if let Some(next) = self.walls.offset(pos, ORTHOGONAL[dir]) {
+            if !self.walls[next] {
+                moves.push(((next, dir), 1));
+            }
+        }
+
+        moves
+    }
+
+    fn find_routes(&self) -> Paths<Reindeer, usize> {
+        dijkstra(
+            (self.start_pos, EAST),
+            |reindeer| self.moves(reindeer),
+            |&(pos, _)| pos == self.end_pos,
+        )
+    }
+
+    fn tiles_on_best_route(&self) -> usize {
+        self.find_routes()
+            .on_optimal_paths()
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect::<HashSet<Pos>>()
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../example1.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn example() {
+        let maze1 = Puzzle::parse(EXAMPLE1).unwrap();
+        let maze2 = Puzzle::parse(EXAMPLE2).unwrap();
+
+        assert_eq!(part_one(&maze1), 7036);
+        assert_eq!(part_one(&maze2), 11048);
+
+        assert_eq!(part_two(&maze1), 45);
+        assert_eq!(part_two(&maze2), 64);
+    }
+}
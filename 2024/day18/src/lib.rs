@@ -0,0 +1,98 @@
+use aoc_grid::{Grid, Pos};
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use aoc_search::bfs;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 18);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// Where each byte falls, in order.
+    type Input<'a> = Vec<Pos>;
+
+    fn parse(input: &str) -> Result<Vec<Pos>, ParseError> {
+        lines(input, |line| {
+            let (x, y) = split_once(line, ",")?;
+            Ok((number(x)?, number(y)?))
+        })
+    }
+
+    fn part_one(bytes: &Vec<Pos>) -> Answer {
+        part_one(bytes, 70, 1024).into()
+    }
+
+    fn part_two(bytes: &Vec<Pos>) -> Answer {
+        part_two(bytes, 70).into()
+    }
+}
+
+fn part_one(bytes: &[Pos], size: usize, time: usize) -> usize {
+    let memory = Memory::new(bytes, size, time);
+    memory.best_route().unwrap()
+}
+
+fn part_two(bytes: &[Pos], size: usize) -> String {
+    let max = bytes.len();
+
+    let (mut i, mut j) = (0, max);
+    while i != j {
+        let n = (i + j) / 2;
+        let memory = Memory::new(bytes, size, n);
+        if memory.best_route().is_some() {
+            if i == n {
+                j = n;
+            }
+            i = n;
+        } else {
+            if j == n {
+                i = n;
+            }
+            j = n;
+        }
+    }
+
+    let (x, y) = bytes[i];
+    format!("{x},{y}")
+}
+
+/// The memory space, with `size` as the highest coordinate along each side.
+struct Memory {
+    corrupted: Grid<bool>,
+}
+
+impl Memory {
+    fn new(bytes: &[Pos], size: usize, time: usize) -> Memory {
+        let mut corrupted = Grid::new(size + 1, size + 1, false);
+        for &pos in bytes.iter().take(time) {
+            corrupted[pos] = true;
+        }
+
+        Memory { corrupted }
+    }
+
+    fn best_route(&self) -> Option<usize> {
+        let end = (self.corrupted.width() - 1, self.corrupted.height() - 1);
+        let successors = |&pos: &Pos| {
+            self.corrupted
+                .neighbours(pos)
+                .filter(|&n| !self.corrupted[n])
+        };
+
+        bfs((0, 0), successors, |&pos| pos == end).goal_cost()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let bytes = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&bytes, 6, 12), 22);
+        assert_eq!(part_two(&bytes, 6), "6,1");
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_parse::{each_char, line, lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 19);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The towel patterns on offer, and the designs to make from them.
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+    fn parse(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+        let (patterns, designs) = split_once(input, "\n\n")?;
+        let patterns = line(patterns, |patterns| {
+            patterns.split(',').map(|p| stripes(p.trim())).collect()
+        })?;
+        let designs = lines(designs, stripes).map_err(|err| err.within(3, designs))?;
+        Ok((patterns, designs))
+    }
+
+    fn part_one((patterns, designs): &(Vec<&str>, Vec<&str>)) -> Answer {
+        Towels::new(patterns, designs).possible_designs().into()
+    }
+
+    fn part_two((patterns, designs): &(Vec<&str>, Vec<&str>)) -> Answer {
+        Towels::new(patterns, designs).total_combinations().into()
+    }
+}
+
+/// Checks a run of coloured stripes, each one of `w`, `u`, `b`, `r` or `g`.
+fn stripes(s: &str) -> Result<&str, ParseError> {
+    each_char(s, "a stripe colour", |c| "wubrg".contains(c).then_some(c))?;
+    Ok(s)
+}
+
+struct Towels {
+    patterns: HashSet<String>,
+    min_pattern_length: usize,
+    max_pattern_length: usize,
+
+    designs: Vec<String>,
+
+    seen: HashMap<String, usize>,
+}
+
+impl Towels {
+    fn new(patterns: &[&str], designs: &[&str]) -> Self {
+        let patterns: HashSet<String> = patterns.iter().map(|&s| s.to_owned()).collect();
+        let designs: Vec<String> = designs.iter().map(|&s| s.to_owned()).collect();
+
+        let (min_pattern_length, max_pattern_length) = patterns
+            .iter()
+            .map(|p| p.len())
+            .fold((usize::MAX, 0), |acc, x| (min(acc.0, x), max(acc.1, x)));
+
+        Towels {
+            patterns,
+            min_pattern_length,
+            max_pattern_length,
+            designs,
+            seen: HashMap::new(),
+        }
+    }
+
+    fn possible_designs(&mut self) -> usize {
+        let mut count = 0;
+        for design in self.designs.clone() {
+            if self.combinations(&design) > 0 {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn total_combinations(&mut self) -> usize {
+        let mut count = 0;
+        for design in self.designs.clone() {
+            count += self.combinations(&design);
+        }
+        count
+    }
+
+    fn combinations(&mut self, s: &str) -> usize {
+        if let Some(count) = self.seen.get(s) {
+            return *count;
+        }
+
+        let count = self._combinations(s);
+        self.seen.insert(s.to_string(), count);
+        count
+    }
+
+    fn _combinations(&mut self, s: &str) -> usize {
+        if s.is_empty() {
+            return 1;
+        }
+
+        let mut count = 0;
+        for i in self.min_pattern_length..=self.max_pattern_length {
+            if i > s.len() {
+                break;
+            }
+
+            let (prefix, remainder) = s.split_at(i);
+            if !self.patterns.contains(prefix) {
+                continue;
+            }
+
+            count += self.combinations(remainder);
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (patterns, designs) = Puzzle::parse(EXAMPLE).unwrap();
+        let mut towels = Towels::new(&patterns, &designs);
+        assert_eq!(towels.possible_designs(), 6);
+        assert_eq!(towels.total_combinations(), 16);
+    }
+}
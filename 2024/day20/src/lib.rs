@@ -0,0 +1,149 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 20);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Grid::new(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        part_one(grid).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        part_two(grid).into()
+    }
+}
+
+fn part_one(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    grid.solve();
+    grid.cheats(2, 100)
+}
+
+fn part_two(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    grid.solve();
+    grid.cheats(20, 100)
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    walls: HashSet<(i64, i64)>,
+    start: (i64, i64),
+    end: (i64, i64),
+
+    visited: HashMap<(i64, i64), i64>,
+}
+
+impl Grid {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let track = aoc_grid::Grid::try_parse(input, |c| match c {
+            '#' | '.' | 'S' | 'E' => Ok(c),
+            _ => Err(ParseError::expected("'#', '.', 'S' or 'E'", &c.to_string())),
+        })?;
+        let find = |target| {
+            track
+                .find(|&c| c == target)
+                .map(|(x, y)| (x as i64, y as i64))
+                .ok_or_else(|| ParseError::new(format!("expected '{target}' on the racetrack")))
+        };
+
+        let walls = track
+            .iter()
+            .filter(|&(_, &c)| c == '#')
+            .map(|((x, y), _)| (x as i64, y as i64))
+            .collect();
+
+        Ok(Grid {
+            walls,
+            start: find('S')?,
+            end: find('E')?,
+            visited: HashMap::new(),
+        })
+    }
+
+    fn solve(&mut self) {
+        self.visited.insert((self.start.0, self.start.1), 0);
+
+        let mut latest_positions: HashMap<(i64, i64), i64> = HashMap::new();
+        latest_positions.insert((self.start.0, self.start.1), 0);
+
+        while !latest_positions.is_empty() {
+            let mut newer_positions = HashMap::new();
+
+            for ((x, y), score) in latest_positions {
+                let neighbours = vec![(x + 1, y), (x - 1, y), (x, y - 1), (x, y + 1)];
+
+                for (x, y) in neighbours {
+                    if self.walls.contains(&(x, y)) {
+                        continue;
+                    }
+
+                    if self.visited.contains_key(&(x, y)) {
+                        continue;
+                    }
+
+                    self.visited.insert((x, y), score + 1);
+                    newer_positions.insert((x, y), score + 1);
+
+                    if x == self.end.0 && y == self.end.1 {
+                        return;
+                    }
+                }
+            }
+
+            latest_positions = newer_positions;
+        }
+    }
+
+    fn cheats(&self, max_cheat_len: i64, min_saving: i64) -> usize {
+        let all_points: Vec<(i64, i64)> = self.visited.clone().into_keys().collect();
+
+        let mut count = 0;
+        for i in 0..all_points.len() {
+            let a = all_points.get(i).unwrap();
+            let pts_a = self.visited.get(a).unwrap();
+
+            for j in i + 1..all_points.len() {
+                let b = all_points.get(j).unwrap();
+
+                let abs_diff = (b.0 - a.0).abs() + (b.1 - a.1).abs();
+                if abs_diff > max_cheat_len {
+                    continue;
+                }
+
+                let pts_b = self.visited.get(b).unwrap();
+                let saving = (pts_b - pts_a).abs() - abs_diff;
+                if saving >= min_saving {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let mut grid = Puzzle::parse(EXAMPLE).unwrap();
+        grid.solve();
+
+        assert_eq!(grid.cheats(2, 2), 44);
+        assert_eq!(grid.cheats(2, 20), 5);
+        assert_eq!(grid.cheats(20, 50), 285);
+    }
+}
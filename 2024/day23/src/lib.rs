@@ -0,0 +1,155 @@
+use aoc_parse::{each_char, lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 23);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// Each pair of connected computers.
+    type Input<'a> = Vec<(Computer, Computer)>;
+
+    fn parse(input: &str) -> Result<Vec<(Computer, Computer)>, ParseError> {
+        lines(input, |line| {
+            let (a, b) = split_once(line, "-")?;
+            Ok((computer(a)?, computer(b)?))
+        })
+    }
+
+    fn part_one(connections: &Vec<(Computer, Computer)>) -> Answer {
+        Network::new(connections).triad_count().into()
+    }
+
+    fn part_two(connections: &Vec<(Computer, Computer)>) -> Answer {
+        Network::new(connections).password().into()
+    }
+}
+
+type Computer = [char; 2];
+
+/// Reads a computer's name, which is two lowercase letters.
+fn computer(s: &str) -> Result<Computer, ParseError> {
+    let name = each_char(s, "a lowercase letter", |c| {
+        c.is_ascii_lowercase().then_some(c)
+    })?;
+    name.try_into()
+        .map_err(|_| ParseError::expected("a two letter name", s))
+}
+
+struct Network {
+    connections: HashSet<(Computer, Computer)>,
+}
+
+impl Network {
+    fn new(connections: &[(Computer, Computer)]) -> Self {
+        let connections: HashSet<(Computer, Computer)> = connections
+            .iter()
+            .map(|&(a, b)| if a < b { (a, b) } else { (b, a) })
+            .collect();
+
+        Network { connections }
+    }
+
+    fn triad_count(&self) -> usize {
+        let groups = self.initial_groups();
+        let mut triads = self.group(&groups);
+        triads.sort();
+
+        triads
+            .iter()
+            .filter(|triad| triad.len() == 3)
+            .filter_map(|triad| triad.iter().find(|computer| computer[0] == 't'))
+            .count()
+    }
+
+    fn password(&self) -> String {
+        let mut groups = self.initial_groups();
+        let mut best = groups.first().unwrap().clone();
+
+        loop {
+            groups = self.group(&groups);
+            if groups.is_empty() {
+                return best
+                    .iter()
+                    .map(|c| {
+                        let mut s = String::new();
+                        s.push(c[0]);
+                        s.push(c[1]);
+                        s
+                    })
+                    .join(",");
+            }
+
+            let max_len = groups.iter().map(|g| g.len()).max().unwrap();
+            groups.retain(|g| g.len() == max_len);
+
+            for g in &groups {
+                if g.len() > best.len() {
+                    best = g.to_vec();
+                }
+            }
+        }
+    }
+
+    fn group(&self, groups: &[Vec<Computer>]) -> Vec<Vec<Computer>> {
+        let mut results = Vec::new();
+
+        for i in 0..groups.len() - 1 {
+            let a = groups.get(i).unwrap();
+
+            for j in i + 1..groups.len() {
+                let b = groups.get(j).unwrap();
+                if let Some(new_group) = self.union(a, b) {
+                    results.push(new_group);
+                }
+            }
+        }
+
+        results.sort();
+        results.dedup();
+        results
+    }
+
+    fn union(&self, a: &[Computer], b: &[Computer]) -> Option<Vec<Computer>> {
+        let mut union = a.to_vec();
+        union.extend(b);
+        union.sort();
+        union.dedup();
+
+        for i in 0..union.len() - 1 {
+            let x = *union.get(i).unwrap();
+            for j in i + 1..union.len() {
+                let y = *union.get(j).unwrap();
+                if !self.connections.contains(&(x, y)) {
+                    return None;
+                }
+            }
+        }
+
+        Some(union)
+    }
+
+    fn initial_groups(&self) -> Vec<Vec<Computer>> {
+        self.connections
+            .clone()
+            .into_iter()
+            .map(|(a, b)| vec![a, b])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let network = Network::new(&Puzzle::parse(EXAMPLE).unwrap());
+        assert_eq!(network.triad_count(), 7);
+        assert_eq!(network.password(), String::from("co,de,ka,ta"));
+    }
+}
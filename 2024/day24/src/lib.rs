@@ -0,0 +1,248 @@
+use aoc_parse::{each_char, lines, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use rand::random;
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 24);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Grid::new(input)
+    }
+
+    fn part_one(grid: &Grid) -> Answer {
+        part_one(grid).into()
+    }
+
+    fn part_two(grid: &Grid) -> Answer {
+        part_two(grid).into()
+    }
+}
+
+fn part_one(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    grid.connect();
+    grid.value('z')
+}
+
+fn part_two(grid: &Grid) -> String {
+    let mut grid = grid.clone();
+    grid.wires = HashMap::new();
+
+    // Populated manually by searching for a set of digits that didn't work.
+    let swaps: Vec<(Wire,Wire)> = vec![
+        (['z', '0', '6'], ['v', 'w', 'r']),
+        (['t', 'q', 'm'], ['z', '1', '1']),
+        (['h', 'c', 'm'], ['g', 'f', 'v']),
+        (['z', '1', '6'], ['k', 'f', 's']),
+    ];
+    for gate in grid.gates.iter_mut() {
+        for swap in &swaps {
+            if gate.out == swap.0 {
+                gate.out = swap.1;
+                continue;
+            }
+            if gate.out == swap.1 {
+                gate.out = swap.0;
+            }
+        }
+    }
+
+    let mut all_gates = grid.gates.clone();
+    let mut pow = 2;
+    for i in 0..44 {
+        let x = [
+            'x',
+            char::from_digit(i / 10, 10).unwrap(),
+            char::from_digit(i % 10, 10).unwrap(),
+        ];
+        grid.wires.insert(x, random());
+
+        let y = [
+            'y',
+            char::from_digit(i / 10, 10).unwrap(),
+            char::from_digit(i % 10, 10).unwrap(),
+        ];
+        grid.wires.insert(y, random());
+
+        grid.connect();
+        let x = grid.value('x');
+        let y = grid.value('y');
+        let z = grid.value('z');
+
+        if (x + y) % pow != z % pow {
+            eprintln!("{}: {} + {} != {}", i, x, y, z);
+            eprintln!("Gates used:");
+            for g in all_gates {
+                if grid.gates.contains(&g) {
+                    continue;
+                }
+
+                eprintln!(
+                    "{}{}{} {:?} {}{}{} -> {}{}{}",
+                    g.a[0],
+                    g.a[1],
+                    g.a[2],
+                    g.op,
+                    g.b[0],
+                    g.b[1],
+                    g.b[2],
+                    g.out[0],
+                    g.out[1],
+                    g.out[2]
+                );
+            }
+
+            return String::from("");
+        }
+
+        pow *= 2;
+        all_gates = grid.gates.clone();
+    }
+
+    let mut swaps: Vec<String> = swaps.into_iter().flat_map(|(a, b)| {
+        let a: String = a.iter().collect();
+        let b: String = b.iter().collect();
+        vec![a, b]
+    }).collect();
+    swaps.sort();
+    swaps.join(",")
+}
+
+type Wire = [char; 3];
+
+/// Reads a wire's name, like `x00` or `tgd`.
+fn parse_wire(s: &str) -> Result<Wire, ParseError> {
+    let name = each_char(s, "a letter or digit", |c| {
+        c.is_ascii_alphanumeric().then_some(c)
+    })?;
+    name.try_into()
+        .map_err(|_| ParseError::expected("a three character wire", s))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Op {
+    AND,
+    OR,
+    XOR,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Gate {
+    a: Wire,
+    b: Wire,
+    op: Op,
+    out: Wire,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    wires: HashMap<Wire, bool>,
+    gates: Vec<Gate>,
+}
+
+impl Grid {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let (wires, gates) = split_once(input, "\n\n")?;
+
+        let wires: HashMap<Wire, bool> = lines(wires, |line| {
+            let (wire, value) = split_once(line, ": ")?;
+            let value = match value {
+                "0" => false,
+                "1" => true,
+                _ => return Err(ParseError::expected("0 or 1", value)),
+            };
+            Ok((parse_wire(wire)?, value))
+        })?
+        .into_iter()
+        .collect();
+
+        let first = wires.len() + 2;
+        let gates: Vec<Gate> = lines(gates, |line| {
+            let (inputs, out) = split_once(line, " -> ")?;
+            let (a, inputs) = split_once(inputs, " ")?;
+            let (op, b) = split_once(inputs, " ")?;
+            let op = match op {
+                "AND" => Op::AND,
+                "OR" => Op::OR,
+                "XOR" => Op::XOR,
+                _ => return Err(ParseError::expected("AND, OR or XOR", op)),
+            };
+
+            Ok(Gate { a: parse_wire(a)?, b: parse_wire(b)?, op, out: parse_wire(out)? })
+        })
+        .map_err(|err| err.within(first, gates))?;
+
+        Ok(Grid { wires, gates })
+    }
+
+    fn connect(&mut self) {
+        while !self.gates.is_empty() {
+            let mut found = false;
+            for i in 0..self.gates.len() {
+                let gate = self.gates.get(i).unwrap();
+                let a = self.wires.get(&gate.a);
+                if a.is_none() {
+                    continue;
+                }
+
+                let b = self.wires.get(&gate.b);
+                if b.is_none() {
+                    continue;
+                }
+
+                found = true;
+                let a = *a.unwrap();
+                let b = *b.unwrap();
+                let out = match gate.op {
+                    Op::AND => a && b,
+                    Op::OR => a || b,
+                    Op::XOR => (a && !b) || (!a && b),
+                };
+
+                self.wires.insert(gate.out, out);
+                self.gates.remove(i);
+                break;
+            }
+
+            if !found {
+                return;
+            }
+        }
+    }
+
+    fn value(&self, prefix: char) -> usize {
+        let mut output_bits: Vec<(Wire, usize)> = self
+            .wires
+            .clone()
+            .into_iter()
+            .filter(|(w, _)| w[0] == prefix)
+            .map(|(w, b)| (w, if b { 1 } else { 0 }))
+            .collect();
+        output_bits.sort();
+        output_bits.reverse();
+        output_bits
+            .into_iter()
+            .map(|(_, n)| n)
+            .reduce(|acc, x| 2 * acc + x)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../example1.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(part_one(&Puzzle::parse(EXAMPLE1).unwrap()), 4);
+        assert_eq!(part_one(&Puzzle::parse(EXAMPLE2).unwrap()), 2024);
+    }
+}
@@ -0,0 +1,129 @@
+use aoc_grid::Grid;
+use aoc_parse::blocks;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+const HEIGHT: u8 = 7;
+
+pub const DAY: Day = Day::new::<Puzzle>(2024, 25);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Schematic>;
+
+    fn parse(input: &str) -> Result<Vec<Schematic>, ParseError> {
+        blocks(input, Schematic::parse)
+    }
+
+    fn part_one(schematics: &Vec<Schematic>) -> Answer {
+        part_one(schematics).into()
+    }
+
+    fn part_two(schematics: &Vec<Schematic>) -> Answer {
+        part_two(schematics).into()
+    }
+}
+
+fn part_one(schematics: &[Schematic]) -> usize {
+    let mut matches = 0;
+    for i in 0..schematics.len() - 1 {
+        let a = schematics.get(i).unwrap();
+        for j in i + 1..schematics.len() {
+            let b = schematics.get(j).unwrap();
+            if a.can_unlock(b) {
+                matches += 1;
+            }
+        }
+    }
+
+    matches
+}
+
+fn part_two(_schematics: &[Schematic]) -> i64 {
+    0
+}
+
+#[derive(Clone, Debug)]
+pub enum Schematic {
+    Key(u8, u8, u8, u8, u8),
+    Lock(u8, u8, u8, u8, u8),
+}
+
+impl Schematic {
+    /// Reads a lock, which has its top row filled in, or a key, which has
+    /// its bottom row filled in. Either way, each column's height is how
+    /// much of it is filled in past that row.
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::try_parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::expected("'#' or '.'", &c.to_string())),
+        })?;
+
+        if grid.width() != 5 || grid.height() != HEIGHT as usize {
+            return Err(ParseError::new(format!(
+                "expected a schematic 5 wide and {} high, but it's {} by {}",
+                HEIGHT,
+                grid.width(),
+                grid.height()
+            )));
+        }
+
+        let filled = |y| grid.row(y).iter().all(|&filled| filled);
+        let is_lock = filled(0);
+        if !is_lock && !filled(grid.height() - 1) {
+            return Err(ParseError::new(
+                "expected a lock or a key, with its top or bottom row filled in",
+            ));
+        }
+
+        let heights: Vec<u8> = grid
+            .columns()
+            .map(|column| column.filter(|&&filled| filled).count() as u8 - 1)
+            .collect();
+        let [a, b, c, d, e] = heights[..] else {
+            unreachable!("the schematic is five columns wide");
+        };
+
+        if is_lock {
+            return Ok(Schematic::Lock(a, b, c, d, e));
+        }
+
+        Ok(Schematic::Key(a, b, c, d, e))
+    }
+
+    fn can_unlock(&self, other: &Schematic) -> bool {
+        use Schematic::{Key, Lock};
+
+        match (self, other) {
+            (Key(_, _, _, _, _), Key(_, _, _, _, _)) => false,
+            (Lock(_, _, _, _, _), Lock(_, _, _, _, _)) => false,
+            (Key(a, b, c, d, e), Lock(v, w, x, y, z))
+            | (Lock(a, b, c, d, e), Key(v, w, x, y, z)) => {
+                a + v < HEIGHT - 1
+                    && b + w < HEIGHT - 1
+                    && c + x < HEIGHT - 1
+                    && d + y < HEIGHT - 1
+                    && e + z < HEIGHT - 1
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let schematics = Puzzle::parse(EXAMPLE).unwrap();
+        for schematic in &schematics {
+            println!("{:?}", schematic);
+        }
+
+        assert_eq!(part_one(&schematics), 3);
+        assert_eq!(part_two(&schematics), 0);
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 1);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        lines(input, Instruction::parse)
+    }
+
+    fn part_one(instructions: &Vec<Instruction>) -> Answer {
+        run(instructions).0.into()
+    }
+
+    fn part_two(instructions: &Vec<Instruction>) -> Answer {
+        run(instructions).1.into()
+    }
+}
+
+fn run(instructions: &[Instruction]) -> (isize, isize) {
+    let mut dial = Dial::new(50);
+
+    instructions.iter().for_each(|&i| dial.rotate(i));
+
+    (dial.landed_on_zero, dial.passed_zero)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Left(isize),
+    Right(isize),
+}
+
+impl Instruction {
+    /// Reads a rotation like `L68` or `R14`.
+    fn parse(s: &str) -> Result<Instruction, ParseError> {
+        use Instruction::*;
+
+        if let Some(n) = s.strip_prefix('L') {
+            Ok(Left(number(n)?))
+        } else if let Some(n) = s.strip_prefix('R') {
+            Ok(Right(number(n)?))
+        } else {
+            Err(ParseError::expected("'L' or 'R'", s))
+        }
+    }
+}
+
+struct Dial {
+    pos: isize,
+    landed_on_zero: isize,
+    passed_zero: isize,
+}
+
+impl Dial {
+    fn new(start_pos: isize) -> Dial {
+        Dial {
+            pos: start_pos,
+            landed_on_zero: 0,
+            passed_zero: 0,
+        }
+    }
+
+    fn rotate(&mut self, i: Instruction) {
+        use Instruction::*;
+
+        match i {
+            Left(x) => {
+                self.passed_zero += x / 100;
+                let new_pos = self.pos - (x % 100);
+
+                if self.pos > 0 && new_pos < 0 {
+                    self.passed_zero += 1;
+                }
+                self.pos = new_pos % 100;
+            }
+            Right(x) => {
+                self.passed_zero += x / 100;
+                self.pos += x % 100;
+
+                if self.pos > 100 {
+                    self.passed_zero += 1;
+                }
+            }
+        }
+
+        self.pos = (self.pos + 100) % 100;
+        if self.pos == 0 {
+            self.passed_zero += 1;
+            self.landed_on_zero += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example() {
+        let (part_one, part_two) = run(&Puzzle::parse(EXAMPLE).unwrap());
+        assert_eq!(part_one, 3);
+        assert_eq!(part_two, 6);
+    }
+}
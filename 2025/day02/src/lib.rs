@@ -0,0 +1,115 @@
+use aoc_parse::{line, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 2);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Range>;
+
+    fn parse(input: &str) -> Result<Vec<Range>, ParseError> {
+        line(input, |ranges| {
+            ranges.split(',').map(Range::parse).collect()
+        })
+    }
+
+    fn part_one(ranges: &Vec<Range>) -> Answer {
+        solve(ranges, true).into()
+    }
+
+    fn part_two(ranges: &Vec<Range>) -> Answer {
+        solve(ranges, false).into()
+    }
+}
+
+fn solve(ranges: &[Range], twice: bool) -> u64 {
+    ranges
+        .iter()
+        .flat_map(|range| range.invalid_ids(twice))
+        .sum()
+}
+
+pub struct Range {
+    min: u64,
+    max: u64,
+}
+
+impl Range {
+    /// Reads a range of IDs like `11-22`.
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let (min, max) = split_once(s, "-")?;
+        let min = number(min.trim())?;
+        let max = number(max.trim())?;
+        Ok(Range { min, max })
+    }
+
+    fn invalid_ids(&self, twice: bool) -> Vec<u64> {
+        let mut result = Vec::new();
+
+        for x in self.min..=self.max {
+            if twice {
+                if self.is_invalid_twice(x) {
+                    result.push(x);
+                }
+            } else if self.is_invalid(x) {
+                result.push(x);
+            }
+        }
+
+        result
+    }
+
+    fn is_invalid_twice(&self, n: u64) -> bool {
+        let digits = 1 + n.ilog10();
+        if digits % 2 == 1 {
+            return false;
+        }
+
+        let div = 10u64.pow(digits / 2);
+        n / div == n % div
+    }
+
+    fn is_invalid(&self, n: u64) -> bool {
+        let digits = 1 + n.ilog10();
+
+        for d in 1..=(digits / 2) {
+            if !digits.is_multiple_of(d) {
+                continue;
+            }
+
+            let div = 10u64.pow(d);
+            let repeat = n % div;
+            let mut x = 0;
+            for _ in 0..digits / d {
+                x = x * div + repeat;
+            }
+
+            if x == n {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let ranges = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&ranges, true), 1227775554);
+        assert_eq!(solve(&ranges, false), 4174379265);
+    }
+
+    #[test]
+    fn example2() {
+        let ranges = Puzzle::parse("998-1012").unwrap();
+        assert_eq!(solve(&ranges, false), 2009);
+    }
+}
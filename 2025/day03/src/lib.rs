@@ -0,0 +1,87 @@
+use aoc_parse::{digits, lines};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 3);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    /// The joltage of each battery, bank by bank.
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        lines(input, |line| {
+            let bank = digits(line)?;
+            if bank.len() < 12 {
+                return Err(ParseError::expected(
+                    "a bank of at least 12 batteries",
+                    line,
+                ));
+            }
+            Ok(bank)
+        })
+    }
+
+    fn part_one(banks: &Vec<Vec<u32>>) -> Answer {
+        solve(banks, 2).into()
+    }
+
+    fn part_two(banks: &Vec<Vec<u32>>) -> Answer {
+        solve(banks, 12).into()
+    }
+}
+
+fn solve(banks: &[Vec<u32>], battery_size: usize) -> u64 {
+    banks
+        .iter()
+        .map(|bank| max_joltage(bank, battery_size))
+        .sum()
+}
+
+fn max_joltage(digits: &[u32], battery_size: usize) -> u64 {
+    let mut sum: u64 = 0;
+    let mut start_pos = 0;
+
+    for i in 0..battery_size {
+        let (v, pos) = max_digit(digits, start_pos, digits.len() - battery_size + 1 + i);
+        sum = sum * 10 + (v as u64);
+        start_pos = pos + 1;
+    }
+
+    sum
+}
+
+fn max_digit(digits: &[u32], start: usize, end: usize) -> (u32, usize) {
+    let mut max: u32 = 0;
+    let mut max_pos: usize = 0;
+
+    for (i, v) in digits.iter().enumerate() {
+        if i >= end {
+            break;
+        }
+        if i < start {
+            continue;
+        }
+
+        if *v > max {
+            max = *v;
+            max_pos = i;
+        }
+    }
+
+    (max, max_pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let banks = Puzzle::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&banks, 2), 357);
+        assert_eq!(solve(&banks, 12), 3121910778619);
+    }
+}
@@ -0,0 +1,84 @@
+use aoc_grid::{Grid, Pos};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 4);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(rolls: &Grid<bool>) -> Answer {
+        part_one(rolls).into()
+    }
+
+    fn part_two(rolls: &Grid<bool>) -> Answer {
+        part_two(rolls).into()
+    }
+}
+
+fn part_one(rolls: &Grid<bool>) -> usize {
+    find_removal_candidates(rolls).len()
+}
+
+fn part_two(rolls: &Grid<bool>) -> usize {
+    let mut rolls = rolls.clone();
+    let mut total = 0;
+
+    loop {
+        let to_remove = find_removal_candidates(&rolls);
+        if to_remove.is_empty() {
+            break;
+        }
+
+        total += to_remove.len();
+
+        for pos in to_remove {
+            rolls[pos] = false;
+        }
+    }
+
+    total
+}
+
+fn find_removal_candidates(rolls: &Grid<bool>) -> Vec<Pos> {
+    rolls
+        .iter()
+        .filter(|&(_, &roll)| roll)
+        .filter_map(|(pos, _)| {
+            let neighbours = rolls.neighbours8(pos).filter(|&n| rolls[n]).count();
+
+            if neighbours < 4 {
+                Some(pos)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn parse_input(s: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::try_parse(s, |c| match c {
+        '@' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseError::expected("'@' or '.'", &c.to_string())),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let rolls = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&rolls), 13);
+        assert_eq!(part_two(&rolls), 43);
+    }
+}
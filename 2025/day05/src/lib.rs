@@ -0,0 +1,107 @@
+use aoc_parse::{lines, number, split_once};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 5);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Vec<(u64, u64)>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<(Vec<(u64, u64)>, Vec<u64>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(inventory: &(Vec<(u64, u64)>, Vec<u64>)) -> Answer {
+        solve(inventory).0.into()
+    }
+
+    fn part_two(inventory: &(Vec<(u64, u64)>, Vec<u64>)) -> Answer {
+        solve(inventory).1.into()
+    }
+}
+
+fn solve((ranges, ingredients): &(Vec<(u64, u64)>, Vec<u64>)) -> (usize, u64) {
+    let ranges = merge_ranges(&mut ranges.clone());
+
+    let part_one = ingredients
+        .iter()
+        .filter(|ingredient| is_fresh(&ranges, **ingredient))
+        .count();
+
+    let part_two: u64 = ranges.iter().map(|(min, max)| *max + 1 - *min).sum();
+
+    (part_one, part_two)
+}
+
+fn merge_ranges(ranges: &mut Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    ranges.sort();
+
+    let mut merged = Vec::new();
+    let mut min: Option<u64> = None;
+    let mut max: Option<u64> = None;
+
+    for (x, y) in ranges {
+        if min.is_none() && max.is_none() {
+            min = Some(*x);
+            max = Some(*y);
+            continue;
+        }
+
+        if *x <= max.unwrap() {
+            // The ranges overlap, so extend it, if needed.
+            if *y >= max.unwrap() {
+                max = Some(*y);
+            }
+            continue;
+        }
+
+        // Otherwise, we're no longer overlapping so commit this range and start a new one.
+        merged.push((min.unwrap(), max.unwrap()));
+        min = Some(*x);
+        max = Some(*y);
+    }
+    merged.push((min.unwrap(), max.unwrap()));
+
+    merged
+}
+
+fn parse_input(input: &str) -> Result<(Vec<(u64, u64)>, Vec<u64>), ParseError> {
+    let (ranges, ingredients) = split_once(input, "\n\n")?;
+
+    let ranges: Vec<(u64, u64)> = lines(ranges, |line| {
+        let (min, max) = split_once(line, "-")?;
+        Ok((number(min)?, number(max)?))
+    })?;
+
+    // The ingredients start after the ranges and the blank line
+    let first_ingredient = ranges.len() + 2;
+    let ingredients =
+        lines(ingredients, number).map_err(|err| err.within(first_ingredient, ingredients))?;
+
+    Ok((ranges, ingredients))
+}
+
+fn is_fresh(ranges: &Vec<(u64, u64)>, ingredient: u64) -> bool {
+    for (min, max) in ranges {
+        if ingredient >= *min && ingredient <= *max {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (part_one, part_two) = solve(&parse_input(EXAMPLE).unwrap());
+        assert_eq!(part_one, 3);
+        assert_eq!(part_two, 14);
+    }
+}
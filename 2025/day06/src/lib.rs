@@ -0,0 +1,110 @@
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 6);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<&'a str>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(columns: &Vec<Vec<&str>>) -> Answer {
+        solve(columns, false).into()
+    }
+
+    fn part_two(columns: &Vec<Vec<&str>>) -> Answer {
+        solve(columns, true).into()
+    }
+}
+
+fn solve(columns: &[Vec<&str>], cephalopod: bool) -> u64 {
+    let mut total = 0;
+
+    for col in columns {
+        let (op, col) = col.split_last().unwrap();
+        let op = op.trim();
+        let nums = if cephalopod {
+            parse_cephalopod(col)
+        } else {
+            parse_nums(col)
+        };
+
+        match op {
+            "*" => total += nums.iter().product::<u64>(),
+            "+" => total += nums.iter().sum::<u64>(),
+            _ => panic!("unknown op: {}", op),
+        }
+    }
+
+    total
+}
+
+fn parse_nums(col: &[&str]) -> Vec<u64> {
+    col.iter().map(|num| num.trim().parse().unwrap()).collect()
+}
+
+fn parse_cephalopod(col: &[&str]) -> Vec<u64> {
+    // Iterate through each digit separately.
+    (0..col.first().unwrap().len())
+        .map(|i| {
+            col.iter().fold(0, |acc, row| {
+                let c = row.chars().nth(i).unwrap();
+                match c {
+                    ' ' => acc,
+                    _ => acc * 10 + (c.to_digit(10).unwrap() as u64),
+                }
+            })
+        })
+        .filter(|num| *num > 0)
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+    let mut lines: Vec<&str> = input.lines().collect();
+
+    let mut op_positions: Vec<usize> = lines
+        .last()
+        .ok_or_else(|| ParseError::expected("a line of operators", ""))?
+        .chars()
+        .enumerate()
+        .filter_map(|(x, c)| if c == ' ' { None } else { Some(x) })
+        .collect();
+
+    op_positions.reverse();
+
+    Ok(op_positions
+        .iter()
+        .map(|i| {
+            (0..lines.len())
+                .map(|j| {
+                    if *i == 0 {
+                        return *lines.get(j).unwrap();
+                    }
+
+                    // Split the line at position i.
+                    let line = lines.get_mut(j).unwrap();
+                    let (l, r) = line.split_at(*i);
+                    *line = l;
+                    r
+                })
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let columns = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&columns, false), 4277556);
+        assert_eq!(solve(&columns, true), 3263827);
+    }
+}
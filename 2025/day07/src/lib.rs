@@ -0,0 +1,109 @@
+use aoc_grid::Grid;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 7);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Manifold;
+
+    fn parse(input: &str) -> Result<Manifold, ParseError> {
+        Manifold::parse(input)
+    }
+
+    fn part_one(manifold: &Manifold) -> Answer {
+        solve(manifold).0.into()
+    }
+
+    fn part_two(manifold: &Manifold) -> Answer {
+        solve(manifold).1.into()
+    }
+}
+
+/// Where the beams start along the top of the manifold, and where the
+/// splitters are on each row below it.
+pub struct Manifold {
+    starts: Vec<usize>,
+    splitters: Vec<HashSet<usize>>,
+}
+
+impl Manifold {
+    fn parse(input: &str) -> Result<Manifold, ParseError> {
+        let diagram = Grid::try_parse(input, |c| match c {
+            '.' | 'S' | '^' => Ok(c),
+            _ => Err(ParseError::expected("'.', 'S' or '^'", &c.to_string())),
+        })?;
+
+        let mut rows = diagram.rows();
+        let starts: Vec<usize> = rows
+            .next()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &c)| if c == 'S' { Some(i) } else { None })
+            .collect();
+        if starts.is_empty() {
+            return Err(ParseError::new("expected 'S' on the first line"));
+        }
+
+        let splitters = rows
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(|(i, &c)| if c == '^' { Some(i) } else { None })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Manifold { starts, splitters })
+    }
+}
+
+fn solve(manifold: &Manifold) -> (usize, usize) {
+    let mut beams: HashMap<usize, usize> = manifold.starts.iter().map(|&i| (i, 1)).collect();
+
+    let mut splits = 0;
+
+    for splitters in &manifold.splitters {
+        if splitters.is_empty() {
+            continue;
+        }
+
+        let mut new_beams: HashMap<usize, usize> = HashMap::new();
+
+        for (pos, count) in beams.iter() {
+            if splitters.contains(pos) {
+                splits += 1;
+
+                let n = new_beams.get(&(pos - 1)).unwrap_or(&0);
+                new_beams.insert(pos - 1, n + count);
+
+                let n = new_beams.get(&(pos + 1)).unwrap_or(&0);
+                new_beams.insert(pos + 1, n + count);
+            } else {
+                let n = new_beams.get(pos).unwrap_or(&0);
+                new_beams.insert(*pos, n + count);
+            }
+        }
+
+        beams = new_beams;
+    }
+
+    (splits, beams.values().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let (part_one, part_two) = solve(&Puzzle::parse(EXAMPLE).unwrap());
+        assert_eq!(part_one, 21);
+        assert_eq!(part_two, 40);
+    }
+}
@@ -0,0 +1,156 @@
+use aoc_parse::{lines, number};
+use aoc_runner::{Answer, Day, ParseError, Solution};
+use std::cmp;
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Puzzle>(2025, 8);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Playground;
+
+    fn parse(input: &str) -> Result<Playground, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(playground: &Playground) -> Answer {
+        part_one(playground, 1000).into()
+    }
+
+    fn part_two(playground: &Playground) -> Answer {
+        part_two(playground).into()
+    }
+}
+
+/// The circuit each junction box starts in, and every pair of boxes, closest
+/// first.
+type Playground = (HashMap<Point, usize>, Vec<(Point, Point, i64)>);
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Point {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let coords: Vec<i64> = input
+            .trim()
+            .split(',')
+            .map(number)
+            .collect::<Result<_, _>>()?;
+        match coords[..] {
+            [x, y, z] => Ok(Point { x, y, z }),
+            _ => Err(ParseError::expected("three coordinates", input)),
+        }
+    }
+
+    fn distance(&self, other: &Point) -> i64 {
+        (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)
+    }
+}
+
+fn part_one((circuits, distances): &Playground, join_limit: usize) -> usize {
+    let mut circuits = circuits.clone();
+
+    for i in 0..join_limit {
+        let (a, b, _) = distances.get(i).unwrap();
+
+        let circuit_a = *circuits.get(a).unwrap();
+        let circuit_b = *circuits.get(b).unwrap();
+        if circuit_a == circuit_b {
+            continue;
+        }
+
+        let min = cmp::min(circuit_a, circuit_b);
+        let max = cmp::max(circuit_a, circuit_b);
+
+        for val in circuits.values_mut() {
+            if *val == max {
+                *val = min;
+            }
+        }
+    }
+
+    let mut circuit_sizes: HashMap<usize, usize> = HashMap::new();
+    for (_, c) in circuits.iter() {
+        let current = circuit_sizes.get(c).unwrap_or(&0);
+        circuit_sizes.insert(*c, current + 1);
+    }
+
+    let mut circuit_sizes: Vec<usize> = circuit_sizes.values().copied().collect();
+    circuit_sizes.sort();
+
+    let mut product = 1;
+    for _ in 0..3 {
+        product *= circuit_sizes.pop().unwrap();
+    }
+    product
+}
+
+fn part_two((circuits, distances): &Playground) -> i64 {
+    let mut circuits = circuits.clone();
+
+    let mut circuit_count = circuits.len();
+
+    let mut i = 0;
+    loop {
+        let (a, b, _) = distances.get(i).unwrap();
+        i += 1;
+
+        let circuit_a = *circuits.get(a).unwrap();
+        let circuit_b = *circuits.get(b).unwrap();
+        if circuit_a == circuit_b {
+            continue;
+        }
+
+        let min = cmp::min(circuit_a, circuit_b);
+        let max = cmp::max(circuit_a, circuit_b);
+
+        for val in circuits.values_mut() {
+            if *val == max {
+                *val = min;
+            }
+        }
+
+        circuit_count -= 1;
+        if circuit_count == 1 {
+            return a.x * b.x;
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Playground, ParseError> {
+    let points: Vec<Point> = lines(input, Point::parse)?;
+
+    let circuits: HashMap<Point, usize> = points.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+
+    let mut distances: Vec<(Point, Point, i64)> = Vec::new();
+
+    for i in 0..points.len() - 1 {
+        for j in i + 1..points.len() {
+            let a = *points.get(i).unwrap();
+            let b = *points.get(j).unwrap();
+            distances.push((a, b, a.distance(&b)));
+        }
+    }
+    distances.sort_by_key(|a| a.2);
+
+    Ok((circuits, distances))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let playground = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&playground, 10), 40);
+        assert_eq!(part_two(&playground), 25272);
+    }
+}
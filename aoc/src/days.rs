@@ -0,0 +1,146 @@
+//! Every day that has a solution, in order.
+
+use aoc_runner::Day;
+
+pub const DAYS: &[Day] = &[
+    aoc_2015_day01::DAY,
+    aoc_2015_day02::DAY,
+    aoc_2015_day03::DAY,
+    aoc_2017_day01::DAY,
+    aoc_2017_day02::DAY,
+    aoc_2018_day01::DAY,
+    aoc_2018_day02::DAY,
+    aoc_2018_day03::DAY,
+    aoc_2018_day04::DAY,
+    aoc_2018_day05::DAY,
+    aoc_2018_day06::DAY,
+    aoc_2018_day08::DAY,
+    aoc_2018_day09::DAY,
+    aoc_2018_day10::DAY,
+    aoc_2018_day11::DAY,
+    aoc_2018_day12::DAY,
+    aoc_2018_day13::DAY,
+    aoc_2018_day14::DAY,
+    aoc_2018_day15::DAY,
+    aoc_2018_day16::DAY,
+    aoc_2018_day18::DAY,
+    aoc_2019_day01::DAY,
+    aoc_2019_day02::DAY,
+    aoc_2019_day03::DAY,
+    aoc_2019_day04::DAY,
+    aoc_2019_day05::DAY,
+    aoc_2019_day06::DAY,
+    aoc_2019_day07::DAY,
+    aoc_2019_day08::DAY,
+    aoc_2019_day09::DAY,
+    aoc_2019_day10::DAY,
+    aoc_2019_day11::DAY,
+    aoc_2019_day12::DAY,
+    aoc_2019_day13::DAY,
+    aoc_2019_day14::DAY,
+    aoc_2019_day15::DAY,
+    aoc_2019_day16::DAY,
+    aoc_2019_day17::DAY,
+    aoc_2019_day19::DAY,
+    aoc_2019_day21::DAY,
+    aoc_2019_day23::DAY,
+    aoc_2019_day25::DAY,
+    aoc_2020_day01::DAY,
+    aoc_2020_day02::DAY,
+    aoc_2020_day03::DAY,
+    aoc_2020_day04::DAY,
+    aoc_2020_day05::DAY,
+    aoc_2020_day06::DAY,
+    aoc_2020_day07::DAY,
+    aoc_2020_day08::DAY,
+    aoc_2020_day09::DAY,
+    aoc_2020_day10::DAY,
+    aoc_2020_day11::DAY,
+    aoc_2021_day01::DAY,
+    aoc_2021_day02::DAY,
+    aoc_2021_day03::DAY,
+    aoc_2021_day04::DAY,
+    aoc_2021_day05::DAY,
+    aoc_2021_day06::DAY,
+    aoc_2021_day07::DAY,
+    aoc_2021_day08::DAY,
+    aoc_2021_day09::DAY,
+    aoc_2021_day10::DAY,
+    aoc_2021_day11::DAY,
+    aoc_2021_day12::DAY,
+    aoc_2021_day13::DAY,
+    aoc_2021_day14::DAY,
+    aoc_2021_day15::DAY,
+    aoc_2021_day16::DAY,
+    aoc_2021_day17::DAY,
+    aoc_2021_day21::DAY,
+    aoc_2022_day01::DAY,
+    aoc_2022_day02::DAY,
+    aoc_2022_day03::DAY,
+    aoc_2022_day04::DAY,
+    aoc_2022_day05::DAY,
+    aoc_2022_day06::DAY,
+    aoc_2022_day07::DAY,
+    aoc_2022_day08::DAY,
+    aoc_2022_day09::DAY,
+    aoc_2022_day10::DAY,
+    aoc_2022_day11::DAY,
+    aoc_2022_day12::DAY,
+    aoc_2022_day13::DAY,
+    aoc_2022_day14::DAY,
+    aoc_2022_day15::DAY,
+    aoc_2022_day16::DAY,
+    aoc_2022_day18::DAY,
+    aoc_2023_day01::DAY,
+    aoc_2023_day02::DAY,
+    aoc_2023_day03::DAY,
+    aoc_2023_day04::DAY,
+    aoc_2023_day05::DAY,
+    aoc_2023_day06::DAY,
+    aoc_2023_day07::DAY,
+    aoc_2023_day08::DAY,
+    aoc_2023_day09::DAY,
+    aoc_2023_day10::DAY,
+    aoc_2023_day11::DAY,
+    aoc_2023_day12::DAY,
+    aoc_2023_day13::DAY,
+    aoc_2023_day14::DAY,
+    aoc_2023_day15::DAY,
+    aoc_2023_day16::DAY,
+    aoc_2023_day17::DAY,
+    aoc_2023_day19::DAY,
+    aoc_2023_day20::DAY,
+    aoc_2024_day01::DAY,
+    aoc_2024_day02::DAY,
+    aoc_2024_day03::DAY,
+    aoc_2024_day04::DAY,
+    aoc_2024_day05::DAY,
+    aoc_2024_day06::DAY,
+    aoc_2024_day07::DAY,
+    aoc_2024_day08::DAY,
+    aoc_2024_day09::DAY,
+    aoc_2024_day10::DAY,
+    aoc_2024_day11::DAY,
+    aoc_2024_day12::DAY,
+    aoc_2024_day13::DAY,
+    aoc_2024_day14::DAY,
+    aoc_2024_day15::DAY,
+    aoc_2024_day16::DAY,
+    aoc_2024_day17::DAY,
+    aoc_2024_day18::DAY,
+    aoc_2024_day19::DAY,
+    aoc_2024_day20::DAY,
+    aoc_2024_day21::DAY,
+    aoc_2024_day22::DAY,
+    aoc_2024_day23::DAY,
+    aoc_2024_day24::DAY,
+    aoc_2024_day25::DAY,
+    aoc_2025_day01::DAY,
+    aoc_2025_day02::DAY,
+    aoc_2025_day03::DAY,
+    aoc_2025_day04::DAY,
+    aoc_2025_day05::DAY,
+    aoc_2025_day06::DAY,
+    aoc_2025_day07::DAY,
+    aoc_2025_day08::DAY,
+];
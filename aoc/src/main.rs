@@ -0,0 +1,612 @@
+//! Runs any of the days from one place.
+//!
+//! Usage:
+//!   aoc run <year> <day> [--part <n>] [--input <path>]
+//!   aoc run --year <year>
+//!   aoc run --all
+//!   aoc verify [<year> <day> | --year <year>] [--record]
+//!   aoc bench [<year> <day> | --year <year>] [--runs <n>]
+//!   aoc new <year> <day>
+//!   aoc import [<year> <day> | --year <year>]
+//!   aoc fetch <year> <day>
+//!   aoc submit <year> <day> <part> [<answer>]
+//!
+//! A day's input is read from the store in `inputs/` unless `--input` says
+//! otherwise. When running more than one day, any without an input are
+//! skipped.
+//!
+//! `verify` checks the answers against the ones in `answers.txt`, reporting
+//! any that are wrong, missing, panicked or couldn't be parsed. With
+//! `--record` it adds the missing ones to the file.
+//!
+//! `bench` times each day's parsing on its own, and each of its parts from
+//! input that's already been parsed, so the parts' times don't include the
+//! parsing. It compares them with the previous run and finishes with the
+//! slowest days of each year. A day that panics is reported as `verify`
+//! reports it, and the rest are still timed and added to the history.
+//!
+//! `new` starts a day from the template in `template/` and adds it to the
+//! table of days.
+//!
+//! `import` copies any inputs left in the days' own directories into the
+//! store, leaving alone any day the store already has a different input for.
+//!
+//! `fetch` downloads a day's input into the store, and its example into the
+//! day's directory if it has one, logging in with the token in `AOC_SESSION`
+//! or `.session`. Neither is downloaded again once it's there.
+//!
+//! `submit` sends an answer, or the day's own answer if none is given, and
+//! records it in `answers.txt` if it's right. Anything the earlier replies
+//! rule out isn't sent.
+
+mod days;
+mod new;
+
+use aoc_client::{Client, Curl, Fetch, Verdict};
+use aoc_runner::bench::{self, History, Settings, Step};
+use aoc_runner::{
+    check, day_dirs, format_duration, print_part, root, Answer, Answers, Day, Error, Import,
+    Inputs, Outcome,
+};
+use days::DAYS;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, panic, process};
+
+const USAGE: &str = "\
+usage: aoc run <year> <day> [--part <n>] [--input <path>]
+       aoc run --year <year>
+       aoc run --all
+       aoc verify [<year> <day> | --year <year>] [--record]
+       aoc bench [<year> <day> | --year <year>] [--runs <n>]
+       aoc new <year> <day>
+       aoc import [<year> <day> | --year <year>]
+       aoc fetch <year> <day>
+       aoc submit <year> <day> <part> [<answer>]";
+
+#[derive(Debug, Default, PartialEq)]
+enum Command {
+    #[default]
+    Run,
+    Verify,
+    Bench,
+    Import,
+}
+
+/// What to do, from the command line.
+#[derive(Debug, Default)]
+struct Options {
+    command: Command,
+    year: Option<u16>,
+    day: Option<u8>,
+    all: bool,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    record: bool,
+    runs: Option<usize>,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Some(("submit", rest)) = args.split_first().map(|(c, rest)| (c.as_str(), rest)) {
+        let (year, day, part, answer) = match rest {
+            [year, day, part] => (year, day, part, None),
+            [year, day, part, answer] => (year, day, part, Some(answer.as_str())),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        };
+        match submit(year, day, part, answer) {
+            Ok(Verdict::Correct) => {}
+            Ok(_) => process::exit(1),
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some((command @ ("new" | "fetch"), rest)) =
+        args.split_first().map(|(c, rest)| (c.as_str(), rest))
+    {
+        let [year, day] = rest else {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        };
+        let done = match command {
+            "new" => new_day(year, day),
+            _ => fetch(year, day),
+        };
+        if let Err(err) = done {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+        return;
+    }
+
+    let options = match args.split_first() {
+        Some((command, rest)) if command == "run" => parse_options(Command::Run, rest),
+        Some((command, rest)) if command == "verify" => parse_options(Command::Verify, rest),
+        Some((command, rest)) if command == "bench" => parse_options(Command::Bench, rest),
+        Some((command, rest)) if command == "import" => parse_options(Command::Import, rest),
+        _ => Err(USAGE.to_string()),
+    }
+    .unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(2);
+    });
+
+    if options.command == Command::Import {
+        return import(&options);
+    }
+
+    let days = select(&options).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+
+    match options.command {
+        Command::Run => {}
+        Command::Verify => {
+            if !verify(&days, options.record) {
+                process::exit(1);
+            }
+            return;
+        }
+        Command::Bench => return benchmark(&days, options.runs),
+        Command::Import => unreachable!(),
+    }
+
+    if let [day] = days.as_slice() {
+        if let Err(err) = run_day(day, &options) {
+            eprintln!("{}: {}", day.name(), err);
+            process::exit(1);
+        }
+        return;
+    }
+
+    let mut total = Duration::ZERO;
+    let mut skipped = Vec::new();
+    for day in &days {
+        println!("== {}", day.name());
+        match run_day(day, &options) {
+            Ok(elapsed) => total += elapsed,
+            Err(err) => {
+                println!("{}", err);
+                skipped.push(day.name());
+            }
+        }
+        println!();
+    }
+
+    println!(
+        "Ran {} of {} days in {}",
+        days.len() - skipped.len(),
+        days.len(),
+        format_duration(total)
+    );
+    if !skipped.is_empty() {
+        println!("Skipped {}: {}", skipped.len(), skipped.join(", "));
+    }
+}
+
+fn parse_options(command: Command, args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        command,
+        ..Options::default()
+    };
+    let mut positional = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| format!("{} needs a value\n{}", flag, USAGE))
+        };
+
+        match arg.as_str() {
+            "--all" => options.all = true,
+            "--year" => options.year = Some(parse_number(value(arg)?)?),
+            "--part" => options.part = Some(parse_number(value(arg)?)?),
+            "--input" => options.input = Some(PathBuf::from(value(arg)?)),
+            "--record" => options.record = true,
+            "--runs" => options.runs = Some(parse_number(value(arg)?)?),
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option {}\n{}", arg, USAGE));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    match positional.as_slice() {
+        [] => {}
+        [year, day] if options.year.is_none() => {
+            options.year = Some(parse_number(year)?);
+            options.day = Some(parse_number(day)?);
+        }
+        _ => return Err(USAGE.to_string()),
+    }
+
+    let single = options.day.is_some();
+    if options.command != Command::Run {
+        // Everything is checked unless asked otherwise
+        options.all |= options.year.is_none();
+        if options.part.is_some() || options.input.is_some() {
+            return Err(format!("--part and --input only work with run\n{}", USAGE));
+        }
+    }
+    if options.record && options.command != Command::Verify {
+        return Err(format!("--record only works with verify\n{}", USAGE));
+    }
+    if options.runs.is_some() && options.command != Command::Bench {
+        return Err(format!("--runs only works with bench\n{}", USAGE));
+    }
+
+    if options.all == options.year.is_some() {
+        return Err(USAGE.to_string());
+    }
+    if !single && (options.part.is_some() || options.input.is_some()) {
+        return Err(format!(
+            "--part and --input only work with a single day\n{}",
+            USAGE
+        ));
+    }
+
+    Ok(options)
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse()
+        .map_err(|_| format!("expected a number, got {}", arg))
+}
+
+/// Starts a new day from the template.
+fn new_day(year: &str, day: &str) -> Result<(), String> {
+    let (year, day) = (parse_number(year)?, parse_number(day)?);
+    let dir = new::new_day(year, day)?;
+
+    println!("Created {}", dir.display());
+    println!(
+        "The example goes in example.txt, and the puzzle input in {}",
+        Inputs::open().path(year, day).display()
+    );
+    Ok(())
+}
+
+/// Downloads a day's input, and its example if the day has been started.
+fn fetch(year: &str, day: &str) -> Result<(), String> {
+    let (year, day) = (parse_number(year)?, parse_number(day)?);
+    let client = Client::new(Curl::default(), aoc_client::session(), Inputs::open());
+    let report = |what: &str, fetch: Fetch| match fetch {
+        Fetch::Cached(path) => println!("Already have the {} in {}", what, path.display()),
+        Fetch::Fetched(path) => println!("Saved the {} to {}", what, path.display()),
+    };
+
+    report(
+        "input",
+        client
+            .fetch_input(year, day)
+            .map_err(|err| err.to_string())?,
+    );
+
+    let dir = root().join(format!("{}/day{:02}", year, day));
+    if dir.is_dir() {
+        let example = client
+            .fetch_example(year, day, &dir.join("example.txt"))
+            .map_err(|err| err.to_string())?;
+        report("example", example);
+    }
+
+    Ok(())
+}
+
+/// Submits an answer for one part, working it out first if it isn't given,
+/// and records it if it's right.
+fn submit(year: &str, day: &str, part: &str, answer: Option<&str>) -> Result<Verdict, String> {
+    let options = Options {
+        year: Some(parse_number(year)?),
+        day: Some(parse_number(day)?),
+        ..Options::default()
+    };
+    let part = parse_number(part)?;
+    let day = select(&options)?[0];
+
+    let mut answers = Answers::load().map_err(|err| err.to_string())?;
+    if let Some(accepted) = answers.get(&day, part) {
+        return Err(format!(
+            "{} part {} was already solved with {:?}",
+            day.name(),
+            part,
+            accepted
+        ));
+    }
+
+    let answer = match answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let solve = day
+                .part(part)
+                .ok_or_else(|| format!("there's no part {}", part))?;
+            let input = day.read_input(None).map_err(|err| err.to_string())?;
+            match solve(&input).map_err(|err| day.parse_error(err).to_string())? {
+                Answer::Drawing(drawing) => {
+                    return Err(format!(
+                        "{} part {} is drawn, so read it and give the letters:\n{}",
+                        day.name(),
+                        part,
+                        drawing
+                    ))
+                }
+                answer => answer.to_string(),
+            }
+        }
+    };
+
+    let client = Client::new(Curl::default(), aoc_client::session(), Inputs::open());
+    let verdict = client
+        .submit(day.year, day.day, part, &answer)
+        .map_err(|err| format!("{}: {}", answer, err))?;
+    println!("{}: {}", answer, verdict);
+
+    if verdict == Verdict::Correct {
+        answers.insert(&day, part, answer);
+        answers.save().map_err(|err| err.to_string())?;
+        println!("Recorded in {}", Answers::path().display());
+    }
+
+    Ok(verdict)
+}
+
+/// The days picked by the options, in order.
+fn select(options: &Options) -> Result<Vec<Day>, String> {
+    let days: Vec<Day> = DAYS
+        .iter()
+        .filter(|day| options.year.is_none_or(|year| day.year == year))
+        .filter(|day| options.day.is_none_or(|d| day.day == d))
+        .copied()
+        .collect();
+
+    match (options.year, options.day) {
+        _ if !days.is_empty() => Ok(days),
+        (Some(year), Some(day)) => Err(format!("no solution for {}/day{:02}", year, day)),
+        (Some(year), None) => Err(format!("no solutions for {}", year)),
+        _ => Err("no solutions".to_string()),
+    }
+}
+
+/// Runs the parts of one day, returning how long they took between them.
+fn run_day(day: &Day, options: &Options) -> Result<Duration, String> {
+    let input = day
+        .read_input(options.input.as_deref())
+        .map_err(|err| err.to_string())?;
+
+    let parts: Vec<_> = match options.part {
+        Some(part) => {
+            let solve = day
+                .part(part)
+                .ok_or_else(|| format!("there's no part {}", part))?;
+            vec![(part, solve)]
+        }
+        None => day.parts().collect(),
+    };
+
+    parts
+        .into_iter()
+        .map(|(part, solve)| print_part(day, part, solve, &input))
+        .sum::<Result<Duration, _>>()
+        .map_err(|err| match err {
+            // The day is given with the error already
+            Error::Parse(_, _, err) => err.to_string(),
+            err => err.to_string(),
+        })
+}
+
+/// Checks the days against the accepted answers, reporting anything that
+/// isn't right. Returns whether nothing was wrong, panicked or unparsed.
+fn verify(days: &[Day], record: bool) -> bool {
+    let mut answers = Answers::load().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+
+    // Panics are reported with the rest, so keep the hook from printing them
+    panic::set_hook(Box::new(|_| {}));
+
+    let (mut correct, mut wrong, mut missing, mut panicked) = (0, 0, 0, 0);
+    let mut unparsed = 0;
+    let mut recorded = 0;
+    let mut skipped = Vec::new();
+
+    for day in days {
+        let Ok(input) = day.read_input(None) else {
+            skipped.push(day.name());
+            continue;
+        };
+
+        for check in check(day, &input, &answers) {
+            let name = format!("{} part {}", day.name(), check.part);
+            match check.outcome {
+                Outcome::Correct => correct += 1,
+                Outcome::Wrong(expected) => {
+                    wrong += 1;
+                    println!(
+                        "{}: wrong answer {:?}, expected {:?}",
+                        name,
+                        check.answer.unwrap(),
+                        expected
+                    );
+                }
+                Outcome::Missing => {
+                    missing += 1;
+                    let answer = check.answer.unwrap();
+                    println!("{}: no answer recorded, got {:?}", name, answer);
+                    if record {
+                        answers.insert(day, check.part, answer);
+                        recorded += 1;
+                    }
+                }
+                Outcome::Panicked(message) => {
+                    panicked += 1;
+                    println!("{}: panicked: {}", name, message);
+                }
+                Outcome::Unparsed(err) => {
+                    unparsed += 1;
+                    println!("{}: {}", name, err);
+                }
+            }
+        }
+    }
+
+    let _ = panic::take_hook();
+
+    println!(
+        "{} correct, {} wrong, {} missing, {} panicked, {} unparsed",
+        correct, wrong, missing, panicked, unparsed
+    );
+    if !skipped.is_empty() {
+        println!("No input for {}: {}", skipped.len(), skipped.join(", "));
+    }
+
+    if recorded > 0 {
+        answers.save().unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1);
+        });
+        println!(
+            "Recorded {} answers in {}",
+            recorded,
+            Answers::path().display()
+        );
+    }
+
+    wrong == 0 && panicked == 0 && unparsed == 0
+}
+
+/// Benchmarks the days, flagging anything that's got slower since the last
+/// run, and adds the results to the history.
+fn benchmark(days: &[Day], runs: Option<usize>) {
+    let mut history = History::load().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+
+    let mut settings = Settings::default();
+    if let Some(runs) = runs {
+        settings.runs = runs;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let mut records = Vec::new();
+    let mut skipped = Vec::new();
+
+    // Panics are reported with the rest, so keep the hook from printing them
+    panic::set_hook(Box::new(|_| {}));
+
+    for day in days {
+        let Ok(input) = day.read_input(None) else {
+            skipped.push(day.name());
+            continue;
+        };
+
+        let results = match bench::bench(day, &input, &settings, timestamp) {
+            Ok(results) => results,
+            Err(err) => {
+                println!("{}", err);
+                continue;
+            }
+        };
+        for record in &results {
+            let mut line = format!(
+                "{} {:<8} median {:>9}  p95 {:>9}",
+                day.name(),
+                match record.step {
+                    Step::Parse => "parse",
+                    Step::Part(1) => "part one",
+                    Step::Part(_) => "part two",
+                },
+                format_duration(record.stats.median),
+                format_duration(record.stats.p95)
+            );
+
+            if let Some(previous) = history.previous(record) {
+                if let Some(ratio) = bench::regression(&previous.stats, &record.stats) {
+                    line += &format!(
+                        "  REGRESSION: {:.0}% slower than {}",
+                        (ratio - 1.0) * 100.0,
+                        format_duration(previous.stats.median)
+                    );
+                }
+            }
+
+            println!("{}", line);
+        }
+
+        records.extend(results);
+    }
+
+    let _ = panic::take_hook();
+
+    if !skipped.is_empty() {
+        println!("No input for {}: {}", skipped.len(), skipped.join(", "));
+    }
+
+    for (year, slowest) in bench::slowest(&records, 5) {
+        println!("\nSlowest in {}:", year);
+        for (i, (name, total)) in slowest.iter().enumerate() {
+            println!("{:>3}. {} {:>9}", i + 1, name, format_duration(*total));
+        }
+    }
+
+    history.append(&records).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+}
+
+/// Copies the inputs left in the days' directories into the store. Every
+/// day with a directory is looked at, whether or not it has a solution.
+fn import(options: &Options) {
+    let inputs = Inputs::open();
+    let (mut imported, mut stored, mut conflicts) = (0, 0, 0);
+
+    let days = day_dirs(&root()).into_iter().filter(|&(year, day, _)| {
+        options.year.is_none_or(|y| year == y) && options.day.is_none_or(|d| day == d)
+    });
+    for (year, day, dir) in days {
+        let name = format!("{}/day{:02}", year, day);
+        match inputs.import(year, day, &dir) {
+            Ok(Import::Imported(stray)) => {
+                imported += 1;
+                println!("{}: imported {}", name, stray.display());
+            }
+            Ok(Import::AlreadyStored) => stored += 1,
+            Ok(Import::Conflict(stray)) => {
+                conflicts += 1;
+                println!(
+                    "{}: {} differs from {}, so it was left alone",
+                    name,
+                    stray.display(),
+                    inputs.path(year, day).display()
+                );
+            }
+            Ok(Import::NotFound) => {}
+            Err(err) => {
+                eprintln!("{}: {}", name, err);
+                process::exit(1);
+            }
+        }
+    }
+
+    println!(
+        "Imported {} inputs into {}, {} were already there and {} differed",
+        imported,
+        inputs.dir().display(),
+        stored,
+        conflicts
+    );
+}
@@ -0,0 +1,197 @@
+//! Starting a new day from the template in `template/`.
+//!
+//! The template is an ordinary crate in the workspace, so it's kept building
+//! and its tests passing. Making a day from it copies its files, swapping
+//! the template's crate name and its placeholder year and day for the new
+//! ones, and adds the day to the runner's table.
+
+use aoc_runner::{root, STRAY_NAMES};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Creates `<year>/day<nn>` from the template and registers it with the
+/// runner, returning the new directory.
+pub fn new_day(year: u16, day: u8) -> Result<PathBuf, String> {
+    if year < 2015 {
+        return Err(format!("there was no advent of code in {}", year));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("there's no day {}", day));
+    }
+
+    let root = root();
+    let dir = root.join(format!("{}/day{:02}", year, day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let template = root.join("template");
+    let mut files = Vec::new();
+    for path in template_files(&template)? {
+        let contents = read(&path)?;
+        files.push((dir.join(path.strip_prefix(&template).unwrap()), contents));
+    }
+
+    // A template that's drifted from what's expected would make a day that
+    // still thinks it's the template, so stop before writing anything
+    let substitutions = substitutions(year, day);
+    for (from, _) in &substitutions {
+        if !files.iter().any(|(_, contents)| contents.contains(from)) {
+            return Err(format!("the template never uses {:?}", from));
+        }
+    }
+
+    for (path, contents) in &files {
+        fs::create_dir_all(path.parent().unwrap()).map_err(|err| write_error(path, err))?;
+        fs::write(path, render(contents, &substitutions)).map_err(|err| write_error(path, err))?;
+    }
+
+    register(
+        &root.join("aoc/Cargo.toml"),
+        &format!(
+            "aoc-{}-day{:02} = {{ path = \"../{}/day{:02}\" }}",
+            year, day, year, day
+        ),
+        |line| line.starts_with("aoc-20"),
+    )?;
+    register(
+        &root.join("aoc/src/days.rs"),
+        &format!("    aoc_{}_day{:02}::DAY,", year, day),
+        |line| line.starts_with("    aoc_20"),
+    )?;
+
+    Ok(dir)
+}
+
+/// What to replace in the template, and with what.
+fn substitutions(year: u16, day: u8) -> Vec<(&'static str, String)> {
+    vec![
+        ("aoc-template", format!("aoc-{}-day{:02}", year, day)),
+        ("aoc_template", format!("aoc_{}_day{:02}", year, day)),
+        ("(0, 0)", format!("({}, {})", year, day)),
+    ]
+}
+
+fn render(contents: &str, substitutions: &[(&str, String)]) -> String {
+    substitutions
+        .iter()
+        .fold(contents.to_string(), |contents, (from, to)| {
+            contents.replace(from, to)
+        })
+}
+
+/// Every file in the template other than its build output and input.
+fn template_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    let entries = fs::read_dir(dir).map_err(|err| format!("{}: {}", dir.display(), err))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("{}: {}", dir.display(), err))?
+            .path();
+        let name = path.file_name().unwrap().to_string_lossy();
+        if name == "target" || STRAY_NAMES.contains(&&*name) {
+            continue;
+        }
+
+        if path.is_dir() {
+            files.extend(template_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Adds `line` to the file among the lines that `is_entry` picks out,
+/// keeping them in order.
+fn register(path: &Path, line: &str, is_entry: impl Fn(&str) -> bool) -> Result<(), String> {
+    let contents = read(path)?;
+    let contents = insert_sorted(&contents, line, is_entry)
+        .ok_or_else(|| format!("{}: nowhere to add {:?}", path.display(), line.trim()))?;
+    fs::write(path, contents).map_err(|err| write_error(path, err))
+}
+
+fn insert_sorted(contents: &str, line: &str, is_entry: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&line) {
+        return Some(contents.to_string());
+    }
+
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(lines[i])).collect();
+    let at = entries
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .or_else(|| Some(entries.last()? + 1))?;
+
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+fn write_error(path: &Path, err: std::io::Error) -> String {
+    format!("couldn't write {}: {}", path.display(), err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let template = fs::read_to_string(root().join("template/src/lib.rs")).unwrap();
+        let rendered = render(&template, &substitutions(2025, 9));
+        assert!(rendered.contains("Day::new::<Puzzle>(2025, 9);"));
+
+        let main = fs::read_to_string(root().join("template/src/main.rs")).unwrap();
+        assert_eq!(
+            render(&main, &substitutions(2025, 9)),
+            "fn main() {\n    aoc_runner::run(&aoc_2025_day09::DAY);\n}\n"
+        );
+
+        let manifest = fs::read_to_string(root().join("template/Cargo.toml")).unwrap();
+        assert!(render(&manifest, &substitutions(2025, 9)).contains("name = \"aoc-2025-day09\""));
+    }
+
+    #[test]
+    fn template_files_test() {
+        let template = root().join("template");
+        let files: Vec<_> = template_files(&template)
+            .unwrap()
+            .into_iter()
+            .map(|path| path.strip_prefix(&template).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            files,
+            ["Cargo.toml", "example.txt", "src/lib.rs", "src/main.rs"].map(PathBuf::from)
+        );
+    }
+
+    #[test]
+    fn insert_sorted_test() {
+        let days = "[\n    aoc_2024_day25::DAY,\n    aoc_2025_day08::DAY,\n];\n";
+        let is_entry = |line: &str| line.starts_with("    aoc_");
+
+        assert_eq!(
+            insert_sorted(days, "    aoc_2025_day09::DAY,", is_entry).unwrap(),
+            "[\n    aoc_2024_day25::DAY,\n    aoc_2025_day08::DAY,\n    aoc_2025_day09::DAY,\n];\n"
+        );
+        assert_eq!(
+            insert_sorted(days, "    aoc_2025_day01::DAY,", is_entry).unwrap(),
+            "[\n    aoc_2024_day25::DAY,\n    aoc_2025_day01::DAY,\n    aoc_2025_day08::DAY,\n];\n"
+        );
+        assert_eq!(
+            insert_sorted(days, "    aoc_2025_day08::DAY,", is_entry).unwrap(),
+            days
+        );
+        assert_eq!(
+            insert_sorted("[\n];\n", "    aoc_2025_day09::DAY,", is_entry),
+            None
+        );
+    }
+}
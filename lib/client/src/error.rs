@@ -0,0 +1,63 @@
+use crate::Refusal;
+use std::fmt;
+use std::time::Duration;
+
+/// Everything that can go wrong talking to the site.
+#[derive(Debug)]
+pub enum Error {
+    /// There's no session token to log in with.
+    NoSession,
+    /// The puzzle doesn't unlock for a while yet.
+    Locked { year: u16, day: u8, wait: Duration },
+    /// The site didn't recognise the session token.
+    BadSession,
+    /// The site asked for fewer requests.
+    RateLimited,
+    /// Anything else the site said that wasn't a success.
+    Http { url: String, status: u16 },
+    /// The request never got an answer.
+    Transport(String),
+    /// The puzzle page didn't have an example in it.
+    NoExample,
+    /// An answer wasn't submitted, because of what's been said before.
+    Refused(Refusal),
+    /// The reply to an answer didn't say whether it was right.
+    UnknownReply,
+    /// Something went wrong storing what was fetched.
+    Store(aoc_runner::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoSession => write!(
+                f,
+                "no session token, so set AOC_SESSION or put it in {}",
+                crate::session_path().display()
+            ),
+            Error::Locked { year, day, wait } => write!(
+                f,
+                "{}/day{:02} doesn't unlock for another {}s",
+                year,
+                day,
+                wait.as_secs()
+            ),
+            Error::BadSession => write!(f, "the session token wasn't accepted"),
+            Error::RateLimited => write!(f, "too many requests, so try again later"),
+            Error::Http { url, status } => write!(f, "{} gave status {}", url, status),
+            Error::Transport(err) => write!(f, "request failed: {}", err),
+            Error::NoExample => write!(f, "couldn't find an example on the puzzle page"),
+            Error::Refused(refusal) => write!(f, "not submitting: {}", refusal),
+            Error::UnknownReply => write!(f, "couldn't tell what the site made of the answer"),
+            Error::Store(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<aoc_runner::Error> for Error {
+    fn from(err: aoc_runner::Error) -> Error {
+        Error::Store(err)
+    }
+}
@@ -0,0 +1,152 @@
+//! A stand-in for the site, for testing against.
+
+use crate::{Error, Method, Request, Response, Transport};
+use aoc_runner::day_dirs;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Serves canned inputs and puzzle pages to whoever has the right session
+/// token, judges answers like the site does, and remembers every request it
+/// was sent.
+#[derive(Debug, Default)]
+pub struct FakeServer {
+    session: String,
+    inputs: BTreeMap<(u16, u8), String>,
+    pages: BTreeMap<(u16, u8), String>,
+    answers: BTreeMap<(u16, u8, u8), String>,
+    solved: RefCell<BTreeSet<(u16, u8, u8)>>,
+    cooldown: Option<Duration>,
+    requests: RefCell<Vec<Request>>,
+}
+
+impl FakeServer {
+    pub fn new(session: &str) -> FakeServer {
+        FakeServer {
+            session: session.to_string(),
+            ..FakeServer::default()
+        }
+    }
+
+    /// Serves the fixtures under `dir`, laid out as
+    /// `<year>/day<nn>/input.txt` and `<year>/day<nn>/page.html`.
+    pub fn with_fixtures(mut self, dir: &Path) -> FakeServer {
+        for (year, day, path) in day_dirs(dir) {
+            if let Ok(input) = fs::read_to_string(path.join("input.txt")) {
+                self.inputs.insert((year, day), input);
+            }
+            if let Ok(page) = fs::read_to_string(path.join("page.html")) {
+                self.pages.insert((year, day), page);
+            }
+        }
+
+        self
+    }
+
+    pub fn with_input(mut self, year: u16, day: u8, input: &str) -> FakeServer {
+        self.inputs.insert((year, day), input.to_string());
+        self
+    }
+
+    /// Accepts `answer` for the part, with anything else being wrong.
+    pub fn with_answer(mut self, year: u16, day: u8, part: u8, answer: &str) -> FakeServer {
+        self.answers.insert((year, day, part), answer.to_string());
+        self
+    }
+
+    /// Turns every answer away as too soon, with `wait` left to wait.
+    pub fn with_cooldown(mut self, wait: Duration) -> FakeServer {
+        self.cooldown = Some(wait);
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.borrow().clone()
+    }
+}
+
+impl Transport for FakeServer {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        self.requests.borrow_mut().push(request.clone());
+
+        let respond = |status: u16, body: &str| {
+            Ok(Response {
+                status,
+                body: body.to_string(),
+            })
+        };
+        if request.session != self.session {
+            return respond(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            );
+        }
+
+        // Everything after the host, e.g. `2023/day/17/input`
+        let path = request.url.splitn(4, '/').nth(3).unwrap_or_default();
+        let parts: Vec<&str> = path.split('/').collect();
+        let puzzle = match parts.as_slice() {
+            [year, "day", day, ..] => year.parse().ok().zip(day.parse().ok()),
+            _ => None,
+        };
+
+        let found = match (request.method, puzzle, parts.get(3..).unwrap_or_default()) {
+            (Method::Get, Some(puzzle), []) => self.pages.get(&puzzle).cloned(),
+            (Method::Get, Some(puzzle), ["input"]) => self.inputs.get(&puzzle).cloned(),
+            (Method::Post, Some((year, day)), ["answer"]) => self.judge(year, day, &request.form),
+            _ => None,
+        };
+        match found {
+            Some(body) => respond(200, &body),
+            None => respond(404, "404 Not Found"),
+        }
+    }
+}
+
+impl FakeServer {
+    /// The page the site replies to an answer with.
+    fn judge(&self, year: u16, day: u8, form: &[(String, String)]) -> Option<String> {
+        let field = |name| {
+            form.iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str())
+        };
+        let part: u8 = field("level")?.parse().ok()?;
+        let answer = field("answer")?;
+        let expected = self.answers.get(&(year, day, part))?;
+
+        let reply = if let Some(wait) = self.cooldown {
+            format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {}m {}s left to wait.",
+                wait.as_secs() / 60,
+                wait.as_secs() % 60
+            )
+        } else if self.solved.borrow().contains(&(year, day, part)) {
+            "You don't seem to be solving the right level.  Did you already complete it?"
+                .to_string()
+        } else if answer == expected {
+            self.solved.borrow_mut().insert((year, day, part));
+            "That's the right answer!  You are one gold star closer to saving Christmas."
+                .to_string()
+        } else {
+            let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+                (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+                (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+                _ => "",
+            };
+            format!(
+                "That's not the right answer{}.  If you're stuck, make sure you're using the \
+                 full input data.  Please wait one minute before trying again.",
+                hint
+            )
+        };
+
+        Some(format!(
+            "<main>\n<article><p>{}</p></article>\n</main>",
+            reply
+        ))
+    }
+}
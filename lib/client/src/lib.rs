@@ -0,0 +1,447 @@
+//! Talking to the advent of code site.
+//!
+//! A [`Client`] downloads a day's input into the input store, and its
+//! example into the day's directory. Requests go through a [`Transport`],
+//! which is [`Curl`] for the real site and a [`FakeServer`] in tests. Since
+//! inputs never change, anything already fetched is never fetched again, and
+//! the requests that are made are kept a few seconds apart.
+//!
+//! It submits answers too, keeping a history of what the site said in
+//! [`Submissions`]. Answers that are already known to be wrong, or that the
+//! site's hints rule out, are never sent.
+
+mod error;
+mod fake;
+mod submit;
+mod throttle;
+mod transport;
+
+pub use error::Error;
+pub use fake::FakeServer;
+pub use submit::{Hint, Refusal, Submission, Submissions, Verdict};
+pub use throttle::Throttle;
+pub use transport::{Curl, Method, Request, Response, Transport};
+
+use aoc_runner::{root, Inputs};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const SITE: &str = "https://adventofcode.com";
+
+/// The least time between two requests.
+pub const INTERVAL: Duration = Duration::from_secs(5);
+
+/// Where the session token is kept when it isn't in `AOC_SESSION`. It's
+/// ignored by git.
+pub fn session_path() -> PathBuf {
+    root().join(".session")
+}
+
+/// The session token to log in with, if there is one.
+pub fn session() -> Option<String> {
+    env::var("AOC_SESSION")
+        .ok()
+        .or_else(|| fs::read_to_string(session_path()).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// Whether something was downloaded, or was already there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetch {
+    Cached(PathBuf),
+    Fetched(PathBuf),
+}
+
+impl Fetch {
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetch::Cached(path) | Fetch::Fetched(path) => path,
+        }
+    }
+}
+
+pub struct Client<T> {
+    transport: T,
+    session: Option<String>,
+    site: String,
+    inputs: Inputs,
+    throttle: Throttle,
+    now: fn() -> SystemTime,
+}
+
+impl<T: Transport> Client<T> {
+    /// A client for the real site, keeping inputs in `inputs` and noting
+    /// when it last made a request alongside them.
+    pub fn new(transport: T, session: Option<String>, inputs: Inputs) -> Client<T> {
+        let throttle = Throttle::new(inputs.dir().join(".last-request"), INTERVAL);
+        Client {
+            transport,
+            session,
+            site: SITE.to_string(),
+            inputs,
+            throttle,
+            now: SystemTime::now,
+        }
+    }
+
+    /// Talks to a different site, e.g. a stand-in for testing.
+    pub fn with_site(mut self, site: &str) -> Client<T> {
+        self.site = site.to_string();
+        self
+    }
+
+    pub fn with_throttle(mut self, throttle: Throttle) -> Client<T> {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Uses a different clock for telling whether puzzles have unlocked.
+    pub fn with_clock(mut self, now: fn() -> SystemTime) -> Client<T> {
+        self.now = now;
+        self
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Downloads a day's input into the store, unless it's already there.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<Fetch, Error> {
+        if self.inputs.contains(year, day) {
+            return Ok(Fetch::Cached(self.inputs.path(year, day)));
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.site, year, day);
+        let input = self.get(year, day, &url)?;
+        Ok(Fetch::Fetched(self.inputs.write(year, day, &input)?))
+    }
+
+    /// Saves the first example on the puzzle page to `path`, unless there's
+    /// already one there.
+    pub fn fetch_example(&self, year: u16, day: u8, path: &Path) -> Result<Fetch, Error> {
+        if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(Fetch::Cached(path.to_path_buf()));
+        }
+
+        let url = format!("{}/{}/day/{}", self.site, year, day);
+        let example = example(&self.get(year, day, &url)?).ok_or(Error::NoExample)?;
+        fs::write(path, example).map_err(|err| aoc_runner::Error::Write(path.into(), err))?;
+        Ok(Fetch::Fetched(path.to_path_buf()))
+    }
+
+    /// Where the history of submitted answers is kept.
+    pub fn submissions_path(&self) -> PathBuf {
+        self.inputs.dir().join("submissions.txt")
+    }
+
+    /// Submits an answer, unless the history says it can't be right or the
+    /// site wouldn't look at it yet, and records what the site said.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, Error> {
+        let mut submissions = Submissions::load(self.submissions_path())?;
+        let timestamp = (self.now)().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        submissions
+            .check((year, day, part), answer, timestamp)
+            .map_err(Error::Refused)?;
+
+        let url = format!("{}/{}/day/{}/answer", self.site, year, day);
+        let form = vec![
+            ("level".to_string(), part.to_string()),
+            ("answer".to_string(), answer.to_string()),
+        ];
+        let page = self.send(year, day, Method::Post, &url, form)?;
+        let verdict = Verdict::parse(&page).ok_or(Error::UnknownReply)?;
+
+        submissions.append(Submission {
+            timestamp,
+            year,
+            day,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        })?;
+        Ok(verdict)
+    }
+
+    fn get(&self, year: u16, day: u8, url: &str) -> Result<String, Error> {
+        self.send(year, day, Method::Get, url, Vec::new())
+    }
+
+    /// Makes a request about a puzzle, once it's unlocked and enough time has
+    /// passed since the last one.
+    fn send(
+        &self,
+        year: u16,
+        day: u8,
+        method: Method,
+        url: &str,
+        form: Vec<(String, String)>,
+    ) -> Result<String, Error> {
+        let session = self.session.clone().ok_or(Error::NoSession)?;
+        let wait = unlocks_at(year, day)
+            .duration_since((self.now)())
+            .unwrap_or_default();
+        if !wait.is_zero() {
+            return Err(Error::Locked { year, day, wait });
+        }
+
+        thread::sleep(self.throttle.delay(SystemTime::now()));
+        self.throttle.record(SystemTime::now())?;
+
+        let request = Request {
+            method,
+            url: url.to_string(),
+            session,
+            form,
+        };
+        let response = self.transport.send(&request)?;
+        match response.status {
+            200 => Ok(response.body),
+            400 if response.body.contains("log in") => Err(Error::BadSession),
+            429 => Err(Error::RateLimited),
+            status => Err(Error::Http {
+                url: url.to_string(),
+                status,
+            }),
+        }
+    }
+}
+
+/// When a puzzle unlocks, which is midnight on its day in the US eastern
+/// time zone, or five in the morning UTC.
+pub fn unlocks_at(year: u16, day: u8) -> SystemTime {
+    // Days since the epoch, from Howard Hinnant's `days_from_civil`
+    let (year, day) = (year as u64, day as u64);
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * 9 + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + 5 * 3_600)
+}
+
+/// The first block of code on a puzzle page, with its markup taken out.
+pub fn example(page: &str) -> Option<String> {
+    let start = page.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + page[start..].find("</code></pre>")?;
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in page[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    Some(
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    const SESSION: &str = "53616c7465645f5f";
+
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+    }
+
+    /// A client for a fake server in a scratch directory of its own, on a
+    /// clock that's well past every puzzle's unlocking.
+    fn fake_client(name: &str, session: Option<&str>) -> (Client<FakeServer>, PathBuf) {
+        let dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let server = FakeServer::new(SESSION).with_fixtures(&fixtures());
+        let client = Client::new(server, session.map(String::from), Inputs::new(&dir))
+            .with_site("http://localhost")
+            .with_throttle(Throttle::new(dir.join(".last-request"), Duration::ZERO))
+            .with_clock(|| unlocks_at(2030, 1));
+        (client, dir)
+    }
+
+    #[test]
+    fn fetch_input_test() {
+        let (client, dir) = fake_client("input", Some(SESSION));
+
+        let fetched = client.fetch_input(2015, 1).unwrap();
+        assert_eq!(fetched, Fetch::Fetched(dir.join("2015/day01.txt")));
+        assert_eq!(fs::read_to_string(fetched.path()).unwrap(), "()())(((()\n");
+
+        // Inputs never change, so the second time doesn't ask at all
+        assert_eq!(
+            client.fetch_input(2015, 1).unwrap(),
+            Fetch::Cached(dir.join("2015/day01.txt"))
+        );
+        let requests = client.transport().requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "http://localhost/2015/day/1/input");
+        assert_eq!(requests[0].session, SESSION);
+
+        assert!(matches!(
+            client.fetch_input(2023, 19),
+            Err(Error::Http { status: 404, .. })
+        ));
+        assert!(!dir.join("2023/day19.txt").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_example_test() {
+        let (client, dir) = fake_client("example", Some(SESSION));
+
+        let path = dir.join("example.txt");
+        fs::write(&path, "").unwrap();
+        assert_eq!(
+            client.fetch_example(2022, 6, &path).unwrap(),
+            Fetch::Fetched(path.clone())
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"
+        );
+        assert_eq!(
+            client.fetch_example(2022, 6, &path).unwrap(),
+            Fetch::Cached(path.clone())
+        );
+
+        let path = dir.join("example2.txt");
+        assert!(matches!(
+            client.fetch_example(2015, 1, &path),
+            Err(Error::NoExample)
+        ));
+        assert!(!path.exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refused_test() {
+        let (client, dir) = fake_client("no-session", None);
+        assert!(matches!(client.fetch_input(2015, 1), Err(Error::NoSession)));
+        fs::remove_dir_all(dir).unwrap();
+
+        let (client, dir) = fake_client("locked", Some(SESSION));
+        let client = client.with_clock(|| unlocks_at(2022, 6) - Duration::from_secs(90));
+        assert!(matches!(
+            client.fetch_input(2022, 6),
+            Err(Error::Locked { wait, .. }) if wait == Duration::from_secs(90)
+        ));
+        assert!(client.transport().requests().is_empty());
+        fs::remove_dir_all(dir).unwrap();
+
+        let (client, dir) = fake_client("bad-session", Some("wrong"));
+        assert!(matches!(
+            client.fetch_input(2022, 6),
+            Err(Error::BadSession)
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// A clock that moves an hour on every time it's read, so the site never
+    /// has us waiting.
+    fn ticking() -> SystemTime {
+        static HOURS: AtomicU64 = AtomicU64::new(0);
+        let hours = HOURS.fetch_add(1, Ordering::Relaxed);
+        unlocks_at(2030, 1) + Duration::from_secs(hours * 3600)
+    }
+
+    #[test]
+    fn submit_test() {
+        let (client, dir) = fake_client("submit", Some(SESSION));
+        let client = Client {
+            transport: FakeServer::new(SESSION).with_answer(2022, 6, 1, "1034"),
+            ..client.with_clock(ticking)
+        };
+
+        assert_eq!(
+            client.submit(2022, 6, 1, "2000").unwrap(),
+            Verdict::Wrong(Some(Hint::TooHigh), Duration::from_secs(60))
+        );
+        let request = &client.transport().requests()[0];
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "http://localhost/2022/day/6/answer");
+        assert_eq!(request.form[1], ("answer".to_string(), "2000".to_string()));
+
+        // Nothing the history already rules out is sent
+        let refused = |answer| match client.submit(2022, 6, 1, answer) {
+            Err(Error::Refused(refusal)) => refusal,
+            other => panic!("{} wasn't refused: {:?}", answer, other),
+        };
+        assert_eq!(refused("2000"), Refusal::KnownWrong);
+        assert_eq!(refused("2500"), Refusal::TooHigh(2000));
+        assert_eq!(client.transport().requests().len(), 1);
+
+        assert_eq!(client.submit(2022, 6, 1, "1034").unwrap(), Verdict::Correct);
+        assert_eq!(refused("1034"), Refusal::Solved("1034".to_string()));
+
+        let history = Submissions::load(client.submissions_path()).unwrap();
+        let verdicts: Vec<_> = history.submissions().iter().map(|s| s.verdict).collect();
+        assert_eq!(verdicts.len(), 2);
+        assert_eq!(verdicts[1], Verdict::Correct);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn submit_too_soon_test() {
+        let (client, dir) = fake_client("too-soon", Some(SESSION));
+        let wait = Duration::from_secs(125);
+        let client = Client {
+            transport: FakeServer::new(SESSION)
+                .with_answer(2022, 6, 1, "1034")
+                .with_cooldown(wait),
+            ..client
+        };
+
+        assert_eq!(
+            client.submit(2022, 6, 1, "1034").unwrap(),
+            Verdict::TooSoon(wait)
+        );
+        assert!(matches!(
+            client.submit(2022, 6, 1, "1034"),
+            Err(Error::Refused(Refusal::Wait(left))) if left == wait
+        ));
+        assert_eq!(client.transport().requests().len(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unlocks_at_test() {
+        let secs = |time: SystemTime| time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(secs(unlocks_at(2023, 1)), 1_701_406_800);
+        assert_eq!(secs(unlocks_at(2015, 25)), 1_451_019_600);
+        assert_eq!(
+            secs(unlocks_at(2024, 6)) - secs(unlocks_at(2024, 5)),
+            86_400
+        );
+    }
+
+    #[test]
+    fn example_test() {
+        let page = fs::read_to_string(fixtures().join("2023/day19/page.html")).unwrap();
+        assert_eq!(
+            example(&page).unwrap(),
+            "px{a<2006:qkq,m>2090:A,rfg}\nin{s<1351:px,qqz}\n\n{x=787,m=2655,a=1222,s=2876}\n"
+        );
+        assert_eq!(example("<p>no code here</p>"), None);
+    }
+}
@@ -0,0 +1,440 @@
+//! What the site said about submitted answers, and a history of them that's
+//! used to avoid submitting anything that's already known to be wrong.
+
+use aoc_runner::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long the site makes you wait after a wrong answer, if it doesn't say.
+const WRONG_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The site's verdict on an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Wrong, perhaps with a hint, and with how long to wait before trying
+    /// again.
+    Wrong(Option<Hint>, Duration),
+    /// Too soon after the last answer, with how long is left to wait. The
+    /// answer wasn't looked at.
+    TooSoon(Duration),
+    /// The part has already been solved, so the answer wasn't looked at.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page the site replies with.
+    pub fn parse(page: &str) -> Option<Verdict> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            let hint = if page.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = between(page, "Please wait ", " before trying again")
+                .and_then(parse_wait)
+                .unwrap_or(WRONG_WAIT);
+            Some(Verdict::Wrong(hint, wait))
+        } else if page.contains("You gave an answer too recently") {
+            let wait = between(page, "You have ", " left to wait").and_then(parse_wait)?;
+            Some(Verdict::TooSoon(wait))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// How long to wait after this before submitting again.
+    pub fn wait(&self) -> Duration {
+        match self {
+            Verdict::Wrong(_, wait) | Verdict::TooSoon(wait) => *wait,
+            Verdict::Correct | Verdict::AlreadySolved => Duration::ZERO,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(None, _) => write!(f, "wrong"),
+            Verdict::Wrong(Some(Hint::TooHigh), _) => write!(f, "too high"),
+            Verdict::Wrong(Some(Hint::TooLow), _) => write!(f, "too low"),
+            Verdict::TooSoon(wait) => write!(f, "too soon, {}s left to wait", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let s = &s[s.find(start)? + start.len()..];
+    Some(&s[..s.find(end)?])
+}
+
+/// Reads a wait like `one minute`, `5 minutes` or `1m 23s`.
+fn parse_wait(s: &str) -> Option<Duration> {
+    let mut secs = 0;
+    let mut words = s.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        let (count, unit) = match word.find(|c: char| c.is_ascii_alphabetic()) {
+            Some(0) => (word, words.next()?),
+            Some(i) => word.split_at(i),
+            None => (word, words.next()?),
+        };
+        let count: u64 = match count {
+            "a" | "an" | "one" => 1,
+            count => count.parse().ok()?,
+        };
+        secs += count
+            * match unit.trim_end_matches('s') {
+                "h" | "hour" => 3600,
+                "m" | "minute" => 60,
+                "" | "second" => 1,
+                _ => return None,
+            };
+        if words.peek().is_some_and(|&word| word == "and") {
+            words.next();
+        }
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+/// One answer that was submitted, and what the site said.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    /// When it was submitted, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl Submission {
+    fn parse(line: &str) -> Option<Submission> {
+        let mut fields = line.splitn(6, ' ');
+        let mut field = || fields.next();
+        let timestamp = field()?.parse().ok()?;
+        let (year, day) = field()?.split_once("/day")?;
+        let part = field()?.parse().ok()?;
+        let verdict = field()?;
+        let wait = Duration::from_secs(field()?.parse().ok()?);
+        let answer = field()?.to_string();
+
+        let verdict = match verdict {
+            "correct" => Verdict::Correct,
+            "wrong" => Verdict::Wrong(None, wait),
+            "too-high" => Verdict::Wrong(Some(Hint::TooHigh), wait),
+            "too-low" => Verdict::Wrong(Some(Hint::TooLow), wait),
+            "too-soon" => Verdict::TooSoon(wait),
+            "already-solved" => Verdict::AlreadySolved,
+            _ => return None,
+        };
+
+        Some(Submission {
+            timestamp,
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            part,
+            verdict,
+            answer,
+        })
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self.verdict {
+            Verdict::Correct => "correct",
+            Verdict::Wrong(None, _) => "wrong",
+            Verdict::Wrong(Some(Hint::TooHigh), _) => "too-high",
+            Verdict::Wrong(Some(Hint::TooLow), _) => "too-low",
+            Verdict::TooSoon(_) => "too-soon",
+            Verdict::AlreadySolved => "already-solved",
+        };
+        write!(
+            f,
+            "{} {}/day{:02} {} {} {} {}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            verdict,
+            self.verdict.wait().as_secs(),
+            self.answer
+        )
+    }
+}
+
+/// Why an answer wasn't worth submitting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part has already been solved, with the given answer.
+    Solved(String),
+    /// The answer was already submitted and was wrong.
+    KnownWrong,
+    /// The answer is at least as high as one that was too high.
+    TooHigh(i64),
+    /// The answer is at most as low as one that was too low.
+    TooLow(i64),
+    /// The site is still making us wait, for this much longer.
+    Wait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "already solved, with {:?}", answer),
+            Refusal::KnownWrong => write!(f, "that answer was already wrong"),
+            Refusal::TooHigh(than) => write!(f, "{} was already too high", than),
+            Refusal::TooLow(than) => write!(f, "{} was already too low", than),
+            Refusal::Wait(wait) => write!(f, "the site wants another {}s first", wait.as_secs()),
+        }
+    }
+}
+
+/// Every answer submitted so far, oldest first. It's kept with the inputs,
+/// as it's no more use to anyone else than they are.
+#[derive(Clone, Debug)]
+pub struct Submissions {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Reads the history from `path`. It's fine for there not to be one yet.
+    pub fn load(path: PathBuf) -> Result<Submissions, Error> {
+        let submissions = match fs::read_to_string(&path) {
+            Ok(contents) => contents.lines().filter_map(Submission::parse).collect(),
+            Err(err) if err.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(Error::Read(path, err)),
+        };
+
+        Ok(Submissions { path, submissions })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Whether it's worth submitting `answer` at `timestamp`, going by what
+    /// the site has said before.
+    pub fn check(
+        &self,
+        (year, day, part): (u16, u8, u8),
+        answer: &str,
+        timestamp: u64,
+    ) -> Result<(), Refusal> {
+        let puzzle = || {
+            self.submissions
+                .iter()
+                .filter(|s| (s.year, s.day) == (year, day))
+        };
+
+        if let Some(last) = puzzle().next_back() {
+            let until = last.timestamp + last.verdict.wait().as_secs();
+            if until > timestamp {
+                return Err(Refusal::Wait(Duration::from_secs(until - timestamp)));
+            }
+        }
+
+        let mut too_high = None;
+        let mut too_low = None;
+        for submission in puzzle().filter(|s| s.part == part) {
+            match (submission.verdict, submission.answer.parse::<i64>().ok()) {
+                (Verdict::Correct, _) => return Err(Refusal::Solved(submission.answer.clone())),
+                (Verdict::Wrong(..), _) if submission.answer == answer => {
+                    return Err(Refusal::KnownWrong)
+                }
+                (Verdict::Wrong(Some(Hint::TooHigh), _), Some(n)) => {
+                    too_high = Some(too_high.map_or(n, |high: i64| high.min(n)))
+                }
+                (Verdict::Wrong(Some(Hint::TooLow), _), Some(n)) => {
+                    too_low = Some(too_low.map_or(n, |low: i64| low.max(n)))
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(n) = answer.parse::<i64>() {
+            if let Some(high) = too_high.filter(|&high| n >= high) {
+                return Err(Refusal::TooHigh(high));
+            }
+            if let Some(low) = too_low.filter(|&low| n <= low) {
+                return Err(Refusal::TooLow(low));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a submission to the history and its file.
+    pub fn append(&mut self, submission: Submission) -> Result<(), Error> {
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            writeln!(file, "{}", submission)
+        };
+
+        write().map_err(|err| Error::Write(self.path.clone(), err))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    fn submission(timestamp: u64, part: u8, verdict: Verdict, answer: &str) -> Submission {
+        Submission {
+            timestamp,
+            year: 2022,
+            day: 6,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_verdict_test() {
+        let page = |p: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", p);
+
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer!  You are one gold star closer to finding the chief."
+            )),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.")),
+            Some(Verdict::Wrong(Some(Hint::TooHigh), Duration::from_secs(60)))
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.")),
+            Some(Verdict::Wrong(Some(Hint::TooLow), Duration::from_secs(300)))
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer.  If you're stuck, there are some general tips on the about page.")),
+            Some(Verdict::Wrong(None, WRONG_WAIT))
+        );
+        assert_eq!(
+            Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.")),
+            Some(Verdict::TooSoon(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(Verdict::parse(&page("Something else entirely")), None);
+    }
+
+    #[test]
+    fn history_test() {
+        let path = env::temp_dir().join(format!("aoc-submissions-{}", process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut history = Submissions::load(path.clone()).unwrap();
+        let submissions = [
+            submission(
+                100,
+                1,
+                Verdict::Wrong(Some(Hint::TooHigh), WRONG_WAIT),
+                "1500",
+            ),
+            submission(200, 1, Verdict::TooSoon(Duration::from_secs(17)), "12"),
+            submission(300, 1, Verdict::Correct, "1034"),
+            submission(400, 2, Verdict::Wrong(None, WRONG_WAIT), "two words"),
+        ];
+        for submission in &submissions {
+            history.append(submission.clone()).unwrap();
+        }
+
+        assert_eq!(
+            Submissions::load(path.clone()).unwrap().submissions(),
+            submissions
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn check_test() {
+        let history = Submissions {
+            path: PathBuf::new(),
+            submissions: vec![
+                submission(1000, 1, Verdict::Correct, "1034"),
+                submission(
+                    2000,
+                    2,
+                    Verdict::Wrong(Some(Hint::TooHigh), WRONG_WAIT),
+                    "3000",
+                ),
+                submission(
+                    2100,
+                    2,
+                    Verdict::Wrong(Some(Hint::TooHigh), WRONG_WAIT),
+                    "2500",
+                ),
+                submission(
+                    2200,
+                    2,
+                    Verdict::Wrong(Some(Hint::TooLow), WRONG_WAIT),
+                    "2000",
+                ),
+                submission(2300, 2, Verdict::Wrong(None, WRONG_WAIT), "2345"),
+            ],
+        };
+        let part = |part| (2022, 6, part);
+
+        assert_eq!(
+            history.check(part(1), "1035", 5000),
+            Err(Refusal::Solved("1034".to_string()))
+        );
+        assert_eq!(
+            history.check(part(2), "2345", 5000),
+            Err(Refusal::KnownWrong)
+        );
+        assert_eq!(
+            history.check(part(2), "2600", 5000),
+            Err(Refusal::TooHigh(2500))
+        );
+        assert_eq!(
+            history.check(part(2), "1999", 5000),
+            Err(Refusal::TooLow(2000))
+        );
+        assert_eq!(history.check(part(2), "2400", 5000), Ok(()));
+        assert_eq!(history.check(part(2), "not a number", 5000), Ok(()));
+
+        // The last wrong answer still has the site making us wait
+        assert_eq!(
+            history.check(part(2), "2400", 2330),
+            Err(Refusal::Wait(Duration::from_secs(30)))
+        );
+        assert_eq!(history.check((2022, 7, 1), "1", 2330), Ok(()));
+    }
+}
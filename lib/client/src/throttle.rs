@@ -0,0 +1,72 @@
+use crate::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Keeps requests at least `interval` apart, even between separate runs, by
+/// noting when the last one was made in a file.
+#[derive(Clone, Debug)]
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(path: PathBuf, interval: Duration) -> Throttle {
+        Throttle { path, interval }
+    }
+
+    /// How long to wait at `now` before making a request.
+    pub fn delay(&self, now: SystemTime) -> Duration {
+        let Some(last) = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+        else {
+            return Duration::ZERO;
+        };
+
+        let since = now.duration_since(last).unwrap_or_default();
+        self.interval.saturating_sub(since)
+    }
+
+    /// Notes that a request was made at `now`.
+    pub fn record(&self, now: SystemTime) -> Result<(), Error> {
+        let millis = now.duration_since(UNIX_EPOCH).unwrap().as_millis();
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| aoc_runner::Error::Write(parent.to_path_buf(), err))?;
+        }
+        fs::write(&self.path, millis.to_string())
+            .map_err(|err| aoc_runner::Error::Write(self.path.clone(), err).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn delay_test() {
+        let path = env::temp_dir().join(format!("aoc-throttle-{}", process::id()));
+        let _ = fs::remove_file(&path);
+        let throttle = Throttle::new(path.clone(), Duration::from_secs(5));
+
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(throttle.delay(start), Duration::ZERO);
+
+        throttle.record(start).unwrap();
+        assert_eq!(throttle.delay(start), Duration::from_secs(5));
+        assert_eq!(
+            throttle.delay(start + Duration::from_millis(1500)),
+            Duration::from_millis(3500)
+        );
+        assert_eq!(
+            throttle.delay(start + Duration::from_secs(60)),
+            Duration::ZERO
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Error;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    /// The session token, sent as a cookie.
+    pub session: String,
+    /// The fields of a form being posted.
+    pub form: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Something that can make requests, so that the tests can stand in for the
+/// real site.
+pub trait Transport {
+    fn send(&self, request: &Request) -> Result<Response, Error>;
+}
+
+/// Makes requests by running `curl`, which saves building TLS into the
+/// workspace.
+#[derive(Clone, Debug)]
+pub struct Curl {
+    /// The site asks that automated requests say where they come from.
+    pub user_agent: String,
+}
+
+impl Default for Curl {
+    fn default() -> Curl {
+        Curl {
+            user_agent: "aoc-client (personal advent of code solutions)".to_string(),
+        }
+    }
+}
+
+impl Transport for Curl {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        let mut command = Command::new("curl");
+        // The cookie goes in on stdin to keep the token out of the process list
+        command
+            .args(["--silent", "--show-error", "--header", "@-"])
+            .args(["--user-agent", &self.user_agent])
+            .args(["--write-out", "\n%{http_code}"]);
+        if request.method == Method::Post {
+            for (name, value) in &request.form {
+                command
+                    .arg("--data-urlencode")
+                    .arg(format!("{}={}", name, value));
+            }
+        }
+        command
+            .arg(&request.url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let transport = |err: std::io::Error| Error::Transport(format!("curl: {}", err));
+        let mut child = command.spawn().map_err(transport)?;
+        let mut stdin = child.stdin.take().unwrap();
+        writeln!(stdin, "Cookie: session={}", request.session).map_err(transport)?;
+        drop(stdin);
+
+        let output = child.wait_with_output().map_err(transport)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::Transport(stderr.trim().to_string()));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| Error::Transport("no status from curl".to_string()))?;
+        let status = status
+            .parse()
+            .map_err(|_| Error::Transport(format!("bad status from curl: {:?}", status)))?;
+
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
@@ -0,0 +1,361 @@
+//! A dense two dimensional grid, for the puzzles whose input is a map.
+//!
+//! Positions are `(x, y)` pairs, with `x` running along each line of the input
+//! and `y` down the lines, so `(0, 0)` is the top left corner.
+
+use aoc_parse::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid, as `(x, y)`.
+pub type Pos = (usize, usize);
+
+/// The offsets to the four orthogonal neighbours, clockwise from north.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets to all eight neighbours, clockwise from north.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Makes a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Makes a grid by calling `f` with each position, row by row.
+    pub fn from_fn<F: FnMut(Pos) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid from puzzle text, converting each character with `f`.
+    /// Every line must be the same length.
+    pub fn parse<F: FnMut(char) -> T>(input: &str, mut f: F) -> Grid<T> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+
+            let line_width = cells.len() - len;
+            match width {
+                None => width = Some(line_width),
+                Some(w) if w != line_width => panic!(
+                    "line {} is {} characters long, expected {}",
+                    height + 1,
+                    line_width,
+                    w
+                ),
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid like [`Grid::parse`], but with a conversion that can
+    /// fail. Its errors are placed at the character that caused them.
+    pub fn try_parse<F>(input: &str, mut f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(c).map_err(|err| err.at(height + 1, x + 1))?);
+            }
+
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(w) if w != line_width => {
+                    return Err(ParseError::new(format!(
+                        "line is {} characters long, expected {}",
+                        line_width, w
+                    ))
+                    .at(height + 1, line_width.min(w) + 1))
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Moves from `pos` by an offset, if that stays inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// All eight neighbours of `pos` that are inside the grid, including the
+    /// diagonals.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Every cell, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The position of the first cell, row by row, that matches.
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Pos> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on zero, but an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Swaps the rows and columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Makes a grid of the same size by converting every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(value) => value,
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(value) => value,
+            None => panic!("{:?} is outside the {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+/// Draws the grid a row per line, the way the puzzles do.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef\n";
+
+    #[test]
+    fn parse_test() {
+        let grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.find(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "line 2 is 2 characters long, expected 3")]
+    fn ragged_test() {
+        Grid::parse("abc\nde\n", |c| c);
+    }
+
+    #[test]
+    fn try_parse_test() {
+        let digit = |c: char| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::expected("a digit", &c.to_string()))
+        };
+        let grid = Grid::try_parse("12\n34\n", digit).unwrap();
+        assert_eq!(grid[(1, 1)], 4);
+
+        let err = Grid::try_parse("12\n3x\n", digit).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a digit, found \"x\""
+        );
+
+        let err = Grid::try_parse("12\n345\n", digit).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: line is 3 characters long, expected 2"
+        );
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 2), (1, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.offset((2, 1), (1, 0)), None);
+    }
+
+    #[test]
+    fn views_test() {
+        let grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+
+        let transposed = grid.transpose();
+        assert_eq!(transposed.width(), 2);
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf");
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn display_test() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + y);
+        assert_eq!(grid.to_string(), "012\n123");
+        assert_eq!(
+            grid.map(|&v| if v > 1 { '#' } else { '.' }).to_string(),
+            "..#\n.##"
+        );
+    }
+}
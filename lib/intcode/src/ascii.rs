@@ -0,0 +1,70 @@
+use crate::{Error, IntCodeComputer, Status};
+
+/// Everything an ASCII program printed before it stopped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsciiOutput {
+    /// The output that was valid ASCII.
+    pub text: String,
+    /// Any output that wasn't, such as a final answer.
+    pub values: Vec<i64>,
+    /// Either `WaitingForInput` or `Finished`.
+    pub status: Status,
+}
+
+impl IntCodeComputer {
+    /// Queues text as input, one character per value.
+    pub fn push_str(&mut self, text: &str) {
+        self.extend_input(text.chars().map(|c| c as i64));
+    }
+
+    /// Queues a line of text as input, adding the newline.
+    pub fn push_line(&mut self, line: &str) {
+        self.push_str(line);
+        self.push_input(10);
+    }
+
+    /// Runs until the program needs input or halts, collecting what it
+    /// printed along the way.
+    pub fn read_ascii(&mut self) -> Result<AsciiOutput, Error> {
+        let mut text = String::new();
+        let mut values = Vec::new();
+
+        loop {
+            match self.run()? {
+                Status::Output(value @ 0..=127) => text.push(value as u8 as char),
+                Status::Output(value) => values.push(value),
+                status => {
+                    return Ok(AsciiOutput {
+                        text,
+                        values,
+                        status,
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_ascii_test() {
+        // Prints "Hi\n", then 1000, then echoes a character
+        let mut computer: IntCodeComputer = "104,72,104,105,104,10,104,1000,3,15,4,15,99"
+            .parse()
+            .unwrap();
+
+        let output = computer.read_ascii().unwrap();
+        assert_eq!(output.text, "Hi\n");
+        assert_eq!(output.values, vec![1000]);
+        assert_eq!(output.status, Status::WaitingForInput);
+
+        computer.push_line("!");
+        let output = computer.read_ascii().unwrap();
+        assert_eq!(output.text, "!");
+        assert_eq!(output.status, Status::Finished);
+        assert_eq!(computer.pending_input().collect::<Vec<_>>(), vec![&10]);
+    }
+}
@@ -0,0 +1,241 @@
+//! Steps through an Intcode program interactively.
+//!
+//! Usage: intcode-debug <program file> [input values...]
+//!
+//! Type `help` at the prompt for the list of commands. An empty line repeats
+//! the last command.
+
+use aoc_intcode::{Breakpoint, Debugger, IntCodeComputer, Opcode, Status, Stop, Watch};
+use std::io::{self, BufRead, Write};
+use std::{env, fs, process};
+
+const HELP: &str = "\
+s, step [n]          execute n instructions (default 1)
+c, continue          run until a breakpoint, watch, output, input or halt
+b, break <addr|op>   stop at an address or before an opcode, e.g. `b 13`, `b OUT`
+d, delete <addr|op>  remove a breakpoint
+w, watch <addr|rb>   stop when a memory cell or the relative base changes
+u, unwatch <addr|rb> remove a watch
+l, list              show breakpoints and watches
+r, regs              show the registers and the next instruction
+m, mem <addr> [len]  dump memory
+h, history           show the recently executed instructions
+i, input <values>    queue input values
+a, ascii <text>      queue a line of text as ASCII input
+p, poke <addr> <val> patch memory
+q, quit              exit";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let path = match args.first() {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: intcode-debug <program file> [input values...]");
+            process::exit(2);
+        }
+    };
+
+    let input = fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("couldn't read {}: {}", path, err);
+        process::exit(1);
+    });
+
+    let mut computer: IntCodeComputer = input.parse().unwrap_or_else(|err| {
+        eprintln!("couldn't parse {}: {}", path, err);
+        process::exit(1);
+    });
+
+    let inputs = parse_values(&args[1..]).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(2);
+    });
+    computer.extend_input(inputs);
+
+    let mut debugger = Debugger::new(computer);
+    println!("{}", debugger.registers());
+
+    let stdin = io::stdin();
+    let mut last = String::new();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+
+        let line = match line.trim() {
+            "" => last.clone(),
+            line => line.to_string(),
+        };
+        last = line.clone();
+
+        match command(&mut debugger, &line) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(err) => println!("error: {}", err),
+        }
+    }
+}
+
+/// Runs a single command, returning false if the debugger should exit.
+fn command(debugger: &mut Debugger, line: &str) -> Result<bool, String> {
+    let (cmd, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let args: Vec<&str> = rest.split_whitespace().collect();
+
+    match cmd {
+        "s" | "step" => {
+            let n = match args.first() {
+                Some(n) => n.parse().map_err(|_| format!("not a count: {}", n))?,
+                None => 1,
+            };
+            for _ in 0..n {
+                let trace = debugger.next_instruction();
+                let stop = debugger.step().map_err(|err| err.to_string())?;
+                if let (Some(trace), false) = (trace, stop == Stop::Status(Status::WaitingForInput))
+                {
+                    println!("{}", trace);
+                }
+                if stop != Stop::Stepped {
+                    report(debugger, stop);
+                    break;
+                }
+            }
+        }
+        "c" | "continue" => {
+            let stop = debugger.resume().map_err(|err| err.to_string())?;
+            report(debugger, stop);
+        }
+        "b" | "break" => {
+            let breakpoint = parse_breakpoint(&args)?;
+            if !debugger.add_breakpoint(breakpoint) {
+                println!("already set");
+            }
+        }
+        "d" | "delete" => {
+            let breakpoint = parse_breakpoint(&args)?;
+            if !debugger.remove_breakpoint(breakpoint) {
+                println!("no such breakpoint");
+            }
+        }
+        "w" | "watch" => {
+            let watch = parse_watch(&args)?;
+            if !debugger.add_watch(watch) {
+                println!("already watched");
+            }
+        }
+        "u" | "unwatch" => {
+            let watch = parse_watch(&args)?;
+            if !debugger.remove_watch(watch) {
+                println!("no such watch");
+            }
+        }
+        "l" | "list" => {
+            for breakpoint in debugger.breakpoints() {
+                match breakpoint {
+                    Breakpoint::Address(addr) => println!("break at {:04}", addr),
+                    Breakpoint::Opcode(op) => println!("break on {}", op.mnemonic()),
+                }
+            }
+            for &watch in debugger.watches() {
+                match watch {
+                    Watch::Memory(addr) => {
+                        println!("watch [{}] = {}", addr, debugger.watched(watch))
+                    }
+                    Watch::RelativeBase => println!("watch rb = {}", debugger.watched(watch)),
+                }
+            }
+        }
+        "r" | "regs" => println!("{}", debugger.registers()),
+        "m" | "mem" => {
+            let values = parse_values(&args)?;
+            let (start, len) = match values[..] {
+                [start] => (start, 8),
+                [start, len] => (start, len),
+                _ => return Err("usage: mem <addr> [len]".to_string()),
+            };
+            let start = usize::try_from(start).map_err(|_| "negative address")?;
+            let len = usize::try_from(len).map_err(|_| "negative length")?;
+            println!("{}", debugger.memory_dump(start, len));
+        }
+        "h" | "history" => {
+            for trace in debugger.history() {
+                println!("{}", trace);
+            }
+        }
+        "i" | "input" => {
+            let values = parse_values(&args)?;
+            debugger.computer_mut().extend_input(values);
+        }
+        "a" | "ascii" => {
+            let text = rest.chars().chain(std::iter::once('\n'));
+            debugger.computer_mut().extend_input(text.map(|c| c as i64));
+        }
+        "p" | "poke" => match parse_values(&args)?[..] {
+            [addr, value] => {
+                let addr = usize::try_from(addr).map_err(|_| "negative address")?;
+                debugger.computer_mut().set(addr, value);
+            }
+            _ => return Err("usage: poke <addr> <value>".to_string()),
+        },
+        "q" | "quit" => return Ok(false),
+        "help" | "?" => println!("{}", HELP),
+        _ => return Err(format!("unknown command: {} (try help)", cmd)),
+    }
+
+    Ok(true)
+}
+
+fn report(debugger: &Debugger, stop: Stop) {
+    match stop {
+        Stop::Stepped => {}
+        Stop::Breakpoint(_) => println!("breakpoint"),
+        Stop::Watch { watch, old, new } => match watch {
+            Watch::Memory(addr) => println!("[{}] changed from {} to {}", addr, old, new),
+            Watch::RelativeBase => println!("rb changed from {} to {}", old, new),
+        },
+        Stop::Status(Status::Output(value)) => match u8::try_from(value) {
+            Ok(c) if c == b'\n' || c.is_ascii_graphic() || c == b' ' => {
+                println!("output: {} {:?}", value, c as char)
+            }
+            _ => println!("output: {}", value),
+        },
+        Stop::Status(Status::WaitingForInput) => println!("waiting for input"),
+        Stop::Status(Status::Finished) => println!("halted"),
+    }
+
+    println!("{}", debugger.registers());
+}
+
+fn parse_breakpoint(args: &[&str]) -> Result<Breakpoint, String> {
+    match args {
+        [arg] => match arg.parse() {
+            Ok(addr) => Ok(Breakpoint::Address(addr)),
+            Err(_) => Opcode::from_mnemonic(arg)
+                .map(Breakpoint::Opcode)
+                .ok_or_else(|| format!("not an address or opcode: {}", arg)),
+        },
+        _ => Err("expected an address or an opcode".to_string()),
+    }
+}
+
+fn parse_watch(args: &[&str]) -> Result<Watch, String> {
+    match args {
+        ["rb"] => Ok(Watch::RelativeBase),
+        [arg] => arg
+            .parse()
+            .map(Watch::Memory)
+            .map_err(|_| format!("not an address: {}", arg)),
+        _ => Err("expected an address or rb".to_string()),
+    }
+}
+
+fn parse_values<S: AsRef<str>>(args: &[S]) -> Result<Vec<i64>, String> {
+    args.iter()
+        .map(|arg| {
+            let arg = arg.as_ref();
+            arg.parse().map_err(|_| format!("not a number: {}", arg))
+        })
+        .collect()
+}
@@ -0,0 +1,28 @@
+//! Prints an annotated listing of an Intcode program.
+//!
+//! Usage: intcode-disasm <program file>
+
+use aoc_intcode::{disassemble, parse_program};
+use std::{env, fs, process};
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: intcode-disasm <program file>");
+            process::exit(2);
+        }
+    };
+
+    let input = fs::read_to_string(&path).unwrap_or_else(|err| {
+        eprintln!("couldn't read {}: {}", path, err);
+        process::exit(1);
+    });
+
+    let program = parse_program(&input).unwrap_or_else(|err| {
+        eprintln!("couldn't parse {}: {}", path, err);
+        process::exit(1);
+    });
+
+    print!("{}", disassemble(&program));
+}
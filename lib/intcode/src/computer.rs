@@ -0,0 +1,313 @@
+use aoc_parse::{line, ParseError};
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use crate::{Error, Instruction, Mode, Opcode};
+
+/// Why the computer stopped running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The program produced a value. Calling `run` again resumes after it.
+    Output(i64),
+    /// The program needs input. Push some and call `run` again to resume.
+    WaitingForInput,
+    /// The program has halted.
+    Finished,
+}
+
+#[derive(Clone, Debug)]
+pub struct IntCodeComputer {
+    memory: Vec<i64>,
+    inputs: VecDeque<i64>,
+    rel_base: i64,
+    pos: usize,
+}
+
+/// Parses a comma separated Intcode program.
+pub fn parse_program(input: &str) -> Result<Vec<i64>, Error> {
+    input
+        .trim()
+        .split(',')
+        .enumerate()
+        .map(|(index, x)| {
+            x.trim().parse().map_err(|_| Error::Parse {
+                index,
+                value: x.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parses a puzzle input that's a program, saying where it went wrong.
+pub fn read_program(input: &str) -> Result<Vec<i64>, ParseError> {
+    line(input, |program| {
+        parse_program(program).map_err(|err| match err {
+            Error::Parse { value, .. } => ParseError::expected("a number", value.trim()),
+            err => ParseError::new(err.to_string()),
+        })
+    })
+}
+
+impl FromStr for IntCodeComputer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_program(s).map(|program| IntCodeComputer::new(&program))
+    }
+}
+
+impl IntCodeComputer {
+    pub fn new(program: &[i64]) -> IntCodeComputer {
+        IntCodeComputer {
+            memory: program.to_vec(),
+            inputs: VecDeque::new(),
+            rel_base: 0,
+            pos: 0,
+        }
+    }
+
+    /// Reads the value at `addr`. Memory beyond the program is zero.
+    pub fn get(&self, addr: usize) -> i64 {
+        *self.memory.get(addr).unwrap_or(&0)
+    }
+
+    /// Patches the value at `addr`, growing the memory if needed.
+    pub fn set(&mut self, addr: usize, value: i64) {
+        if self.memory.len() <= addr {
+            self.memory.resize(addr + 1, 0);
+        }
+
+        self.memory[addr] = value;
+    }
+
+    /// Queues a value to be consumed by the next input instruction.
+    pub fn push_input(&mut self, value: i64) {
+        self.inputs.push_back(value);
+    }
+
+    /// Queues several values, to be consumed in order.
+    pub fn extend_input<I: IntoIterator<Item = i64>>(&mut self, values: I) {
+        self.inputs.extend(values);
+    }
+
+    /// The address of the next instruction to be executed.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub fn rel_base(&self) -> i64 {
+        self.rel_base
+    }
+
+    /// The memory the program has touched so far.
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    /// The input values that haven't been consumed yet.
+    pub fn pending_input(&self) -> impl Iterator<Item = &i64> {
+        self.inputs.iter()
+    }
+
+    /// Runs until the program outputs a value, needs input it doesn't have,
+    /// or halts. Running a halted program just reports that it is finished.
+    pub fn run(&mut self) -> Result<Status, Error> {
+        loop {
+            if let Some(status) = self.step()? {
+                return Ok(status);
+            }
+        }
+    }
+
+    /// Runs the program until it halts, returning everything it output. Any
+    /// input must already have been queued.
+    pub fn run_to_end(&mut self) -> Result<Vec<i64>, Error> {
+        let mut outputs = Vec::new();
+        loop {
+            match self.run()? {
+                Status::Output(x) => outputs.push(x),
+                Status::WaitingForInput => return Err(Error::MissingInput { pos: self.pos }),
+                Status::Finished => return Ok(outputs),
+            }
+        }
+    }
+
+    /// Executes a single instruction, returning a status if it is one that
+    /// should stop the computer. An input instruction with nothing queued
+    /// doesn't move on, so stepping again retries it.
+    pub fn step(&mut self) -> Result<Option<Status>, Error> {
+        let instruction = Instruction::decode(self.pos, self.get(self.pos))?;
+
+        match instruction.opcode {
+            Opcode::Add => {
+                let value = self.param(&instruction, 0)? + self.param(&instruction, 1)?;
+                self.write(&instruction, 2, value)?;
+            }
+            Opcode::Multiply => {
+                let value = self.param(&instruction, 0)? * self.param(&instruction, 1)?;
+                self.write(&instruction, 2, value)?;
+            }
+            Opcode::Input => match self.inputs.pop_front() {
+                Some(value) => self.write(&instruction, 0, value)?,
+                None => return Ok(Some(Status::WaitingForInput)),
+            },
+            Opcode::Output => {
+                let value = self.param(&instruction, 0)?;
+                self.pos += instruction.size();
+                return Ok(Some(Status::Output(value)));
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let jump = (self.param(&instruction, 0)? != 0)
+                    == (instruction.opcode == Opcode::JumpIfTrue);
+                if jump {
+                    self.pos = self.address(self.param(&instruction, 1)?)?;
+                    return Ok(None);
+                }
+            }
+            Opcode::LessThan => {
+                let value = self.param(&instruction, 0)? < self.param(&instruction, 1)?;
+                self.write(&instruction, 2, value as i64)?;
+            }
+            Opcode::Equals => {
+                let value = self.param(&instruction, 0)? == self.param(&instruction, 1)?;
+                self.write(&instruction, 2, value as i64)?;
+            }
+            Opcode::AdjustRelativeBase => {
+                self.rel_base += self.param(&instruction, 0)?;
+            }
+            Opcode::Halt => return Ok(Some(Status::Finished)),
+        }
+
+        self.pos += instruction.size();
+        Ok(None)
+    }
+
+    /// Reads the value of the n-th parameter of the current instruction.
+    fn param(&self, instruction: &Instruction, n: usize) -> Result<i64, Error> {
+        let raw = self.get(self.pos + n + 1);
+        match instruction.modes[n] {
+            Mode::Position => Ok(self.get(self.address(raw)?)),
+            Mode::Immediate => Ok(raw),
+            Mode::Relative => Ok(self.get(self.address(self.rel_base + raw)?)),
+        }
+    }
+
+    /// Writes to the address given by the n-th parameter of the current
+    /// instruction.
+    fn write(&mut self, instruction: &Instruction, n: usize, value: i64) -> Result<(), Error> {
+        let raw = self.get(self.pos + n + 1);
+        let addr = match instruction.modes[n] {
+            Mode::Position => self.address(raw)?,
+            Mode::Immediate => return Err(Error::ImmediateWrite { pos: self.pos }),
+            Mode::Relative => self.address(self.rel_base + raw)?,
+        };
+
+        self.set(addr, value);
+        Ok(())
+    }
+
+    fn address(&self, addr: i64) -> Result<usize, Error> {
+        usize::try_from(addr).map_err(|_| Error::NegativeAddress {
+            pos: self.pos,
+            addr,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_with(program: &str, inputs: &[i64]) -> Vec<i64> {
+        let mut computer: IntCodeComputer = program.parse().unwrap();
+        computer.extend_input(inputs.iter().copied());
+        computer.run_to_end().unwrap()
+    }
+
+    #[test]
+    fn memory_test() {
+        let mut computer: IntCodeComputer = "1,9,10,3,2,3,11,0,99,30,40,50".parse().unwrap();
+        computer.run_to_end().unwrap();
+        assert_eq!(computer.get(0), 3500);
+
+        let mut computer: IntCodeComputer = "1,1,1,4,99,5,6,0,99".parse().unwrap();
+        computer.run_to_end().unwrap();
+        assert_eq!(computer.get(0), 30);
+    }
+
+    #[test]
+    fn comparison_test() {
+        let equal_to_eight = "3,9,8,9,10,9,4,9,99,-1,8";
+        assert_eq!(run_with(equal_to_eight, &[8]), vec![1]);
+        assert_eq!(run_with(equal_to_eight, &[7]), vec![0]);
+
+        let less_than_eight = "3,3,1107,-1,8,3,4,3,99";
+        assert_eq!(run_with(less_than_eight, &[5]), vec![1]);
+        assert_eq!(run_with(less_than_eight, &[9]), vec![0]);
+    }
+
+    #[test]
+    fn jump_test() {
+        let program = "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9";
+        assert_eq!(run_with(program, &[0]), vec![0]);
+        assert_eq!(run_with(program, &[5]), vec![1]);
+    }
+
+    #[test]
+    fn relative_base_test() {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected = parse_program(quine).unwrap();
+        assert_eq!(run_with(quine, &[]), expected);
+
+        assert_eq!(
+            run_with("104,1125899906842624,99", &[]),
+            vec![1125899906842624]
+        );
+        assert_eq!(
+            run_with("1102,34915192,34915192,7,4,7,99,0", &[]),
+            vec![1219070632396864]
+        );
+    }
+
+    #[test]
+    fn resumable_test() {
+        let mut computer: IntCodeComputer = "3,0,4,0,3,0,4,0,99".parse().unwrap();
+        assert_eq!(computer.run(), Ok(Status::WaitingForInput));
+        computer.push_input(3);
+        assert_eq!(computer.run(), Ok(Status::Output(3)));
+        assert_eq!(computer.run(), Ok(Status::WaitingForInput));
+        computer.push_input(4);
+        assert_eq!(computer.run(), Ok(Status::Output(4)));
+        assert_eq!(computer.run(), Ok(Status::Finished));
+        assert_eq!(computer.run(), Ok(Status::Finished));
+    }
+
+    #[test]
+    fn error_test() {
+        assert_eq!(
+            "1,2,x".parse::<IntCodeComputer>().unwrap_err(),
+            Error::Parse {
+                index: 2,
+                value: "x".to_string()
+            }
+        );
+
+        assert_eq!(
+            read_program("1,2,x,4\n").unwrap_err().to_string(),
+            "line 1, column 5: expected a number, found \"x\""
+        );
+        assert_eq!(read_program("1,2,99\n"), Ok(vec![1, 2, 99]));
+
+        let mut computer: IntCodeComputer = "3,0,99".parse().unwrap();
+        assert_eq!(computer.run_to_end(), Err(Error::MissingInput { pos: 0 }));
+
+        let mut computer: IntCodeComputer = "1101,1,1,0,1,0,0,-5,99".parse().unwrap();
+        assert_eq!(
+            computer.run_to_end(),
+            Err(Error::NegativeAddress { pos: 4, addr: -5 })
+        );
+
+        let mut computer: IntCodeComputer = "11101,1,1,0,99".parse().unwrap();
+        assert_eq!(computer.run_to_end(), Err(Error::ImmediateWrite { pos: 0 }));
+    }
+}
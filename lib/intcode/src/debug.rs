@@ -0,0 +1,378 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::disasm::format_instruction;
+use crate::{Error, Instruction, IntCodeComputer, Opcode, Status};
+
+/// How many executed instructions are remembered by default.
+const DEFAULT_HISTORY: usize = 32;
+
+/// Where the debugger should stop before executing an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Stop when the instruction pointer reaches an address.
+    Address(usize),
+    /// Stop before any instruction with this opcode.
+    Opcode(Opcode),
+}
+
+/// A value that the debugger stops on when it changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Watch {
+    Memory(usize),
+    RelativeBase,
+}
+
+/// Why the debugger handed control back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// A single step finished and nothing interesting happened.
+    Stepped,
+    /// The next instruction matches a breakpoint.
+    Breakpoint(Breakpoint),
+    /// The last instruction changed a watched value.
+    Watch { watch: Watch, old: i64, new: i64 },
+    /// The computer stopped by itself.
+    Status(Status),
+}
+
+/// A decoded instruction at some address, along with the relative base it
+/// ran with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    pub addr: usize,
+    pub instruction: Instruction,
+    pub params: Vec<i64>,
+    pub rel_base: i64,
+}
+
+impl Trace {
+    fn at(computer: &IntCodeComputer, addr: usize) -> Option<Trace> {
+        let instruction = Instruction::decode(addr, computer.get(addr)).ok()?;
+        let params = (1..instruction.size())
+            .map(|n| computer.get(addr + n))
+            .collect();
+
+        Some(Trace {
+            addr,
+            instruction,
+            params,
+            rel_base: computer.rel_base(),
+        })
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = format_instruction(&self.instruction, &self.params);
+        write!(f, "{:04}  {:<32} ; rb={}", self.addr, text, self.rel_base)
+    }
+}
+
+/// Wraps a computer to run it one instruction at a time, stopping at
+/// breakpoints and when watched values change.
+#[derive(Clone, Debug)]
+pub struct Debugger {
+    computer: IntCodeComputer,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<Watch>,
+    history: VecDeque<Trace>,
+    history_len: usize,
+}
+
+impl Debugger {
+    pub fn new(computer: IntCodeComputer) -> Debugger {
+        Debugger {
+            computer,
+            breakpoints: Vec::new(),
+            watches: Vec::new(),
+            history: VecDeque::new(),
+            history_len: DEFAULT_HISTORY,
+        }
+    }
+
+    /// Sets how many executed instructions to remember.
+    pub fn with_history(mut self, len: usize) -> Debugger {
+        self.history_len = len;
+        self.history.truncate(len);
+        self
+    }
+
+    pub fn computer(&self) -> &IntCodeComputer {
+        &self.computer
+    }
+
+    /// Gives access to the computer, to queue input or patch memory.
+    pub fn computer_mut(&mut self) -> &mut IntCodeComputer {
+        &mut self.computer
+    }
+
+    /// Adds a breakpoint, returning false if it was already set.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        if self.breakpoints.contains(&breakpoint) {
+            return false;
+        }
+
+        self.breakpoints.push(breakpoint);
+        true
+    }
+
+    /// Removes a breakpoint, returning false if it wasn't set.
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        let len = self.breakpoints.len();
+        self.breakpoints.retain(|&b| b != breakpoint);
+        self.breakpoints.len() != len
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    /// Adds a watch, returning false if it was already set.
+    pub fn add_watch(&mut self, watch: Watch) -> bool {
+        if self.watches.contains(&watch) {
+            return false;
+        }
+
+        self.watches.push(watch);
+        true
+    }
+
+    /// Removes a watch, returning false if it wasn't set.
+    pub fn remove_watch(&mut self, watch: Watch) -> bool {
+        let len = self.watches.len();
+        self.watches.retain(|&w| w != watch);
+        self.watches.len() != len
+    }
+
+    pub fn watches(&self) -> &[Watch] {
+        &self.watches
+    }
+
+    /// The most recently executed instructions, oldest first.
+    pub fn history(&self) -> impl Iterator<Item = &Trace> {
+        self.history.iter()
+    }
+
+    /// The instruction that will be executed next, if it can be decoded.
+    pub fn next_instruction(&self) -> Option<Trace> {
+        Trace::at(&self.computer, self.computer.pos())
+    }
+
+    /// The breakpoint that matches the next instruction, if any.
+    pub fn breakpoint_hit(&self) -> Option<Breakpoint> {
+        let pos = self.computer.pos();
+        let opcode = Opcode::from_code(self.computer.get(pos) % 100);
+
+        self.breakpoints.iter().copied().find(|&b| match b {
+            Breakpoint::Address(addr) => addr == pos,
+            Breakpoint::Opcode(op) => Some(op) == opcode,
+        })
+    }
+
+    /// The current value of a watch.
+    pub fn watched(&self, watch: Watch) -> i64 {
+        match watch {
+            Watch::Memory(addr) => self.computer.get(addr),
+            Watch::RelativeBase => self.computer.rel_base(),
+        }
+    }
+
+    /// Executes a single instruction. Breakpoints are ignored, since the
+    /// point of stepping is to get past them.
+    pub fn step(&mut self) -> Result<Stop, Error> {
+        let trace = self.next_instruction();
+        let before: Vec<i64> = self.watches.iter().map(|&w| self.watched(w)).collect();
+
+        let status = self.computer.step()?;
+        if status == Some(Status::WaitingForInput) {
+            // Nothing was executed, so there's nothing to record
+            return Ok(Stop::Status(Status::WaitingForInput));
+        }
+
+        if let Some(trace) = trace {
+            if self.history.len() == self.history_len {
+                self.history.pop_front();
+            }
+            if self.history_len > 0 {
+                self.history.push_back(trace);
+            }
+        }
+
+        if let Some(status) = status {
+            return Ok(Stop::Status(status));
+        }
+
+        let changed = self
+            .watches
+            .iter()
+            .zip(before)
+            .map(|(&watch, old)| (watch, old, self.watched(watch)))
+            .find(|&(_, old, new)| old != new);
+
+        Ok(match changed {
+            Some((watch, old, new)) => Stop::Watch { watch, old, new },
+            None => Stop::Stepped,
+        })
+    }
+
+    /// Runs until a breakpoint or watch triggers, or the computer stops by
+    /// itself. At least one instruction is always executed, so continuing
+    /// from a breakpoint doesn't stop on it again straight away.
+    pub fn resume(&mut self) -> Result<Stop, Error> {
+        loop {
+            let stop = self.step()?;
+            if stop != Stop::Stepped {
+                return Ok(stop);
+            }
+
+            if let Some(breakpoint) = self.breakpoint_hit() {
+                return Ok(Stop::Breakpoint(breakpoint));
+            }
+        }
+    }
+
+    /// Describes the instruction pointer, relative base, queued input and
+    /// the next instruction.
+    pub fn registers(&self) -> String {
+        let next = match self.next_instruction() {
+            Some(trace) => format_instruction(&trace.instruction, &trace.params),
+            None => format!("??? {}", self.computer.get(self.computer.pos())),
+        };
+        let input: Vec<String> = self
+            .computer
+            .pending_input()
+            .map(|v| v.to_string())
+            .collect();
+
+        format!(
+            "pos={:04} rb={} input=[{}]\nnext: {}",
+            self.computer.pos(),
+            self.computer.rel_base(),
+            input.join(", "),
+            next.trim_end()
+        )
+    }
+
+    /// Shows `len` memory cells starting from `start`, eight to a line.
+    pub fn memory_dump(&self, start: usize, len: usize) -> String {
+        (start..start + len)
+            .step_by(8)
+            .map(|row| {
+                let values: Vec<String> = (row..(row + 8).min(start + len))
+                    .map(|addr| format!("{:>6}", self.computer.get(addr)))
+                    .collect();
+                format!("{:04}: {}", row, values.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debugger(program: &str) -> Debugger {
+        Debugger::new(program.parse().unwrap())
+    }
+
+    #[test]
+    fn step_test() {
+        let mut debugger = debugger("1101,2,3,5,104,0,99");
+        assert_eq!(debugger.step(), Ok(Stop::Stepped));
+        assert_eq!(debugger.computer().pos(), 4);
+        assert_eq!(debugger.step(), Ok(Stop::Status(Status::Output(5))));
+        assert_eq!(debugger.step(), Ok(Stop::Status(Status::Finished)));
+    }
+
+    #[test]
+    fn breakpoint_test() {
+        // Counts down from 3, outputting each value
+        let program = "4,13,1001,13,-1,13,1005,13,0,99,0,0,0,3";
+        let mut debugger = debugger(program);
+        debugger.add_breakpoint(Breakpoint::Address(6));
+        assert!(!debugger.add_breakpoint(Breakpoint::Address(6)));
+
+        assert_eq!(debugger.resume(), Ok(Stop::Status(Status::Output(3))));
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Breakpoint(Breakpoint::Address(6)))
+        );
+        assert_eq!(debugger.computer().get(13), 2);
+
+        // Continuing from a breakpoint moves past it
+        assert_eq!(debugger.resume(), Ok(Stop::Status(Status::Output(2))));
+
+        debugger.remove_breakpoint(Breakpoint::Address(6));
+        debugger.add_breakpoint(Breakpoint::Opcode(Opcode::Halt));
+        assert_eq!(debugger.resume(), Ok(Stop::Status(Status::Output(1))));
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Breakpoint(Breakpoint::Opcode(Opcode::Halt)))
+        );
+        assert_eq!(debugger.computer().pos(), 9);
+    }
+
+    #[test]
+    fn watch_test() {
+        let mut debugger = debugger("109,5,1101,1,2,20,3,20,99");
+        debugger.add_watch(Watch::RelativeBase);
+        debugger.add_watch(Watch::Memory(20));
+
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watch {
+                watch: Watch::RelativeBase,
+                old: 0,
+                new: 5
+            })
+        );
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watch {
+                watch: Watch::Memory(20),
+                old: 0,
+                new: 3
+            })
+        );
+
+        // Waiting for input doesn't execute anything
+        assert_eq!(debugger.resume(), Ok(Stop::Status(Status::WaitingForInput)));
+        debugger.computer_mut().push_input(7);
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watch {
+                watch: Watch::Memory(20),
+                old: 3,
+                new: 7
+            })
+        );
+    }
+
+    #[test]
+    fn history_test() {
+        let mut debugger = debugger("1101,1,1,9,1101,2,2,9,99,0").with_history(2);
+        assert_eq!(debugger.resume(), Ok(Stop::Status(Status::Finished)));
+
+        let history: Vec<usize> = debugger.history().map(|t| t.addr).collect();
+        assert_eq!(history, vec![4, 8]);
+        assert_eq!(
+            debugger.history().next().unwrap().to_string(),
+            "0004  ADD  2, 2, [9]                   ; rb=0"
+        );
+    }
+
+    #[test]
+    fn dump_test() {
+        let debugger = debugger("104,42,99");
+        assert_eq!(
+            debugger.registers(),
+            "pos=0000 rb=0 input=[]\nnext: OUT  42"
+        );
+        assert_eq!(
+            debugger.memory_dump(0, 10),
+            "0000:    104     42     99      0      0      0      0      0\n0008:      0      0"
+        );
+    }
+}
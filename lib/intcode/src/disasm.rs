@@ -0,0 +1,387 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::{Instruction, Mode, Opcode};
+
+/// How many data values to show on each line of a listing.
+const DATA_WIDTH: usize = 8;
+
+/// A single line of a disassembled program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Item {
+    /// An instruction that can be reached from the entry point.
+    Code {
+        addr: usize,
+        instruction: Instruction,
+        params: Vec<i64>,
+    },
+    /// A run of values that are never executed.
+    Data { addr: usize, values: Vec<i64> },
+}
+
+impl Item {
+    pub fn addr(&self) -> usize {
+        match self {
+            Item::Code { addr, .. } | Item::Data { addr, .. } => *addr,
+        }
+    }
+}
+
+/// The result of disassembling a program, which can be printed as an
+/// annotated listing.
+#[derive(Clone, Debug)]
+pub struct Listing {
+    pub items: Vec<Item>,
+    /// Addresses that some instruction jumps to with an immediate target.
+    pub jump_targets: BTreeSet<usize>,
+    /// Instructions that are overwritten at runtime, keyed by their address,
+    /// along with the addresses of the instructions that write to them.
+    pub modified: BTreeMap<usize, Vec<usize>>,
+}
+
+/// Disassembles a program.
+///
+/// Code is found by following the control flow from address 0, including
+/// both branches of any conditional jump with a known target. Jumps through
+/// memory can't be followed, but subroutine calls push their return address
+/// as a constant first, so any constant that is stored and points just past
+/// an unconditional jump is treated as code too. Everything else is data.
+pub fn disassemble(program: &[i64]) -> Listing {
+    let mut code: BTreeMap<usize, (Instruction, Vec<i64>)> = BTreeMap::new();
+    let mut owned = vec![false; program.len()];
+    let mut jump_targets = BTreeSet::new();
+
+    let mut seeds = BTreeSet::new();
+    let mut pending = vec![0];
+    loop {
+        while let Some(addr) = pending.pop() {
+            trace(
+                program,
+                addr,
+                &mut code,
+                &mut owned,
+                &mut jump_targets,
+                &mut pending,
+            );
+        }
+
+        // Look for return addresses of subroutine calls
+        pending = code
+            .values()
+            .filter_map(|(instruction, params)| stored_constant(instruction, params))
+            .filter_map(|value| usize::try_from(value).ok())
+            .filter(|&addr| addr > 0 && addr < program.len() && !owned[addr] && owned[addr - 1])
+            .filter(|&addr| seeds.insert(addr))
+            .collect();
+
+        if pending.is_empty() {
+            break;
+        }
+    }
+
+    // Find any instructions that are written to by other instructions
+    let mut modified: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (&addr, (instruction, params)) in &code {
+        let target = match instruction.opcode.write_param() {
+            Some(n) if instruction.modes[n] == Mode::Position => params[n],
+            _ => continue,
+        };
+        let target = match usize::try_from(target) {
+            Ok(target) => target,
+            Err(_) => continue,
+        };
+
+        if let Some((&start, (target_instruction, _))) = code.range(..=target).next_back() {
+            if target < start + target_instruction.size() {
+                modified.entry(start).or_default().push(addr);
+            }
+        }
+    }
+
+    let mut items = Vec::new();
+    let mut addr = 0;
+    while addr < program.len() {
+        if let Some((instruction, params)) = code.get(&addr) {
+            items.push(Item::Code {
+                addr,
+                instruction: *instruction,
+                params: params.clone(),
+            });
+            addr += instruction.size();
+            continue;
+        }
+
+        let start = addr;
+        while addr < program.len()
+            && !owned[addr]
+            && addr - start < DATA_WIDTH
+            && (addr == start || !jump_targets.contains(&addr))
+        {
+            addr += 1;
+        }
+
+        items.push(Item::Data {
+            addr: start,
+            values: program[start..addr].to_vec(),
+        });
+    }
+
+    Listing {
+        items,
+        jump_targets,
+        modified,
+    }
+}
+
+/// Follows straight-line code from `addr` until it halts, jumps away for good
+/// or runs into something that can't be decoded.
+fn trace(
+    program: &[i64],
+    mut addr: usize,
+    code: &mut BTreeMap<usize, (Instruction, Vec<i64>)>,
+    owned: &mut [bool],
+    jump_targets: &mut BTreeSet<usize>,
+    pending: &mut Vec<usize>,
+) {
+    while addr < program.len() && !owned[addr] {
+        let instruction = match Instruction::decode(addr, program[addr]) {
+            Ok(instruction) => instruction,
+            Err(_) => return,
+        };
+
+        let end = addr + instruction.size();
+        if end > program.len() || owned[addr..end].iter().any(|&o| o) {
+            return;
+        }
+
+        let params = program[addr + 1..end].to_vec();
+        owned[addr..end].iter_mut().for_each(|o| *o = true);
+        code.insert(addr, (instruction, params.clone()));
+
+        match instruction.opcode {
+            Opcode::Halt => return,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                if instruction.modes[1] == Mode::Immediate {
+                    if let Ok(target) = usize::try_from(params[1]) {
+                        jump_targets.insert(target);
+                        pending.push(target);
+                    }
+                }
+
+                if instruction.modes[0] == Mode::Immediate
+                    && (params[0] != 0) == (instruction.opcode == Opcode::JumpIfTrue)
+                {
+                    // The jump is always taken, so there's no fall through
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        addr = end;
+    }
+}
+
+/// The value written by an instruction whose inputs are both constants.
+fn stored_constant(instruction: &Instruction, params: &[i64]) -> Option<i64> {
+    if instruction.modes[0] != Mode::Immediate || instruction.modes[1] != Mode::Immediate {
+        return None;
+    }
+
+    match instruction.opcode {
+        Opcode::Add => params[0].checked_add(params[1]),
+        Opcode::Multiply => params[0].checked_mul(params[1]),
+        _ => None,
+    }
+}
+
+/// Formats an instruction as its mnemonic followed by its operands.
+pub(crate) fn format_instruction(instruction: &Instruction, params: &[i64]) -> String {
+    let operands = params
+        .iter()
+        .zip(instruction.modes.iter())
+        .map(|(&v, &m)| format_param(m, v))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{:<4} {}", instruction.opcode.mnemonic(), operands)
+}
+
+fn format_param(mode: Mode, value: i64) -> String {
+    match mode {
+        Mode::Position => format!("[{}]", value),
+        Mode::Immediate => value.to_string(),
+        Mode::Relative if value < 0 => format!("[rb-{}]", -value),
+        Mode::Relative => format!("[rb+{}]", value),
+    }
+}
+
+/// Renders values as a string if they all look like printable ASCII.
+fn ascii(values: &[i64]) -> Option<String> {
+    values
+        .iter()
+        .map(|&v| match v {
+            10 => Some('\n'),
+            32..=126 => Some(v as u8 as char),
+            _ => None,
+        })
+        .collect::<Option<String>>()
+        .map(|s| format!("{:?}", s))
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let last = self.items.last().map_or(0, |item| item.addr());
+        let width = last.to_string().len().max(4);
+
+        for item in &self.items {
+            let addr = item.addr();
+            if self.jump_targets.contains(&addr) {
+                writeln!(f)?;
+                writeln!(f, "{:0width$}:", addr, width = width)?;
+            }
+
+            let (raw, text, comments) = match item {
+                Item::Code {
+                    instruction,
+                    params,
+                    ..
+                } => {
+                    let raw = std::iter::once(instruction.encode())
+                        .chain(params.iter().copied())
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",");
+
+                    let text = format_instruction(instruction, params);
+
+                    let mut comments = Vec::new();
+                    if let Opcode::JumpIfTrue | Opcode::JumpIfFalse = instruction.opcode {
+                        comments.push(match instruction.modes[1] {
+                            Mode::Immediate => format!("-> {:0width$}", params[1], width = width),
+                            _ => "-> computed".to_string(),
+                        });
+                    }
+                    if let Some(writers) = self.modified.get(&addr) {
+                        let writers: Vec<String> = writers
+                            .iter()
+                            .map(|w| format!("{:0width$}", w, width = width))
+                            .collect();
+                        comments.push(format!("modified by {}", writers.join(", ")));
+                    }
+
+                    (raw, text, comments)
+                }
+                Item::Data { values, .. } => {
+                    let raw = values
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    (raw, "DATA".to_string(), ascii(values).into_iter().collect())
+                }
+            };
+
+            let line = format!("{:0width$}  {:<24} {}", addr, raw, text, width = width);
+            if comments.is_empty() {
+                writeln!(f, "{}", line.trim_end())?;
+            } else {
+                writeln!(f, "{:<56} ; {}", line, comments.join("; "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_program;
+
+    fn listing(program: &str) -> Listing {
+        disassemble(&parse_program(program).unwrap())
+    }
+
+    #[test]
+    fn straight_line_test() {
+        let listing = listing("1002,4,3,4,33");
+        assert_eq!(
+            listing.items,
+            vec![
+                Item::Code {
+                    addr: 0,
+                    instruction: Instruction::decode(0, 1002).unwrap(),
+                    params: vec![4, 3, 4],
+                },
+                Item::Data {
+                    addr: 4,
+                    values: vec![33]
+                },
+            ]
+        );
+        assert_eq!(listing.modified.get(&0), None);
+    }
+
+    #[test]
+    fn data_after_halt_test() {
+        // Everything after the halt looks like valid instructions, but is
+        // never reached
+        let listing = listing("104,7,99,1,1,1,1");
+        assert_eq!(listing.items.len(), 3);
+        assert_eq!(
+            listing.items[2],
+            Item::Data {
+                addr: 3,
+                values: vec![1, 1, 1, 1]
+            }
+        );
+    }
+
+    #[test]
+    fn jump_test() {
+        // Jumps over a data cell to the output
+        let listing = listing("1105,1,4,42,4,3,99");
+        assert_eq!(listing.jump_targets, BTreeSet::from([4]));
+        let addrs: Vec<usize> = listing.items.iter().map(|i| i.addr()).collect();
+        assert_eq!(addrs, vec![0, 3, 4, 6]);
+        assert!(matches!(listing.items[1], Item::Data { .. }));
+    }
+
+    #[test]
+    fn subroutine_test() {
+        // Pushes a return address, calls a subroutine at 10 which outputs 1
+        // and returns through the stack, then halts.
+        let program = "109,20,21101,9,0,0,1105,1,10,99,104,1,2106,0,0";
+        let listing = listing(program);
+        let addrs: Vec<usize> = listing.items.iter().map(|i| i.addr()).collect();
+        assert_eq!(addrs, vec![0, 2, 6, 9, 10, 12]);
+    }
+
+    #[test]
+    fn self_modifying_test() {
+        // Overwrites the output's parameter before running it
+        let listing = listing("1101,0,9,5,104,0,99");
+        assert_eq!(listing.modified.get(&4), Some(&vec![0]));
+
+        let text = listing.to_string();
+        assert!(text.contains("ADD  0, 9, [5]"));
+        assert!(text.contains("modified by 0000"));
+    }
+
+    #[test]
+    fn display_test() {
+        let listing = listing("3,11,1008,11,10,12,1005,12,13,99,0,0,0,204,-1,99");
+        let expected = "\
+0000  3,11                     IN   [11]
+0002  1008,11,10,12            EQ   [11], 10, [12]
+0006  1005,12,13               JT   [12], 13             ; -> 0013
+0009  99                       HALT
+0010  0,0,0                    DATA
+
+0013:
+0013  204,-1                   OUT  [rb-1]
+0015  99                       HALT
+";
+        assert_eq!(listing.to_string(), expected);
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// Everything that can go wrong while loading or running an Intcode program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The program text contained something that isn't an integer.
+    Parse { index: usize, value: String },
+    /// The instruction at `pos` has an opcode we don't recognise.
+    UnknownOpcode { pos: usize, code: i64 },
+    /// The instruction at `pos` uses a parameter mode we don't recognise.
+    UnknownMode { pos: usize, mode: i64 },
+    /// The instruction at `pos` tried to access a negative address.
+    NegativeAddress { pos: usize, addr: i64 },
+    /// The instruction at `pos` tried to write to an immediate parameter.
+    ImmediateWrite { pos: usize },
+    /// The program wanted input at `pos` but none was available.
+    MissingInput { pos: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse { index, value } => {
+                write!(f, "invalid value {:?} at index {}", value, index)
+            }
+            Error::UnknownOpcode { pos, code } => {
+                write!(f, "unknown opcode {} at position {}", code, pos)
+            }
+            Error::UnknownMode { pos, mode } => {
+                write!(f, "unknown parameter mode {} at position {}", mode, pos)
+            }
+            Error::NegativeAddress { pos, addr } => {
+                write!(f, "negative address {} used at position {}", addr, pos)
+            }
+            Error::ImmediateWrite { pos } => {
+                write!(f, "write to immediate parameter at position {}", pos)
+            }
+            Error::MissingInput { pos } => write!(f, "no input available at position {}", pos),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,184 @@
+use crate::Error;
+
+/// The operation encoded in the last two digits of an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    /// Decodes the opcode from a full instruction value, ignoring the modes.
+    pub fn from_code(code: i64) -> Option<Opcode> {
+        match code % 100 {
+            1 => Some(Opcode::Add),
+            2 => Some(Opcode::Multiply),
+            3 => Some(Opcode::Input),
+            4 => Some(Opcode::Output),
+            5 => Some(Opcode::JumpIfTrue),
+            6 => Some(Opcode::JumpIfFalse),
+            7 => Some(Opcode::LessThan),
+            8 => Some(Opcode::Equals),
+            9 => Some(Opcode::AdjustRelativeBase),
+            99 => Some(Opcode::Halt),
+            _ => None,
+        }
+    }
+
+    /// The number of parameters that follow the opcode.
+    pub fn params(&self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustRelativeBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Add => "ADD",
+            Opcode::Multiply => "MUL",
+            Opcode::Input => "IN",
+            Opcode::Output => "OUT",
+            Opcode::JumpIfTrue => "JT",
+            Opcode::JumpIfFalse => "JF",
+            Opcode::LessThan => "LT",
+            Opcode::Equals => "EQ",
+            Opcode::AdjustRelativeBase => "ARB",
+            Opcode::Halt => "HALT",
+        }
+    }
+
+    /// Looks up an opcode by its mnemonic, ignoring case.
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Opcode> {
+        (1..=9)
+            .chain([99])
+            .filter_map(Opcode::from_code)
+            .find(|opcode| opcode.mnemonic().eq_ignore_ascii_case(mnemonic))
+    }
+
+    /// The index of the parameter this opcode writes to, if any.
+    pub fn write_param(&self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => Some(2),
+            Opcode::Input => Some(0),
+            _ => None,
+        }
+    }
+}
+
+/// How a parameter should be interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl Mode {
+    fn from_digit(digit: i64) -> Option<Mode> {
+        match digit {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+}
+
+/// A decoded instruction: the opcode plus the mode of each of its parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    /// Decodes the instruction value found at `pos`.
+    pub fn decode(pos: usize, code: i64) -> Result<Instruction, Error> {
+        let opcode = Opcode::from_code(code).ok_or(Error::UnknownOpcode { pos, code })?;
+
+        let mut modes = [Mode::Position; 3];
+        let mut digits = code / 100;
+        for mode in modes.iter_mut() {
+            *mode = Mode::from_digit(digits % 10).ok_or(Error::UnknownMode {
+                pos,
+                mode: digits % 10,
+            })?;
+            digits /= 10;
+        }
+
+        Ok(Instruction { opcode, modes })
+    }
+
+    /// Re-encodes the instruction into the value it was decoded from.
+    pub fn encode(&self) -> i64 {
+        let code = match self.opcode {
+            Opcode::Add => 1,
+            Opcode::Multiply => 2,
+            Opcode::Input => 3,
+            Opcode::Output => 4,
+            Opcode::JumpIfTrue => 5,
+            Opcode::JumpIfFalse => 6,
+            Opcode::LessThan => 7,
+            Opcode::Equals => 8,
+            Opcode::AdjustRelativeBase => 9,
+            Opcode::Halt => 99,
+        };
+
+        let modes = self.modes.iter().rev().fold(0, |acc, mode| {
+            acc * 10
+                + match mode {
+                    Mode::Position => 0,
+                    Mode::Immediate => 1,
+                    Mode::Relative => 2,
+                }
+        });
+
+        modes * 100 + code
+    }
+
+    /// The total length of the instruction, including the opcode itself.
+    pub fn size(&self) -> usize {
+        self.opcode.params() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_test() {
+        assert_eq!(
+            Instruction::decode(0, 1002),
+            Ok(Instruction {
+                opcode: Opcode::Multiply,
+                modes: [Mode::Position, Mode::Immediate, Mode::Position],
+            })
+        );
+        assert_eq!(
+            Instruction::decode(0, 21107).map(|i| i.modes),
+            Ok([Mode::Immediate, Mode::Immediate, Mode::Relative])
+        );
+        assert_eq!(Instruction::decode(0, 21107).unwrap().encode(), 21107);
+        assert_eq!(Instruction::decode(0, 99).unwrap().encode(), 99);
+        assert_eq!(
+            Instruction::decode(4, 42),
+            Err(Error::UnknownOpcode { pos: 4, code: 42 })
+        );
+        assert_eq!(
+            Instruction::decode(4, 301),
+            Err(Error::UnknownMode { pos: 4, mode: 3 })
+        );
+    }
+}
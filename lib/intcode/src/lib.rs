@@ -0,0 +1,22 @@
+//! The Intcode interpreter shared by the 2019 puzzles.
+//!
+//! A program is loaded into an [`IntCodeComputer`], which can either be run to
+//! completion with all of its input supplied up front, or driven one output at
+//! a time so that its input can depend on what it has produced so far.
+//! Several computers can be wired together into a [`Network`].
+
+mod ascii;
+mod computer;
+mod debug;
+mod disasm;
+mod error;
+mod instruction;
+mod network;
+
+pub use ascii::AsciiOutput;
+pub use computer::{parse_program, read_program, IntCodeComputer, Status};
+pub use debug::{Breakpoint, Debugger, Stop, Trace, Watch};
+pub use disasm::{disassemble, Item, Listing};
+pub use error::Error;
+pub use instruction::{Instruction, Mode, Opcode};
+pub use network::{Addressing, Nat, Network, Packet};
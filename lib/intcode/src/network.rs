@@ -0,0 +1,319 @@
+use std::ops::ControlFlow;
+
+use crate::{Error, IntCodeComputer, Status};
+
+/// How the outputs of each machine find their way to the others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Addressing {
+    /// Every output is passed straight on to the next machine, and the last
+    /// machine feeds the first.
+    Ring,
+    /// Outputs are grouped into packets of an address followed by this many
+    /// values. Addresses that aren't a machine go to the NAT.
+    Packets(usize),
+}
+
+/// Values sent to an address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub to: i64,
+    pub values: Vec<i64>,
+}
+
+/// Handles the traffic that leaves the network, and decides what to do when
+/// it goes quiet.
+pub trait Nat {
+    type Output;
+
+    /// Receives a packet sent to an address outside the network. Breaking
+    /// stops the network.
+    fn receive(&mut self, packet: Packet) -> ControlFlow<Self::Output>;
+
+    /// Called when every machine that's still running is waiting for input
+    /// that nobody has sent. Either sends a packet to wake the network up or
+    /// breaks to stop it.
+    fn idle(&mut self) -> ControlFlow<Self::Output, Packet>;
+}
+
+/// Drops anything sent outside the network and stops it when it goes idle.
+impl Nat for () {
+    type Output = ();
+
+    fn receive(&mut self, _packet: Packet) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    fn idle(&mut self) -> ControlFlow<(), Packet> {
+        ControlFlow::Break(())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Machine {
+    computer: IntCodeComputer,
+    outbox: Vec<i64>,
+    last_output: Option<i64>,
+    halted: bool,
+}
+
+/// Runs several computers that talk to each other.
+///
+/// The machines take turns in order. Each turn a machine runs until it needs
+/// input it doesn't have or halts, and anything it sends is delivered
+/// immediately, so the same programs always produce the same traffic.
+#[derive(Clone, Debug)]
+pub struct Network {
+    machines: Vec<Machine>,
+    addressing: Addressing,
+    empty_input: Option<i64>,
+}
+
+impl Network {
+    pub fn new(computers: Vec<IntCodeComputer>, addressing: Addressing) -> Network {
+        let machines = computers
+            .into_iter()
+            .map(|computer| Machine {
+                computer,
+                outbox: Vec::new(),
+                last_output: None,
+                halted: false,
+            })
+            .collect();
+
+        Network {
+            machines,
+            addressing,
+            empty_input: None,
+        }
+    }
+
+    /// Starts `count` copies of the same program.
+    pub fn with_copies(program: &[i64], count: usize, addressing: Addressing) -> Network {
+        Network::new(vec![IntCodeComputer::new(program); count], addressing)
+    }
+
+    /// Gives a machine this value at the start of its turn if it's waiting
+    /// for input and nothing has been sent to it, instead of leaving it
+    /// blocked.
+    pub fn with_empty_input(mut self, value: i64) -> Network {
+        self.empty_input = Some(value);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    pub fn computer(&self, machine: usize) -> &IntCodeComputer {
+        &self.machines[machine].computer
+    }
+
+    /// Queues a value for one of the machines.
+    pub fn push_input(&mut self, machine: usize, value: i64) {
+        self.machines[machine].computer.push_input(value);
+    }
+
+    /// The most recent value output by a machine.
+    pub fn last_output(&self, machine: usize) -> Option<i64> {
+        self.machines[machine].last_output
+    }
+
+    /// Runs the network until the NAT stops it, returning what it stopped
+    /// with, or until every machine has halted.
+    pub fn run<N: Nat>(&mut self, nat: &mut N) -> Result<Option<N::Output>, Error> {
+        loop {
+            let mut running = false;
+            let mut idle = true;
+
+            for i in 0..self.machines.len() {
+                if self.machines[i].halted {
+                    continue;
+                }
+
+                running = true;
+                match self.turn(i, nat)? {
+                    ControlFlow::Break(output) => return Ok(Some(output)),
+                    ControlFlow::Continue(quiet) => idle &= quiet,
+                }
+            }
+
+            if !running {
+                return Ok(None);
+            }
+
+            if idle {
+                let packet = match nat.idle() {
+                    ControlFlow::Break(output) => return Ok(Some(output)),
+                    ControlFlow::Continue(packet) => packet,
+                };
+                if let ControlFlow::Break(output) = self.deliver(packet, nat) {
+                    return Ok(Some(output));
+                }
+            }
+        }
+    }
+
+    /// Runs a single machine until it blocks or halts. Continues with whether
+    /// the machine had nothing to do.
+    fn turn<N: Nat>(
+        &mut self,
+        i: usize,
+        nat: &mut N,
+    ) -> Result<ControlFlow<N::Output, bool>, Error> {
+        let starved = self.machines[i].computer.pending_input().next().is_none();
+        if let (true, Some(value)) = (starved, self.empty_input) {
+            self.machines[i].computer.push_input(value);
+        }
+
+        let count = self.machines.len();
+        let mut sent = false;
+        loop {
+            let machine = &mut self.machines[i];
+            match machine.computer.run()? {
+                Status::Output(value) => {
+                    machine.last_output = Some(value);
+                    machine.outbox.push(value);
+
+                    let packet = match self.addressing {
+                        Addressing::Ring => Packet {
+                            to: ((i + 1) % count) as i64,
+                            values: machine.outbox.split_off(0),
+                        },
+                        Addressing::Packets(len) if machine.outbox.len() == len + 1 => {
+                            let values = machine.outbox.split_off(1);
+                            Packet {
+                                to: machine.outbox.pop().unwrap(),
+                                values,
+                            }
+                        }
+                        Addressing::Packets(_) => continue,
+                    };
+
+                    sent = true;
+                    if let ControlFlow::Break(output) = self.deliver(packet, nat) {
+                        return Ok(ControlFlow::Break(output));
+                    }
+                }
+                Status::WaitingForInput => break,
+                Status::Finished => {
+                    machine.halted = true;
+                    break;
+                }
+            }
+        }
+
+        Ok(ControlFlow::Continue(starved && !sent))
+    }
+
+    fn deliver<N: Nat>(&mut self, packet: Packet, nat: &mut N) -> ControlFlow<N::Output> {
+        match usize::try_from(packet.to) {
+            Ok(to) if to < self.machines.len() => {
+                self.machines[to].computer.extend_input(packet.values);
+                ControlFlow::Continue(())
+            }
+            _ => nat.receive(packet),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_program;
+
+    /// Reads its address, then waits for packets of (x, y) and passes each one
+    /// on to the next address as (x, y + 1). A -1 means there's no packet.
+    const RELAY: &str = "3,100,3,101,1008,101,-1,104,1005,104,2,3,102,1001,100,1,103,\
+                         4,103,4,101,1001,102,1,105,4,105,1105,1,2";
+
+    fn relays(count: usize) -> Network {
+        let program = parse_program(RELAY).unwrap();
+        let mut network =
+            Network::with_copies(&program, count, Addressing::Packets(2)).with_empty_input(-1);
+        for i in 0..count {
+            network.push_input(i, i as i64);
+        }
+        network
+    }
+
+    /// Kicks off the network when it's idle and keeps what comes out, until
+    /// it has been round enough times.
+    struct Loop {
+        last: Option<Packet>,
+        idles: usize,
+        until: i64,
+    }
+
+    impl Nat for Loop {
+        type Output = i64;
+
+        fn receive(&mut self, packet: Packet) -> ControlFlow<i64> {
+            self.last = Some(packet);
+            ControlFlow::Continue(())
+        }
+
+        fn idle(&mut self) -> ControlFlow<i64, Packet> {
+            self.idles += 1;
+            match self.last.take() {
+                None => ControlFlow::Continue(Packet {
+                    to: 0,
+                    values: vec![7, 0],
+                }),
+                Some(packet) if packet.values[1] >= self.until => {
+                    ControlFlow::Break(packet.values[1])
+                }
+                Some(packet) => ControlFlow::Continue(Packet { to: 0, ..packet }),
+            }
+        }
+    }
+
+    #[test]
+    fn ring_test() {
+        // Adds one to its input and outputs it
+        let program = parse_program("3,9,1001,9,1,9,4,9,99,0").unwrap();
+        let mut network = Network::with_copies(&program, 3, Addressing::Ring);
+        network.push_input(0, 10);
+
+        assert_eq!(network.run(&mut ()), Ok(None));
+        assert_eq!(network.last_output(2), Some(13));
+        assert_eq!(network.computer(0).pending_input().next(), Some(&13));
+    }
+
+    #[test]
+    fn feedback_test() {
+        // Adds one to each input until it reaches 20, then halts
+        let program = parse_program("3,20,1001,20,1,20,4,20,1007,20,20,21,1005,21,0,99").unwrap();
+        let mut network = Network::with_copies(&program, 2, Addressing::Ring);
+        network.push_input(0, 0);
+
+        assert_eq!(network.run(&mut ()), Ok(None));
+        assert_eq!(network.last_output(0), Some(21));
+        assert_eq!(network.last_output(1), Some(20));
+    }
+
+    #[test]
+    fn packets_test() {
+        let mut network = relays(3);
+        let mut nat = Loop {
+            last: None,
+            idles: 0,
+            until: 9,
+        };
+
+        // Each trip through the three relays adds 3
+        assert_eq!(network.run(&mut nat), Ok(Some(9)));
+        assert_eq!(nat.idles, 4);
+    }
+
+    #[test]
+    fn idle_test() {
+        // Nothing is ever sent, so the default NAT stops the network
+        let mut network = relays(2);
+        assert_eq!(network.run(&mut ()), Ok(Some(())));
+        assert_eq!(network.computer(0).pos(), 2);
+    }
+}
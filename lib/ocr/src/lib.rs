@@ -0,0 +1,244 @@
+//! Reading the block letters some puzzles draw their answers in.
+//!
+//! There are two fonts: letters four wide and six high, which most years
+//! use, and letters six wide and ten high from 2018. Each letter is matched
+//! against the fonts where it starts, rather than only split off at the
+//! next empty column, because the small Y is five wide and runs straight
+//! into whatever's drawn after it. So letters can be any distance apart, or
+//! touching, and the drawing can have empty space around it.
+
+use aoc_grid::Grid;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The letters of the small font, as they're drawn on the sheet below.
+const SMALL_LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+
+const SMALL: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#.###...##.#..#.#.....##..###..###...###.#..#.#...#.####",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#",
+    "#..#.###..#....###..###..#....####..#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.",
+    "####.#..#.#....#....#....#.##.#..#..#.....#.#.#..#....#..#.###..###...##..#..#...#....#..",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...",
+    "#..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####",
+];
+
+const LARGE_LETTERS: &str = "ABCEFGHJKLNPRXZ";
+
+const LARGE: [&str; 10] = [
+    "..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######",
+    ".#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.",
+    "#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..",
+    "######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...",
+    "#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....",
+    "#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....",
+    "#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....",
+    "#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Nothing in the drawing was lit.
+    Blank,
+    /// A letter that isn't in either font, counting letters from zero, drawn
+    /// out in `#` and `.`.
+    Unknown { index: usize, glyph: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Blank => write!(f, "there are no letters to read"),
+            Error::Unknown { index, glyph } => {
+                write!(f, "letter {} isn't in either font:\n{}", index + 1, glyph)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Reads the letters drawn by a set of lit `(x, y)` points, with `y` going
+/// down the page.
+pub fn read_points(points: impl IntoIterator<Item = (i64, i64)>) -> Result<String, Error> {
+    let points: Vec<(i64, i64)> = points.into_iter().collect();
+    let (Some(min_x), Some(min_y)) = (
+        points.iter().map(|&(x, _)| x).min(),
+        points.iter().map(|&(_, y)| y).min(),
+    ) else {
+        return Err(Error::Blank);
+    };
+
+    let width = points.iter().map(|&(x, _)| x - min_x + 1).max().unwrap();
+    let height = points.iter().map(|&(_, y)| y - min_y + 1).max().unwrap();
+
+    let mut grid = Grid::new(width as usize, height as usize, false);
+    for (x, y) in points {
+        grid[((x - min_x) as usize, (y - min_y) as usize)] = true;
+    }
+
+    read_grid(&grid)
+}
+
+/// Reads the letters drawn by the lit cells of a grid.
+pub fn read_grid(grid: &Grid<bool>) -> Result<String, Error> {
+    let font = font();
+    glyphs(grid, &font)
+        .into_iter()
+        .enumerate()
+        .map(|(index, glyph)| match font.get(&glyph) {
+            Some(&letter) => Ok(letter),
+            None => Err(Error::Unknown { index, glyph }),
+        })
+        .collect::<Result<String, Error>>()
+        .and_then(|text| match text.is_empty() {
+            true => Err(Error::Blank),
+            false => Ok(text),
+        })
+}
+
+/// Every letter of both fonts, keyed by how it's drawn.
+fn font() -> HashMap<String, char> {
+    [(SMALL_LETTERS, &SMALL[..]), (LARGE_LETTERS, &LARGE[..])]
+        .into_iter()
+        .flat_map(|(letters, sheet)| {
+            let grid = Grid::parse(&sheet.join("\n"), |c| c == '#');
+            glyphs(&grid, &HashMap::new())
+                .into_iter()
+                .zip(letters.chars())
+        })
+        .collect()
+}
+
+/// Splits a drawing into its letters, each drawn out in `#` and `.` a row to
+/// a line. Empty rows above and below all the letters are left out.
+///
+/// At each letter, the widest one in `font` that fits is taken whole, and if
+/// none of them do the letter runs up to the next empty column.
+fn glyphs(grid: &Grid<bool>, font: &HashMap<String, char>) -> Vec<String> {
+    let lit_rows: Vec<usize> = (0..grid.height())
+        .filter(|&y| grid.row(y).contains(&true))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Vec::new();
+    };
+
+    let draw = |left: usize, right: usize| {
+        let rows: Vec<String> = (top..=bottom)
+            .map(|y| {
+                (left..right)
+                    .map(|x| if grid[(x, y)] { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
+    };
+    let empty = |x: usize| !grid.column(x).any(|&lit| lit);
+
+    let mut widths: Vec<usize> = font
+        .keys()
+        .filter_map(|glyph| glyph.lines().next())
+        .map(str::len)
+        .collect();
+    widths.sort_unstable_by(|a, b| b.cmp(a));
+    widths.dedup();
+
+    let mut glyphs = Vec::new();
+    let mut left = 0;
+    while left < grid.width() {
+        if empty(left) {
+            left += 1;
+            continue;
+        }
+
+        let right = widths
+            .iter()
+            .map(|&width| left + width)
+            .filter(|&right| right <= grid.width())
+            .find(|&right| font.contains_key(&draw(left, right)))
+            .or_else(|| (left..grid.width()).find(|&x| empty(x)))
+            .unwrap_or(grid.width());
+
+        glyphs.push(draw(left, right));
+        left = right;
+    }
+
+    glyphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(drawing: &str) -> Result<String, Error> {
+        read_grid(&Grid::parse(drawing, |c| c == '#'))
+    }
+
+    #[test]
+    fn fonts_test() {
+        assert_eq!(read(&SMALL.join("\n")), Ok(SMALL_LETTERS.to_string()));
+        assert_eq!(read(&LARGE.join("\n")), Ok(LARGE_LETTERS.to_string()));
+    }
+
+    #[test]
+    fn spacing_test() {
+        // Drawn with spaces, with room around the letters
+        let drawing = [
+            "                                     ",
+            "  #  #  ##   ##  ###   ##  ####  #   ",
+            "  #  # #  # #  # #  # #  #    #  #   ",
+            "  #### #  # #    #  # #      #   #   ",
+            "  #  # #### # ## ###  #     #    #   ",
+            "  #  # #  # #  # #    #  # #     #   ",
+            "  #  # #  #  ### #     ##  ####  ####",
+        ];
+        assert_eq!(read(&drawing.join("\n")), Ok("HAGPCZL".to_string()));
+    }
+
+    #[test]
+    fn touching_test() {
+        // The small Y fills the five columns each letter gets, so there's no
+        // gap between it and the next one
+        let drawing = [
+            "#..#.#...#####.#...#",
+            "#..#.#...#...#.#...#",
+            "####..#.#...#...#.#.",
+            "#..#...#...#.....#..",
+            "#..#...#..#......#..",
+            "#..#...#..####...#..",
+        ];
+        assert_eq!(read(&drawing.join("\n")), Ok("HYZY".to_string()));
+    }
+
+    #[test]
+    fn points_test() {
+        let points = [(0, 0), (0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]
+            .into_iter()
+            .chain((1..4).map(|x| (x, 5)));
+        assert_eq!(read_points(points), Ok("L".to_string()));
+
+        let shifted = [(-5, 7), (-4, 7), (-3, 7)].into_iter();
+        let err = read_points(shifted).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Unknown {
+                index: 0,
+                glyph: "###".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn errors_test() {
+        assert_eq!(read_points([]), Err(Error::Blank));
+        assert_eq!(read("....\n...."), Err(Error::Blank));
+
+        let err = read("#..#.#\n####.#\n#..#.#").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "letter 1 isn't in either font:\n#..#\n####\n#..#"
+        );
+    }
+}
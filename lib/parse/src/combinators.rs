@@ -0,0 +1,281 @@
+//! Small parsers for the shapes that keep turning up in puzzle inputs, and
+//! ways of putting them together.
+//!
+//! A parser takes the text in front of it and gives back what it read along
+//! with the rest of the text, so parsers can be chained one after another:
+//!
+//! ```
+//! use aoc_parse::combinators::{int, Parser};
+//!
+//! fn claim(s: &str) -> aoc_parse::combinators::Parsed<'_, (u32, u32)> {
+//!     let (id, s) = ("#", int).read(s)?;
+//!     let (x, s) = (" @ ", int).read(s)?;
+//!     Ok(((id, x), s))
+//! }
+//!
+//! assert_eq!(claim("#3 @ 5"), Ok(((3, 5), "")));
+//! ```
+//!
+//! A `&str` is a parser for itself, and a pair `(prefix, parser)` parses the
+//! prefix and then the parser. Any function from `&str` to [`Parsed`] is a
+//! parser too, which is how recursive formats are written. Use
+//! [`complete`] to turn a parser into one for whole lines or blocks, to go
+//! with [`lines`](crate::lines) and [`blocks`](crate::blocks).
+
+use crate::ParseError;
+use std::str::FromStr;
+
+/// What was read, and the text after it.
+pub type Parsed<'a, T> = Result<(T, &'a str), ParseError>;
+
+pub trait Parser<'a, T> {
+    fn read(&mut self, input: &'a str) -> Parsed<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: FnMut(&'a str) -> Parsed<'a, T>,
+{
+    fn read(&mut self, input: &'a str) -> Parsed<'a, T> {
+        self(input)
+    }
+}
+
+/// Text that has to be there, as it is.
+impl<'a> Parser<'a, &'a str> for &str {
+    fn read(&mut self, input: &'a str) -> Parsed<'a, &'a str> {
+        match input.strip_prefix(*self) {
+            Some(rest) => Ok((&input[..self.len()], rest)),
+            None => Err(expected(format!("{:?}", self), input)),
+        }
+    }
+}
+
+/// Text that has to be there, followed by what's wanted.
+impl<'a, T, P: Parser<'a, T>> Parser<'a, T> for (&str, P) {
+    fn read(&mut self, input: &'a str) -> Parsed<'a, T> {
+        let (_, rest) = self.0.read(input)?;
+        self.1.read(rest)
+    }
+}
+
+/// An error for finding `rest` where `what` should have been. Only the
+/// first line of it is shown, but all of it is kept for placing the error.
+fn expected(what: impl std::fmt::Display, rest: &str) -> ParseError {
+    let shown = rest.lines().next().filter(|line| !line.is_empty());
+    let shown = shown.unwrap_or(rest);
+    ParseError {
+        found: Some(rest.to_string()),
+        ..ParseError::expected(what, shown)
+    }
+}
+
+/// An integer, with or without a sign.
+pub fn int<T: FromStr>(input: &str) -> Parsed<'_, T> {
+    let unsigned = input.strip_prefix(['-', '+']).unwrap_or(input);
+    let digits = unsigned.bytes().take_while(u8::is_ascii_digit).count();
+    let (n, rest) = input.split_at(input.len() - unsigned.len() + digits);
+
+    match n.parse() {
+        Ok(n) if digits > 0 => Ok((n, rest)),
+        _ => Err(expected("a number", input)),
+    }
+}
+
+/// One or more letters, digits or underscores.
+pub fn word(input: &str) -> Parsed<'_, &str> {
+    take_while("a word", |c| c.is_alphanumeric() || c == '_').read(input)
+}
+
+/// The characters up to the first that doesn't match `f`, of which there
+/// has to be at least one.
+pub fn take_while<'a>(
+    what: &'static str,
+    mut f: impl FnMut(char) -> bool,
+) -> impl FnMut(&'a str) -> Parsed<'a, &'a str> {
+    move |input| {
+        let end = input.find(|c| !f(c)).unwrap_or(input.len());
+        match end {
+            0 => Err(expected(what, input)),
+            _ => Ok(input.split_at(end)),
+        }
+    }
+}
+
+pub fn map<'a, T, U>(
+    mut parser: impl Parser<'a, T>,
+    mut f: impl FnMut(T) -> U,
+) -> impl FnMut(&'a str) -> Parsed<'a, U> {
+    move |input| {
+        let (value, rest) = parser.read(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+/// Tries `first`, then `second` if that fails.
+pub fn either<'a, T>(
+    mut first: impl Parser<'a, T>,
+    mut second: impl Parser<'a, T>,
+) -> impl FnMut(&'a str) -> Parsed<'a, T> {
+    move |input| first.read(input).or_else(|_| second.read(input))
+}
+
+/// Items separated by commas, with or without spaces around them, like
+/// `1, -2,3`. There may be no items at all.
+pub fn list<'a, T>(item: impl Parser<'a, T>) -> impl FnMut(&'a str) -> Parsed<'a, Vec<T>> {
+    separated(item, Some(','))
+}
+
+/// Items separated by spaces, like `1 2  3`.
+pub fn spaced<'a, T>(item: impl Parser<'a, T>) -> impl FnMut(&'a str) -> Parsed<'a, Vec<T>> {
+    separated(item, None)
+}
+
+fn separated<'a, T>(
+    mut item: impl Parser<'a, T>,
+    separator: Option<char>,
+) -> impl FnMut(&'a str) -> Parsed<'a, Vec<T>> {
+    move |input| {
+        let mut items = Vec::new();
+        let mut rest = input;
+
+        loop {
+            match item.read(rest.trim_start_matches(' ')) {
+                Ok((value, after)) => {
+                    items.push(value);
+                    rest = after;
+                }
+                // Nothing after a separator is only all right for spaces
+                Err(_) if items.is_empty() || separator.is_none() => return Ok((items, rest)),
+                Err(err) => return Err(err),
+            }
+
+            let after = rest.trim_start_matches(' ');
+            match separator {
+                Some(separator) => match after.strip_prefix(separator) {
+                    Some(after) => rest = after,
+                    None => return Ok((items, rest)),
+                },
+                None if after.len() < rest.len() => {}
+                None => return Ok((items, rest)),
+            }
+        }
+    }
+}
+
+/// A list of items in square brackets, like `[1, [2, 3]]` if the items are
+/// themselves lists or numbers.
+pub fn bracketed<'a, T>(item: impl Parser<'a, T>) -> impl FnMut(&'a str) -> Parsed<'a, Vec<T>> {
+    let mut items = list(item);
+    move |input| {
+        let (items, rest) = ("[", &mut items).read(input)?;
+        let (_, rest) = "]".read(rest.trim_start_matches(' '))?;
+        Ok((items, rest))
+    }
+}
+
+/// A field like `x=-3`.
+pub fn field<'a, T>(
+    key: &'static str,
+    mut value: impl Parser<'a, T>,
+) -> impl FnMut(&'a str) -> Parsed<'a, T> {
+    move |input| {
+        let (_, rest) = (key, "=").read(input)?;
+        value.read(rest)
+    }
+}
+
+/// A value with a label in front of it, like `Register A: 729`.
+pub fn labelled<'a, T>(
+    label: &'static str,
+    mut value: impl Parser<'a, T>,
+) -> impl FnMut(&'a str) -> Parsed<'a, T> {
+    move |input| {
+        let (_, rest) = (label, ":").read(input)?;
+        value.read(rest.trim_start_matches(' '))
+    }
+}
+
+/// Runs `parser` over the whole of some text, which it has to use up.
+pub fn complete<'a, T>(
+    mut parser: impl Parser<'a, T>,
+) -> impl FnMut(&'a str) -> Result<T, ParseError> {
+    move |input| match parser.read(input)? {
+        (value, "") => Ok(value),
+        (_, rest) => Err(expected("the end of the line", rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{blocks, lines};
+
+    #[test]
+    fn int_test() {
+        assert_eq!(int::<i32>("-12, 3"), Ok((-12, ", 3")));
+        assert_eq!(int::<u8>("+7"), Ok((7, "")));
+        assert!(int::<u32>("-1").is_err());
+        assert!(int::<i32>("-").is_err());
+        assert!(int::<i32>("x").is_err());
+    }
+
+    #[test]
+    fn lists_test() {
+        assert_eq!(
+            list(int::<i32>)("1, -2,3 ; 4"),
+            Ok((vec![1, -2, 3], " ; 4"))
+        );
+        assert_eq!(list(int::<i32>)("]"), Ok((vec![], "]")));
+        assert_eq!(spaced(int::<i32>)("1 2  3 x"), Ok((vec![1, 2, 3], " x")));
+
+        let err = complete(list(int::<i32>))("1, 2, x").unwrap_err();
+        assert_eq!(err.to_string(), "expected a number, found \"x\"");
+    }
+
+    #[test]
+    fn fields_test() {
+        let (x, rest) = field("x", int::<i32>)("x=-3, y=4").unwrap();
+        assert_eq!(x, -3);
+        assert_eq!((", ", field("y", int::<i32>)).read(rest), Ok((4, "")));
+
+        assert_eq!(
+            labelled("Register A", int::<u64>)("Register A: 729"),
+            Ok((729, ""))
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Nested {
+        Int(u32),
+        List(Vec<Nested>),
+    }
+
+    fn nested(input: &str) -> Parsed<'_, Nested> {
+        either(map(int, Nested::Int), map(bracketed(nested), Nested::List))(input)
+    }
+
+    #[test]
+    fn bracketed_test() {
+        use Nested::*;
+
+        assert_eq!(
+            complete(nested)("[1,[2, []],3]"),
+            Ok(List(vec![Int(1), List(vec![Int(2), List(vec![])]), Int(3)]))
+        );
+        assert!(complete(nested)("[1,[2]").is_err());
+    }
+
+    #[test]
+    fn placed_test() {
+        let parse = |input| lines(input, complete(("a=", list(int::<u32>))));
+        let err = parse("a=1,2\na=3,y\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected a number, found \"y\""
+        );
+
+        let err = blocks("a=1\n\na=2\nb", complete(("a=", spaced(int::<u32>)))).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(3), Some(4)));
+    }
+}
@@ -0,0 +1,277 @@
+//! Reading puzzle inputs, with errors that say where the input went wrong.
+//!
+//! The helpers here return a [`ParseError`] rather than panicking. An error
+//! made while parsing one line or one block doesn't know where that text
+//! sits in the whole input, so [`lines`] and [`blocks`] place it there, and
+//! work out its column from the text it complains about.
+//!
+//! The [`combinators`] are for lines with more to them than one number.
+
+pub mod combinators;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Input that couldn't be parsed, and where. Lines and columns count from
+/// one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+    /// What was there instead of what was expected, for finding the column.
+    found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: None,
+            column: None,
+            message: message.into(),
+            found: None,
+        }
+    }
+
+    /// An error for finding `found` where `what` should have been.
+    pub fn expected(what: impl fmt::Display, found: &str) -> ParseError {
+        let message = if found.is_empty() {
+            format!("expected {}, found nothing", what)
+        } else {
+            format!("expected {}, found {:?}", what, found)
+        };
+
+        ParseError {
+            found: Some(found.to_string()),
+            ..ParseError::new(message)
+        }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Places an error at a known line and column, unless it's already been
+    /// placed.
+    pub fn at(mut self, line: usize, column: usize) -> ParseError {
+        if self.line.is_none() {
+            self.line = Some(line);
+            self.column = Some(column);
+        }
+        self
+    }
+
+    /// Places an error from parsing `text`, which starts on line `first` of
+    /// the text around it. An error that already has a line is moved down
+    /// by the lines before `text`. Otherwise it goes on the line holding
+    /// what it complains about, if that can be found.
+    pub fn within(mut self, first: usize, text: &str) -> ParseError {
+        if let Some(line) = self.line {
+            self.line = Some(first + line - 1);
+            return self;
+        }
+
+        // What's left over after parsing part of the text is at its end
+        let offset = self
+            .found
+            .as_deref()
+            .filter(|found| !found.is_empty())
+            .and_then(|found| match text.ends_with(found) {
+                true => Some(text.len() - found.len()),
+                false => text.find(found),
+            });
+        match offset {
+            Some(offset) => {
+                let before = &text[..offset];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                self.line = Some(first + before.matches('\n').count());
+                self.column = Some(before[line_start..].chars().count() + 1);
+            }
+            None => self.line = Some(first),
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)
+            }
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            _ => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses each line of `input`, placing any error on its line.
+pub fn lines<'a, T, F>(input: &'a str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.within(i + 1, line)))
+        .collect()
+}
+
+/// Parses an input that's all on one line.
+pub fn line<'a, T, F>(input: &'a str, parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&'a str) -> Result<T, ParseError>,
+{
+    let line = input.trim_end_matches('\n');
+    if let Some(extra) = line.lines().nth(1) {
+        return Err(ParseError::expected("a single line", extra).at(2, 1));
+    }
+
+    parse(line).map_err(|err| err.within(1, line))
+}
+
+/// Parses each of the blocks of `input` that are separated by blank lines,
+/// placing any error on its line.
+pub fn blocks<'a, T, F>(input: &'a str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    let mut first = 1;
+    let mut parsed = Vec::new();
+    for block in input.split("\n\n") {
+        if !block.trim().is_empty() {
+            parsed.push(parse(block).map_err(|err| err.within(first, block))?);
+        }
+        first += block.matches('\n').count() + 2;
+    }
+
+    Ok(parsed)
+}
+
+/// Parses a whole number, or any other type that can be read with `parse`.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::expected("a number", s))
+}
+
+pub fn split_once<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::expected(format!("{:?}", separator), s))
+}
+
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::expected(format!("{:?}", prefix), s))
+}
+
+pub fn strip_suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix)
+        .ok_or_else(|| ParseError::expected(format!("{:?}", suffix), s))
+}
+
+/// Reads each character of `s` with `f`, which gives `None` for any that
+/// isn't `what` it should be. Only that character is shown in the error.
+pub fn each_char<T>(
+    s: &str,
+    what: &str,
+    mut f: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| {
+            f(c).ok_or_else(|| ParseError {
+                found: Some(s[i..].to_string()),
+                ..ParseError::expected(what, &c.to_string())
+            })
+        })
+        .collect()
+}
+
+/// A line of single digits, like `91212129`.
+pub fn digits(s: &str) -> Result<Vec<u32>, ParseError> {
+    each_char(s, "a digit", |c| c.to_digit(10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_test() {
+        let parse = |input| lines(input, |line| number::<u32>(split_once(line, ": ")?.1));
+        assert_eq!(parse("a: 1\nb: 2\n"), Ok(vec![1, 2]));
+
+        let err = parse("a: 1\nb: 2\nc: x3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 4: expected a number, found \"x3\""
+        );
+
+        let err = parse("a: 1\nb 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected \": \", found \"b 2\""
+        );
+
+        let err = lines("a\n", |_| Err::<(), _>(ParseError::new("no good"))).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), None));
+    }
+
+    #[test]
+    fn line_test() {
+        assert_eq!(line("12\n", number::<u32>), Ok(12));
+
+        let err = line("1, x\n", |line| {
+            line.split(", ")
+                .map(number::<u32>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), Some(4)));
+
+        let err = line("1\n2\n", number::<u32>).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(2), Some(1)));
+    }
+
+    #[test]
+    fn each_char_test() {
+        assert_eq!(line("1122\n", digits), Ok(vec![1, 1, 2, 2]));
+
+        let err = lines("12\n3x4\n", digits).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a digit, found \"x\""
+        );
+
+        let steps = |s| each_char(s, "'(' or ')'", |c| [')', '('].iter().position(|&b| b == c));
+        assert_eq!(steps("(()"), Ok(vec![1, 1, 0]));
+    }
+
+    #[test]
+    fn blocks_test() {
+        let parse = |input| blocks(input, |block| lines(block, number::<u32>));
+        assert_eq!(parse("1\n2\n\n3\n"), Ok(vec![vec![1, 2], vec![3]]));
+
+        let err = parse("1\n2\n\n3\n4\n\n5\nsix\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 8, column 1: expected a number, found \"six\""
+        );
+
+        // Errors from the block as a whole are found within it
+        let err = blocks("1\n\n2\nthree", |block| {
+            block.lines().map(number::<u32>).sum::<Result<u32, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(4), Some(1)));
+    }
+}
@@ -0,0 +1,285 @@
+//! The answers that have been accepted, and checking solutions against them.
+
+use crate::{root, time, Day, Error, ParseError};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+const HEADER: &str = "\
+# Accepted answers, one per line as `<year>/day<nn> <part>: <answer>`.
+# Answers drawn over several lines have their line breaks written as `\\n`.
+";
+
+/// Accepted answers, keyed by year, day and part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u16, u8, u8), String>,
+}
+
+impl Answers {
+    /// Where the answers are checked in.
+    pub fn path() -> PathBuf {
+        root().join("answers.txt")
+    }
+
+    /// Reads the checked in answers. It's fine for there not to be any yet.
+    pub fn load() -> Result<Answers, Error> {
+        let path = Answers::path();
+        match fs::read_to_string(&path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(Error::Read(path, err)),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let path = Answers::path();
+        fs::write(&path, self.to_string()).map_err(|err| Error::Write(path, err))
+    }
+
+    pub fn get(&self, day: &Day, part: u8) -> Option<&str> {
+        self.answers
+            .get(&(day.year, day.day, part))
+            .map(|answer| answer.as_str())
+    }
+
+    /// Records an answer, returning the one it replaced.
+    pub fn insert(&mut self, day: &Day, part: u8, answer: String) -> Option<String> {
+        self.answers.insert((day.year, day.day, part), answer)
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+
+        for (i, line) in s.lines().enumerate() {
+            // Drawn answers can end with spaces, so only blank lines are trimmed
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let bad = |reason: &str| Error::Answers(i + 1, reason.to_string());
+            let (key, answer) = line
+                .split_once(": ")
+                .ok_or_else(|| bad("expected `<year>/day<nn> <part>: <answer>`"))?;
+            let (name, part) = key.split_once(' ').ok_or_else(|| bad("missing part"))?;
+            let (year, day) = name
+                .split_once("/day")
+                .ok_or_else(|| bad("expected `<year>/day<nn>`"))?;
+
+            let year = year.parse().map_err(|_| bad("bad year"))?;
+            let day = day.parse().map_err(|_| bad("bad day"))?;
+            let part = part.parse().map_err(|_| bad("bad part"))?;
+            if answers
+                .answers
+                .insert((year, day, part), unescape(answer))
+                .is_some()
+            {
+                return Err(bad("answer given twice"));
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(HEADER)?;
+        for ((year, day, part), answer) in &self.answers {
+            writeln!(f, "{}/day{:02} {}: {}", year, day, part, escape(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+/// How one part compared with its accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The answer didn't match the accepted one, which is given.
+    Wrong(String),
+    /// There's no accepted answer to compare against.
+    Missing,
+    /// The solution panicked, with the given message.
+    Panicked(String),
+    /// The input couldn't be parsed.
+    Unparsed(ParseError),
+}
+
+#[derive(Clone, Debug)]
+pub struct Check {
+    pub part: u8,
+    /// The answer given, unless the solution panicked or couldn't parse the
+    /// input.
+    pub answer: Option<String>,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Solves every part of a day and compares the answers with the accepted
+/// ones. Panics are caught and reported rather than passed on, so the
+/// caller may want to quieten the panic hook first.
+pub fn check(day: &Day, input: &str, answers: &Answers) -> Vec<Check> {
+    day.parts()
+        .map(|(part, solve)| {
+            let (result, elapsed) = time(|| panic::catch_unwind(AssertUnwindSafe(|| solve(input))));
+
+            let result = result.map(|answer| answer.map(|answer| answer.to_string()));
+            let outcome = match (&result, answers.get(day, part)) {
+                (Err(payload), _) => Outcome::Panicked(panic_message(payload.as_ref())),
+                (Ok(Err(err)), _) => Outcome::Unparsed(err.clone()),
+                (Ok(Ok(_)), None) => Outcome::Missing,
+                (Ok(Ok(answer)), Some(expected)) if answer == expected => Outcome::Correct,
+                (Ok(Ok(_)), Some(expected)) => Outcome::Wrong(expected.to_string()),
+            };
+
+            Check {
+                part,
+                answer: result.ok().and_then(Result::ok),
+                outcome,
+                elapsed,
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    const DAY: Day = Day {
+        year: 2021,
+        day: 13,
+        parse: |_| Ok(()),
+        solve_parsed: |_, _| Ok(()),
+        part_one: |input| Ok(input.len().into()),
+        part_two: Some(|input| Ok(Answer::Drawing(input.replace(',', "\n")))),
+    };
+
+    const BROKEN: Day = Day {
+        year: 2021,
+        day: 14,
+        parse: |_| Ok(()),
+        solve_parsed: |_, _| Ok(()),
+        part_one: |input| Ok(aoc_parse::number::<u8>(input)?.into()),
+        part_two: Some(|_| panic!("not done yet")),
+    };
+
+    #[test]
+    fn parse_test() {
+        let answers: Answers = "# comment\n\n2021/day13 1: 17\n2021/day13 2: #.\\n.#\\\\\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers.get(&DAY, 1), Some("17"));
+        assert_eq!(answers.get(&DAY, 2), Some("#.\n.#\\"));
+        assert_eq!(answers.get(&BROKEN, 1), None);
+
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+        assert!(answers.to_string().ends_with("2021/day13 2: #.\\n.#\\\\\n"));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = "2021/day13 1: 17\n2021/day13 x: 3\n"
+            .parse::<Answers>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "answers line 2: bad part");
+
+        let err = "2021/day13 1: 17\n2021/day13 1: 18\n"
+            .parse::<Answers>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "answers line 2: answer given twice");
+    }
+
+    #[test]
+    fn check_test() {
+        let mut answers = Answers::default();
+        answers.insert(&DAY, 1, "3".to_string());
+        answers.insert(&DAY, 2, "a\nb".to_string());
+        answers.insert(&BROKEN, 1, "7".to_string());
+
+        let checks = check(&DAY, "a,b", &answers);
+        assert_eq!(checks[0].outcome, Outcome::Correct);
+        assert_eq!(checks[1].outcome, Outcome::Correct);
+
+        let checks = check(&DAY, "a,c", &answers);
+        assert_eq!(checks[1].outcome, Outcome::Wrong("a\nb".to_string()));
+        assert_eq!(checks[1].answer.as_deref(), Some("a\nc"));
+
+        let checks = check(&BROKEN, "8", &answers);
+        assert_eq!(checks[0].outcome, Outcome::Wrong("7".to_string()));
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Panicked("not done yet".to_string())
+        );
+        assert_eq!(checks[1].answer, None);
+
+        answers.insert(&BROKEN, 1, "8".to_string());
+        let checks = check(&BROKEN, "8", &answers);
+        assert_eq!(checks[0].outcome, Outcome::Correct);
+
+        let checks = check(&BROKEN, "eight", &answers);
+        assert_eq!(
+            checks[0].outcome,
+            Outcome::Unparsed(ParseError::expected("a number", "eight"))
+        );
+        assert_eq!(checks[0].answer, None);
+
+        let checks = check(&DAY, "a,b", &Answers::default());
+        assert_eq!(checks[0].outcome, Outcome::Missing);
+    }
+}
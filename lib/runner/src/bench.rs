@@ -0,0 +1,499 @@
+//! Benchmarking the solutions, with a history of the results so each run can
+//! be compared with the one before.
+
+use crate::answers::panic_message;
+use crate::{root, Day, Error};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::hint::black_box;
+use std::io::{ErrorKind, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Every part is timed at least this many times, however slow it is.
+const MIN_RUNS: usize = 5;
+
+/// How much slower than last time a step has to be to count as a regression,
+/// as a fraction and as an absolute amount, so that the fastest steps don't
+/// get flagged for noise.
+const REGRESSION_RATIO: f64 = 1.1;
+const REGRESSION_MIN: Duration = Duration::from_micros(50);
+
+const HISTORY_HEADER: &str = "timestamp,year,day,step,runs,median_ns,p95_ns";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Settings {
+    /// How many untimed runs to make first.
+    pub warmup: usize,
+    /// The most timed runs to make.
+    pub runs: usize,
+    /// Roughly how long to spend on each step, after which it stops short of
+    /// `runs` (but never with fewer than five).
+    pub budget: Duration,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            warmup: 3,
+            runs: 100,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// A summary of the timings of one step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+
+        // Nearest rank, so the p95 of a handful of runs is the slowest
+        let rank = |p: usize| (samples.len() * p).div_ceil(100).max(1) - 1;
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            runs: samples.len(),
+            median,
+            p95: samples[rank(95)],
+        }
+    }
+}
+
+/// Times `f`, after warming up with a few untimed runs.
+pub fn measure<T, F: FnMut() -> T>(settings: &Settings, mut f: F) -> Stats {
+    let start = Instant::now();
+    for _ in 0..settings.warmup {
+        black_box(f());
+        if start.elapsed() > settings.budget / 10 {
+            break;
+        }
+    }
+
+    let start = Instant::now();
+    let mut samples = Vec::new();
+    while samples.len() < settings.runs.max(MIN_RUNS)
+        && (samples.len() < MIN_RUNS || start.elapsed() < settings.budget)
+    {
+        let now = Instant::now();
+        black_box(f());
+        samples.push(now.elapsed());
+    }
+
+    Stats::from_samples(samples)
+}
+
+/// What was timed: parsing, or one of the parts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "{}", part),
+        }
+    }
+}
+
+/// One step of one day, from one run of the benchmarks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// When the benchmarks were run, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+impl Record {
+    pub fn name(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    fn parse(line: &str) -> Option<Record> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [timestamp, year, day, step, runs, median, p95] = fields.as_slice() else {
+            return None;
+        };
+
+        let step = match *step {
+            "parse" => Step::Parse,
+            part => Step::Part(part.parse().ok()?),
+        };
+
+        Some(Record {
+            timestamp: timestamp.parse().ok()?,
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            step,
+            stats: Stats {
+                runs: runs.parse().ok()?,
+                median: Duration::from_nanos(median.parse().ok()?),
+                p95: Duration::from_nanos(p95.parse().ok()?),
+            },
+        })
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.step,
+            self.stats.runs,
+            self.stats.median.as_nanos(),
+            self.stats.p95.as_nanos()
+        )
+    }
+}
+
+/// Times parsing, and each of the day's parts on its own from input that's
+/// already been parsed, once the input is known to parse.
+///
+/// A panic is caught and given back as an error saying which step it was
+/// in, so the caller may want to quieten the panic hook first.
+pub fn bench(
+    day: &Day,
+    input: &str,
+    settings: &Settings,
+    timestamp: u64,
+) -> Result<Vec<Record>, Error> {
+    let mut step = Step::Parse;
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        bench_steps(day, input, settings, timestamp, &mut step)
+    }))
+    .unwrap_or_else(|payload| {
+        let message = panic_message(payload.as_ref());
+        Err(Error::Panicked(day.year, day.day, step, message))
+    })
+}
+
+/// Does the work of `bench`, keeping `step` up to date with what's being
+/// timed.
+fn bench_steps(
+    day: &Day,
+    input: &str,
+    settings: &Settings,
+    timestamp: u64,
+    step: &mut Step,
+) -> Result<Vec<Record>, Error> {
+    (day.parse)(input).map_err(|err| day.parse_error(err))?;
+
+    let record = |step, stats| Record {
+        timestamp,
+        year: day.year,
+        day: day.day,
+        step,
+        stats,
+    };
+
+    let parse = record(Step::Parse, measure(settings, || (day.parse)(input)));
+    let mut records = vec![parse];
+    (day.solve_parsed)(input, &mut |part, solve| {
+        if day.part(part).is_some() {
+            *step = Step::Part(part);
+            records.push(record(*step, measure(settings, solve)));
+        }
+    })
+    .map_err(|err| day.parse_error(err))?;
+
+    Ok(records)
+}
+
+/// How much slower `current` is than `previous`, if it's enough to be a
+/// regression.
+pub fn regression(previous: &Stats, current: &Stats) -> Option<f64> {
+    let ratio = current.median.as_nanos() as f64 / previous.median.as_nanos().max(1) as f64;
+    let slower = current.median.saturating_sub(previous.median);
+    (ratio > REGRESSION_RATIO && slower > REGRESSION_MIN).then_some(ratio)
+}
+
+/// The days of each year that took longest across all their parts, slowest
+/// first, with at most `count` a year.
+pub fn slowest(records: &[Record], count: usize) -> BTreeMap<u16, Vec<(String, Duration)>> {
+    let mut totals: BTreeMap<(u16, u8), Duration> = BTreeMap::new();
+    for record in records.iter().filter(|r| r.step != Step::Parse) {
+        *totals.entry((record.year, record.day)).or_default() += record.stats.median;
+    }
+
+    let mut years: BTreeMap<u16, Vec<(String, Duration)>> = BTreeMap::new();
+    for ((year, day), total) in totals {
+        let name = format!("{}/day{:02}", year, day);
+        years.entry(year).or_default().push((name, total));
+    }
+    for days in years.values_mut() {
+        days.sort_by_key(|&(_, total)| Reverse(total));
+        days.truncate(count);
+    }
+
+    years
+}
+
+/// The results of every benchmark run so far, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Where the history is kept. It's only of use on the machine it was
+    /// made on, so it isn't checked in.
+    pub fn path() -> PathBuf {
+        root().join("bench-history.csv")
+    }
+
+    pub fn load() -> Result<History, Error> {
+        let path = History::path();
+        match fs::read_to_string(&path) {
+            Ok(contents) => Ok(History::parse(&contents)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(Error::Read(path, err)),
+        }
+    }
+
+    /// Reads the history, skipping the header and anything unreadable.
+    pub fn parse(s: &str) -> History {
+        History {
+            records: s.lines().filter_map(Record::parse).collect(),
+        }
+    }
+
+    /// The most recent result for the same step, before `timestamp`.
+    pub fn previous(&self, record: &Record) -> Option<&Record> {
+        self.records.iter().rev().find(|r| {
+            (r.year, r.day, r.step) == (record.year, record.day, record.step)
+                && r.timestamp < record.timestamp
+        })
+    }
+
+    /// Adds the records to the history and appends them to its file.
+    pub fn append(&mut self, records: &[Record]) -> Result<(), Error> {
+        let path = History::path();
+        let write = || -> std::io::Result<()> {
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            if file.metadata()?.len() == 0 {
+                writeln!(file, "{}", HISTORY_HEADER)?;
+            }
+            for record in records {
+                writeln!(file, "{}", record)?;
+            }
+            Ok(())
+        };
+
+        write().map_err(|err| Error::Write(path.clone(), err))?;
+        self.records.extend_from_slice(records);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError, Solution};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    fn record(timestamp: u64, day: u8, step: Step, median_ms: u64) -> Record {
+        let median = Duration::from_millis(median_ms);
+        Record {
+            timestamp,
+            year: 2024,
+            day,
+            step,
+            stats: Stats {
+                runs: 10,
+                median,
+                p95: median * 2,
+            },
+        }
+    }
+
+    #[test]
+    fn stats_test() {
+        let stats = Stats::from_samples(millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+
+        let stats = Stats::from_samples(millis(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2_500));
+
+        let stats = Stats::from_samples((1..=100).map(Duration::from_millis).collect());
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn measure_test() {
+        let settings = Settings {
+            warmup: 2,
+            runs: 7,
+            budget: Duration::from_secs(10),
+        };
+        let mut calls = 0;
+        let stats = measure(&settings, || calls += 1);
+        assert_eq!(stats.runs, 7);
+        assert_eq!(calls, 9);
+
+        // Slow steps still get timed a few times
+        let settings = Settings {
+            budget: Duration::ZERO,
+            ..settings
+        };
+        assert_eq!(measure(&settings, || ()).runs, MIN_RUNS);
+    }
+
+    /// Counts how many times it's parsed.
+    struct Counted;
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    impl Solution for Counted {
+        type Input<'a> = usize;
+
+        fn parse(input: &str) -> Result<usize, ParseError> {
+            PARSES.fetch_add(1, Ordering::Relaxed);
+            Ok(input.len())
+        }
+
+        fn part_one(len: &usize) -> Answer {
+            (*len).into()
+        }
+
+        fn part_two(_: &usize) -> Answer {
+            panic!("not done yet")
+        }
+    }
+
+    #[test]
+    fn bench_test() {
+        let settings = Settings {
+            warmup: 2,
+            runs: 7,
+            budget: Duration::from_secs(10),
+        };
+        let day = Day::one_part::<Counted>(2024, 6);
+        let records = bench(&day, "abc", &settings, 100).unwrap();
+
+        let steps: Vec<Step> = records.iter().map(|r| r.step).collect();
+        assert_eq!(steps, [Step::Parse, Step::Part(1)]);
+        assert!(records.iter().all(|r| r.stats.runs == 7));
+
+        // Once to check it parses, nine times timing it, and once for the
+        // parts to share
+        assert_eq!(PARSES.load(Ordering::Relaxed), 11);
+    }
+
+    /// Gets as far as its second part.
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        type Input<'a> = ();
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_: &()) -> Answer {
+            1.into()
+        }
+
+        fn part_two(_: &()) -> Answer {
+            panic!("not done yet")
+        }
+    }
+
+    #[test]
+    fn bench_panic_test() {
+        let settings = Settings {
+            warmup: 0,
+            runs: 1,
+            budget: Duration::ZERO,
+        };
+        let day = Day::new::<Unfinished>(2024, 7);
+        let err = bench(&day, "", &settings, 100).unwrap_err();
+        assert!(matches!(err, Error::Panicked(2024, 7, Step::Part(2), _)));
+        assert_eq!(err.to_string(), "2024/day07 part 2: panicked: not done yet");
+    }
+
+    #[test]
+    fn history_test() {
+        let records = [
+            record(100, 6, Step::Parse, 1),
+            record(100, 6, Step::Part(2), 800),
+            record(200, 6, Step::Part(2), 900),
+        ];
+        let csv: String = records.iter().map(|r| format!("{}\n", r)).collect();
+        let history = History::parse(&format!("{}\n{}", HISTORY_HEADER, csv));
+        assert_eq!(history.records, records);
+
+        let current = record(300, 6, Step::Part(2), 1000);
+        assert_eq!(history.previous(&current), Some(&records[2]));
+        assert_eq!(history.previous(&records[2]), Some(&records[1]));
+        assert_eq!(history.previous(&records[1]), None);
+        assert_eq!(history.previous(&record(300, 7, Step::Part(2), 1)), None);
+    }
+
+    #[test]
+    fn regression_test() {
+        let stats = |ms| record(0, 1, Step::Part(1), ms).stats;
+        assert_eq!(regression(&stats(100), &stats(105)), None);
+        assert_eq!(regression(&stats(100), &stats(150)), Some(1.5));
+        assert_eq!(regression(&stats(150), &stats(100)), None);
+
+        // Too small a difference to mean anything, however big the ratio
+        let tiny = |us| Stats {
+            runs: 10,
+            median: Duration::from_micros(us),
+            p95: Duration::from_micros(us),
+        };
+        assert_eq!(regression(&tiny(10), &tiny(40)), None);
+    }
+
+    #[test]
+    fn slowest_test() {
+        let records = [
+            record(1, 6, Step::Parse, 500),
+            record(1, 6, Step::Part(1), 10),
+            record(1, 6, Step::Part(2), 800),
+            record(1, 12, Step::Part(1), 300),
+            record(1, 12, Step::Part(2), 600),
+            record(1, 1, Step::Part(1), 1),
+        ];
+
+        let slowest = slowest(&records, 2);
+        assert_eq!(
+            slowest[&2024],
+            vec![
+                ("2024/day12".to_string(), Duration::from_millis(900)),
+                ("2024/day06".to_string(), Duration::from_millis(810)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! The puzzle inputs, kept in one place outside of git.
+//!
+//! Inputs aren't meant to be shared, so they live under `inputs/` at the
+//! root, which is ignored, as `inputs/<year>/day<nn>.txt`. Older days kept
+//! theirs in their own directories under all sorts of names, and those can
+//! be imported into the store.
+
+use crate::{root, Error};
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The names inputs were saved under in the days' own directories, most
+/// likely first.
+pub const STRAY_NAMES: [&str; 4] = ["input.txt", "input", "captcha.txt", "samples"];
+
+/// The store of inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+/// What importing a day's stray input did.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Import {
+    /// The input was copied into the store from the given file.
+    Imported(PathBuf),
+    /// The store already had the same input.
+    AlreadyStored,
+    /// The store already had a different input, so the given file was left
+    /// alone.
+    Conflict(PathBuf),
+    /// There was nothing to import.
+    NotFound,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Inputs {
+        Inputs { dir: dir.into() }
+    }
+
+    /// The store under the root, or wherever `AOC_INPUTS` points.
+    pub fn open() -> Inputs {
+        match env::var_os("AOC_INPUTS") {
+            Some(dir) => Inputs::new(dir),
+            None => Inputs::new(root().join("inputs")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Where a day's input is kept, whether or not it's there yet.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn contains(&self, year: u16, day: u8) -> bool {
+        fs::metadata(self.path(year, day)).is_ok_and(|m| m.is_file() && m.len() > 0)
+    }
+
+    /// Reads a day's input. An empty file counts as missing, as no puzzle
+    /// has an empty input.
+    pub fn read(&self, year: u16, day: u8) -> Result<String, Error> {
+        let path = self.path(year, day);
+        let missing = |path| Error::MissingInput(path, stray(&day_dir(year, day)));
+        match fs::read_to_string(&path) {
+            Ok(input) if input.is_empty() => Err(missing(path)),
+            Ok(input) => Ok(input),
+            Err(err) if err.kind() == ErrorKind::NotFound => Err(missing(path)),
+            Err(err) => Err(Error::Read(path, err)),
+        }
+    }
+
+    /// Stores a day's input, replacing anything that was there.
+    pub fn write(&self, year: u16, day: u8, input: &str) -> Result<PathBuf, Error> {
+        let path = self.path(year, day);
+        let parent = path.parent().unwrap();
+        fs::create_dir_all(parent).map_err(|err| Error::Write(parent.to_path_buf(), err))?;
+        fs::write(&path, input).map_err(|err| Error::Write(path.clone(), err))?;
+        Ok(path)
+    }
+
+    /// Copies a stray input from `dir` into the store, unless the store
+    /// already has one for the day.
+    pub fn import(&self, year: u16, day: u8, dir: &Path) -> Result<Import, Error> {
+        let Some(stray) = stray(dir) else {
+            return Ok(Import::NotFound);
+        };
+        let input = fs::read_to_string(&stray).map_err(|err| Error::Read(stray.clone(), err))?;
+
+        if self.contains(year, day) {
+            if self.read(year, day)? == input {
+                return Ok(Import::AlreadyStored);
+            }
+            return Ok(Import::Conflict(stray));
+        }
+
+        self.write(year, day, &input)?;
+        Ok(Import::Imported(stray))
+    }
+}
+
+/// Where a day's code lives, e.g. `2023/day17`.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    root().join(format!("{}/day{:02}", year, day))
+}
+
+/// Finds an input left in a day's directory, passing over empty files.
+pub fn stray(dir: &Path) -> Option<PathBuf> {
+    STRAY_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() > 0))
+}
+
+/// Every `<year>/day<nn>` directory under `dir`, in order. Under the root,
+/// that's every day whether or not it has a solution yet.
+pub fn day_dirs(dir: &Path) -> Vec<(u16, u8, PathBuf)> {
+    let mut days = Vec::new();
+    let entries = |dir: &Path| fs::read_dir(dir).into_iter().flatten().flatten();
+    for year in entries(dir) {
+        let Some(y) = year.file_name().to_str().and_then(|y| y.parse().ok()) else {
+            continue;
+        };
+        for day in entries(&year.path()) {
+            let name = day.file_name();
+            let Some(d) = name
+                .to_str()
+                .and_then(|d| d.strip_prefix("day")?.parse().ok())
+            else {
+                continue;
+            };
+            if day.path().is_dir() {
+                days.push((y, d, day.path()));
+            }
+        }
+    }
+
+    days.sort();
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    /// An empty directory to play in, unique to the test.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn read_write_test() {
+        let inputs = Inputs::new(scratch("read-write"));
+        assert!(inputs.path(2023, 7).ends_with("2023/day07.txt"));
+        assert!(!inputs.contains(2023, 7));
+
+        let err = inputs.read(2023, 7).unwrap_err();
+        assert!(matches!(&err, Error::MissingInput(path, _) if *path == inputs.path(2023, 7)));
+        assert!(err.to_string().contains("2023/day07.txt"));
+
+        inputs.write(2023, 7, "32T3K 765\n").unwrap();
+        assert!(inputs.contains(2023, 7));
+        assert_eq!(inputs.read(2023, 7).unwrap(), "32T3K 765\n");
+
+        inputs.write(2023, 7, "").unwrap();
+        assert!(matches!(inputs.read(2023, 7), Err(Error::MissingInput(..))));
+
+        fs::remove_dir_all(inputs.dir()).unwrap();
+    }
+
+    #[test]
+    fn import_test() {
+        let dir = scratch("import");
+        let inputs = Inputs::new(dir.join("inputs"));
+        let day = dir.join("day01");
+        fs::create_dir_all(&day).unwrap();
+
+        assert_eq!(inputs.import(2017, 1, &day).unwrap(), Import::NotFound);
+
+        // Empty placeholders are passed over for the real thing
+        fs::write(day.join("input.txt"), "").unwrap();
+        fs::write(day.join("captcha.txt"), "1122\n").unwrap();
+        assert_eq!(stray(&day), Some(day.join("captcha.txt")));
+
+        assert_eq!(
+            inputs.import(2017, 1, &day).unwrap(),
+            Import::Imported(day.join("captcha.txt"))
+        );
+        assert_eq!(inputs.read(2017, 1).unwrap(), "1122\n");
+        assert_eq!(inputs.import(2017, 1, &day).unwrap(), Import::AlreadyStored);
+
+        fs::write(day.join("input.txt"), "1111\n").unwrap();
+        assert_eq!(
+            inputs.import(2017, 1, &day).unwrap(),
+            Import::Conflict(day.join("input.txt"))
+        );
+        assert_eq!(inputs.read(2017, 1).unwrap(), "1122\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn day_dirs_test() {
+        let days = day_dirs(&root());
+        assert!(days.contains(&(2023, 17, root().join("2023/day17"))));
+        assert!(days.is_sorted());
+        assert!(days.iter().all(|&(year, day, _)| year >= 2015 && day <= 25));
+    }
+}
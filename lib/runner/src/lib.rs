@@ -0,0 +1,314 @@
+//! Running puzzle solutions.
+//!
+//! Every day implements [`Solution`] and exports a [`Day`] made from it.
+//! Its own binary hands that to [`run`], and the `aoc` binary keeps a table
+//! of all of them so any day can be run from one place.
+//!
+//! The answers that were accepted are kept in [`Answers`], so the solutions
+//! can be checked against them after any change. Inputs are read when a day
+//! is run, from the store in [`Inputs`].
+
+mod answers;
+pub mod bench;
+mod inputs;
+mod solution;
+
+pub use answers::{check, Answers, Check, Outcome};
+pub use aoc_parse::ParseError;
+pub use inputs::{day_dirs, stray, Import, Inputs, STRAY_NAMES};
+pub use solution::{Answer, Solution};
+
+use std::fmt;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fs, io, process};
+
+/// Solves one part of a puzzle, given the whole input.
+pub type Part = fn(&str) -> Result<Answer, ParseError>;
+
+/// Given a part's number and something that solves it from input that's
+/// already been parsed.
+pub type Solver<'a> = &'a mut dyn FnMut(u8, &mut dyn FnMut() -> Answer);
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Parses the input without solving anything, so the benchmarks can
+    /// time it separately.
+    pub parse: fn(&str) -> Result<(), ParseError>,
+    /// Parses the input once, then hands each part to the solver, so the
+    /// benchmarks can time the parts without the parsing.
+    pub solve_parsed: fn(&str, Solver) -> Result<(), ParseError>,
+    pub part_one: Part,
+    /// Christmas Day only has the one part.
+    pub part_two: Option<Part>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Day {
+        Day {
+            year,
+            day,
+            parse: parse::<S>,
+            solve_parsed: solve_parsed::<S>,
+            part_one: part_one::<S>,
+            part_two: Some(part_two::<S>),
+        }
+    }
+
+    /// A day with only the one part: Christmas Day, or a day whose second
+    /// part hasn't been solved yet.
+    pub const fn one_part<S: Solution>(year: u16, day: u8) -> Day {
+        Day {
+            part_two: None,
+            ..Day::new::<S>(year, day)
+        }
+    }
+
+    /// Where the day lives in the repository, e.g. `2023/day17`.
+    pub fn name(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        inputs::day_dir(self.year, self.day)
+    }
+
+    pub fn part(&self, part: u8) -> Option<Part> {
+        match part {
+            1 => Some(self.part_one),
+            2 => self.part_two,
+            _ => None,
+        }
+    }
+
+    /// The parts that exist, numbered from one.
+    pub fn parts(&self) -> impl Iterator<Item = (u8, Part)> + '_ {
+        (1..=2).filter_map(|part| Some((part, self.part(part)?)))
+    }
+
+    /// Where the day's input is kept.
+    pub fn input_path(&self) -> PathBuf {
+        Inputs::open().path(self.year, self.day)
+    }
+
+    /// Says which day's input `err` was found in.
+    pub fn parse_error(&self, err: ParseError) -> Error {
+        Error::Parse(self.year, self.day, err)
+    }
+
+    /// Reads the input from `path`, or from the store if there isn't one.
+    pub fn read_input(&self, path: Option<&Path>) -> Result<String, Error> {
+        match path {
+            Some(path) => fs::read_to_string(path).map_err(|err| Error::Read(path.into(), err)),
+            None => Inputs::open().read(self.year, self.day),
+        }
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(), ParseError> {
+    black_box(S::parse(input)?);
+    Ok(())
+}
+
+fn solve_parsed<S: Solution>(input: &str, solver: Solver) -> Result<(), ParseError> {
+    let parsed = S::parse(input)?;
+    solver(1, &mut || S::part_one(&parsed));
+    solver(2, &mut || S::part_two(&parsed));
+    Ok(())
+}
+
+fn part_one<S: Solution>(input: &str) -> Result<Answer, ParseError> {
+    Ok(S::part_one(&S::parse(input)?))
+}
+
+fn part_two<S: Solution>(input: &str) -> Result<Answer, ParseError> {
+    Ok(S::part_two(&S::parse(input)?))
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day({})", self.name())
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// There's no input where it was expected, though there may be one
+    /// left in the day's directory that could be imported.
+    MissingInput(PathBuf, Option<PathBuf>),
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    /// A line of the answers file couldn't be understood.
+    Answers(usize, String),
+    /// A day's input couldn't be parsed.
+    Parse(u16, u8, ParseError),
+    /// A day panicked while it was being benchmarked, at the given step.
+    Panicked(u16, u8, bench::Step, String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput(path, None) => write!(f, "no input found at {}", path.display()),
+            Error::MissingInput(path, Some(stray)) => write!(
+                f,
+                "no input found at {}, but there's one at {} that `aoc import` will copy there",
+                path.display(),
+                stray.display()
+            ),
+            Error::Read(path, err) => write!(f, "couldn't read {}: {}", path.display(), err),
+            Error::Write(path, err) => write!(f, "couldn't write {}: {}", path.display(), err),
+            Error::Answers(line, reason) => write!(f, "answers line {}: {}", line, reason),
+            Error::Parse(year, day, err) => write!(f, "{} day {}, {}", year, day, err),
+            Error::Panicked(year, day, step, message) => {
+                let step = match step {
+                    bench::Step::Parse => "parsing".to_string(),
+                    bench::Step::Part(part) => format!("part {}", part),
+                };
+                write!(f, "{}/day{:02} {}: panicked: {}", year, day, step, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The root of the workspace, which every day's directory is relative to.
+pub fn root() -> PathBuf {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest.ancestors().nth(2).unwrap().to_path_buf()
+}
+
+/// Calls `f`, timing how long it takes.
+pub fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let now = Instant::now();
+    let value = f();
+    (value, now.elapsed())
+}
+
+/// Formats a duration with a unit to suit its size, e.g. `850µs` or `1.25s`.
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1e3)
+    } else {
+        format!("{:.2}s", micros as f64 / 1e6)
+    }
+}
+
+/// Solves one part and prints the answer with how long it took.
+pub fn print_part(day: &Day, part: u8, solve: Part, input: &str) -> Result<Duration, Error> {
+    let (answer, elapsed) = time(|| solve(input));
+    let answer = answer.map_err(|err| day.parse_error(err))?;
+    let name = if part == 1 { "one" } else { "two" };
+
+    // Some answers are drawn over several lines, so start them on their own
+    if matches!(answer, Answer::Drawing(_)) || answer.to_string().contains('\n') {
+        println!("Part {}: ({})\n{}", name, format_duration(elapsed), answer);
+    } else {
+        println!("Part {}: {} ({})", name, answer, format_duration(elapsed));
+    }
+
+    Ok(elapsed)
+}
+
+/// Reads a day's input from the store, or exits saying where it should be.
+/// This is for the days that don't have a [`Day`] yet.
+pub fn input(year: u16, day: u8) -> String {
+    Inputs::open().read(year, day).unwrap_or_else(|err| {
+        eprintln!("{}/day{:02}: {}", year, day, err);
+        process::exit(1);
+    })
+}
+
+/// The `main` of every day's own binary. The input comes from the path
+/// given with `--input`, or the store.
+pub fn run(day: &Day) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let path = match args.as_slice() {
+        [] => None,
+        [flag, path] if flag == "--input" => Some(Path::new(path)),
+        _ => {
+            eprintln!("usage: {} [--input <path>]", day.name());
+            process::exit(2);
+        }
+    };
+
+    let input = day.read_input(path).unwrap_or_else(|err| {
+        eprintln!("{}: {}", day.name(), err);
+        process::exit(1);
+    });
+
+    for (part, solve) in day.parts() {
+        if let Err(err) = print_part(day, part, solve, &input) {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+            aoc_parse::lines(input, |line| match line {
+                "" => Err(ParseError::new("empty line")),
+                line => Ok(line),
+            })
+        }
+
+        fn part_one(lines: &Vec<&str>) -> Answer {
+            lines.len().into()
+        }
+
+        fn part_two(lines: &Vec<&str>) -> Answer {
+            lines.concat().into()
+        }
+    }
+
+    const DAY: Day = Day::new::<Lengths>(2023, 7);
+
+    #[test]
+    fn day_test() {
+        assert_eq!(DAY.name(), "2023/day07");
+        assert!(DAY.dir().ends_with("2023/day07"));
+        assert_eq!(DAY.parts().count(), 2);
+        assert_eq!(
+            DAY.part(1).map(|part| part("ab\nc")),
+            Some(Ok(Answer::Int(2)))
+        );
+        assert_eq!(
+            DAY.part(2).map(|part| part("ab\nc")),
+            Some(Ok("abc".into()))
+        );
+        assert!(DAY.part(3).is_none());
+
+        let err = (DAY.parse)("ab\n\nc").unwrap_err();
+        assert_eq!(
+            DAY.parse_error(err).to_string(),
+            "2023 day 7, line 2: empty line"
+        );
+
+        let day = Day::one_part::<Lengths>(2019, 25);
+        assert_eq!(day.parts().count(), 1);
+        assert!(day.part(2).is_none());
+    }
+
+    #[test]
+    fn format_duration_test() {
+        assert_eq!(format_duration(Duration::from_micros(850)), "850µs");
+        assert_eq!(format_duration(Duration::from_micros(12_346)), "12.35ms");
+        assert_eq!(format_duration(Duration::from_millis(1_250)), "1.25s");
+    }
+}
@@ -0,0 +1,95 @@
+//! The shape every day's solution takes, and the answers it gives.
+
+use aoc_parse::ParseError;
+use std::fmt;
+
+/// A puzzle's solution: how to parse its input, and how to solve each part
+/// from what was parsed. Even days that work on the text itself check it
+/// here, and hand back its lines or whatever pieces the parts need. Input
+/// that can't be parsed is an error rather than a panic, so it can be
+/// reported with where it went wrong.
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Answer;
+
+    fn part_two(input: &Self::Input<'_>) -> Answer;
+}
+
+/// An answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Letters drawn in `#` and `.`, one row to a line, to be read by eye.
+    /// No day gives one now that `aoc-ocr` reads the drawings, but it's kept
+    /// on purpose for any letters drawn in a font it doesn't know.
+    Drawing(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Drawing(s) => f.write_str(s),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n as i128)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, n: &$t) -> bool {
+                    matches!(self, Answer::Int(m) if *m == *n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, s: &&str) -> bool {
+        matches!(self, Answer::Text(t) | Answer::Drawing(t) if t == s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_test() {
+        assert_eq!(Answer::from(42u8).to_string(), "42");
+        assert_eq!(Answer::from(-7i64), Answer::Int(-7));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("CFLELOYFCS"), "CFLELOYFCS");
+        assert_eq!(Answer::from(3usize), 3usize);
+        assert_ne!(Answer::from("3"), Answer::from(3));
+
+        let drawing = Answer::Drawing("#..#\n####\n#..#".to_string());
+        assert_eq!(drawing.to_string(), "#..#\n####\n#..#");
+    }
+}
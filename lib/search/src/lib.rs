@@ -0,0 +1,339 @@
+//! Shortest path searches over any kind of state.
+//!
+//! A search starts from one state and asks a successor function where it can
+//! go next, so the state can be a grid position, a position and a heading, or
+//! anything else that can be hashed. Each search stops once it has settled
+//! every goal state at the lowest cost, and the [`Paths`] it returns remember
+//! every predecessor that lies on an optimal path, so all of the best routes
+//! can be recovered and not just one.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The result of a search.
+#[derive(Clone, Debug)]
+pub struct Paths<N, C> {
+    start: N,
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The cost of the cheapest way to `node`. This is exact for every node
+    /// no further away than the goals; anything beyond may have been reached
+    /// before the search stopped without being settled.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every node that was reached, with its cost.
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    /// The nodes from which `node` is reached at its lowest cost.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], |p| p.as_slice())
+    }
+
+    /// The goal nodes reached at the lowest cost, in the order they were found.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The cost of reaching a goal, if one could be reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.cost(goal))
+    }
+
+    /// One of the cheapest paths to `node`, from the start to `node` itself.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// One of the cheapest paths to the first goal found.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// Every node on any of the cheapest paths to any of the goals.
+    pub fn on_optimal_paths(&self) -> HashSet<N> {
+        let mut seen: HashSet<N> = self.goals.iter().cloned().collect();
+        let mut queue: Vec<N> = self.goals.clone();
+
+        while let Some(node) = queue.pop() {
+            for prev in self.predecessors(&node) {
+                if seen.insert(prev.clone()) {
+                    queue.push(prev.clone());
+                }
+            }
+        }
+
+        seen
+    }
+
+    fn new(start: N, zero: C) -> Paths<N, C> {
+        Paths {
+            costs: HashMap::from([(start.clone(), zero)]),
+            start,
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Paths<N, C> {
+    /// Records a way to reach `next` via `node`, returning whether it's the
+    /// cheapest yet and `next` needs to be (re)visited.
+    fn relax(&mut self, node: &N, next: N, cost: C) -> bool {
+        match self.costs.get(&next).map(|old| cost.cmp(old)) {
+            Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) => {
+                let preds = self.predecessors.entry(next).or_default();
+                if !preds.contains(node) {
+                    preds.push(node.clone());
+                }
+                false
+            }
+            _ => {
+                self.costs.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth-first search, where every step costs one. Pass a goal that never
+/// matches to visit everything reachable.
+pub fn bfs<N, S, I, G>(start: N, mut successors: S, mut goal: G) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut best = None;
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        if goal(&node) {
+            best = Some(cost);
+            paths.goals.push(node);
+            continue;
+        }
+
+        for next in successors(&node) {
+            if paths.relax(&node, next.clone(), cost + 1) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm. The successor function gives the cost of each step,
+/// which mustn't be negative, and `C::default()` is taken to be zero.
+pub fn dijkstra<N, C, S, I, G>(start: N, successors: S, goal: G) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// A* search. The heuristic must never overestimate the cost left to reach a
+/// goal, and must not drop by more than the cost of any single step, or some
+/// optimal paths may be missed.
+pub fn astar<N, C, S, I, H, G>(
+    start: N,
+    mut successors: S,
+    mut heuristic: H,
+    mut goal: G,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let zero = C::default();
+    let mut paths = Paths::new(start.clone(), zero);
+    let mut settled = HashSet::new();
+    let mut best = None;
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Entry {
+        priority: heuristic(&start),
+        cost: zero,
+        node: start,
+    });
+
+    while let Some(Entry {
+        priority,
+        cost,
+        node,
+    }) = queue.pop()
+    {
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+
+        // Skip anything that's been found more cheaply since it was queued
+        if paths.cost(&node).is_some_and(|c| c < cost) || !settled.insert(node.clone()) {
+            continue;
+        }
+
+        if goal(&node) {
+            best = Some(cost);
+            paths.goals.push(node);
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.relax(&node, next.clone(), next_cost) {
+                queue.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// A queued node, ordered so the heap pops the lowest priority first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted graph with two equally cheap routes from a to e, via b and
+    /// via c, and a longer one via d.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2), ('d', 1)],
+            'b' => vec![('e', 3)],
+            'c' => vec![('e', 2)],
+            'd' => vec![('e', 5)],
+            _ => vec![],
+        }
+    }
+
+    /// A 5x5 open grid with a wall down x = 2 that has a gap at y = 4.
+    fn open(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .filter(|&(x, y)| x != 2 || y == 4)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_test() {
+        let paths = bfs((0, 0), open, |&pos| pos == (4, 0));
+        assert_eq!(paths.goal_cost(), Some(12));
+
+        let path = paths.path().unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!(path[0], (0, 0));
+        assert!(path.contains(&(2, 4)));
+
+        let everywhere = bfs((0, 0), open, |_| false);
+        assert_eq!(everywhere.costs().len(), 21);
+        assert_eq!(everywhere.goal_cost(), None);
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let paths = dijkstra('a', edges, |&n| n == 'e');
+        assert_eq!(paths.goal_cost(), Some(4));
+        assert_eq!(paths.predecessors(&'e'), &['b', 'c']);
+        assert_eq!(paths.path(), Some(vec!['a', 'b', 'e']));
+
+        let mut on_path: Vec<char> = paths.on_optimal_paths().into_iter().collect();
+        on_path.sort();
+        assert_eq!(on_path, vec!['a', 'b', 'c', 'e']);
+
+        assert_eq!(dijkstra('b', edges, |&n| n == 'a').goal_cost(), None);
+    }
+
+    #[test]
+    fn all_goals_test() {
+        // Both corners on the far side are the same distance away
+        let paths = bfs((2, 0), open, |&(x, y)| y == 4 && (x == 0 || x == 4));
+        assert_eq!(paths.goal_cost(), Some(6));
+        assert_eq!(paths.goals().len(), 2);
+
+        // Every route down and round is as good as any other
+        assert_eq!(paths.on_optimal_paths().len(), 21);
+    }
+
+    #[test]
+    fn astar_test() {
+        let goal = (4, 0);
+        let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+        let weighted = |pos: &(i32, i32)| open(pos).into_iter().map(|p| (p, 1));
+
+        let paths = astar((0, 0), weighted, manhattan, |&pos| pos == goal);
+        assert_eq!(paths.goal_cost(), Some(12));
+        assert_eq!(
+            paths.on_optimal_paths(),
+            dijkstra((0, 0), weighted, |&pos| pos == goal).on_optimal_paths()
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_parse::lines;
+use aoc_runner::{Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day::new::<Puzzle>(0, 0);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        lines(input, Ok)
+    }
+
+    fn part_one(input: &Vec<&str>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(input: &Vec<&str>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+fn part_one(_input: &[&str]) -> i64 {
+    0
+}
+
+fn part_two(_input: &[&str]) -> i64 {
+    0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part_one_test() {
+        assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()), 0);
+    }
+
+    #[test]
+    fn part_two_test() {
+        assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE).unwrap()), 0);
+    }
+}